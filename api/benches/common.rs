@@ -1,3 +1,8 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
 pub static UNIT_STRINGS: [&str; 25] = [
     // The unity
     "1",
@@ -60,3 +65,37 @@ pub static UNIT_PAIRS: [(&str, &str); 19] = [
     ("10[in_i]3", "100[in_us]3"),
     ("10[in_i]3", "100[in_us]2"),
 ];
+
+/// Wraps the system allocator to count allocations, so benchmarks can check how many an
+/// operation makes. Each benchmark binary installs it with `#[global_allocator]`.
+///
+pub struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// The number of allocations made while calling `f`.
+///
+pub fn count_allocations<T, F: FnOnce() -> T>(f: F) -> usize {
+    let before = ALLOCATIONS.load(Ordering::SeqCst);
+    let result = f();
+    let after = ALLOCATIONS.load(Ordering::SeqCst);
+    drop(result);
+
+    after - before
+}
@@ -6,7 +6,10 @@ mod common;
 
 use criterion::Criterion;
 use std::str::FromStr;
-use wise_units::{Convertible, Measurement, Unit};
+use wise_units::{Converter, Convertible, Measurement, Unit};
+
+#[global_allocator]
+static ALLOCATOR: common::CountingAllocator = common::CountingAllocator;
 
 fn new_group(c: &mut Criterion) {
     c.bench_function_over_inputs(
@@ -18,6 +21,25 @@ fn new_group(c: &mut Criterion) {
     );
 }
 
+fn clone_group(c: &mut Criterion) {
+    c.bench_function_over_inputs(
+        "Measurement::clone()",
+        |b, &unit_string| {
+            let measurement = Measurement::try_new(1.0, unit_string).unwrap();
+
+            assert_eq!(
+                common::count_allocations(|| measurement.clone()),
+                0,
+                "cloning a Measurement in {} allocated",
+                unit_string
+            );
+
+            b.iter(|| measurement.clone());
+        },
+        &common::UNIT_STRINGS,
+    );
+}
+
 //-----------------------------------------------------------------------------
 // impl Convertible
 //-----------------------------------------------------------------------------
@@ -46,10 +68,55 @@ fn convert_to_unit_group(c: &mut Criterion) {
     );
 }
 
+fn converter_convert_slice_group(c: &mut Criterion) {
+    c.bench_function_over_inputs(
+        "Converter::convert_slice()",
+        |b, &(lhs_string, rhs_string)| {
+            let lhs = Unit::from_str(lhs_string).unwrap();
+            let rhs = Unit::from_str(rhs_string).unwrap();
+            let converter = Converter::new(&lhs, &rhs);
+            let values: Vec<f64> = (0..1000).map(f64::from).collect();
+            let mut output = vec![0.0; values.len()];
+
+            // Like `convert_to()` above, incompatible pairs only measure the error.
+            b.iter(|| {
+                if let Ok(ref converter) = converter {
+                    converter.convert_slice(&values, &mut output);
+                }
+            });
+        },
+        &common::UNIT_PAIRS,
+    );
+}
+
+/// Temperatures, which `Converter` handles with a multiply and an add instead of calling each
+/// unit's conversion functions.
+///
+static AFFINE_UNIT_PAIRS: [(&str, &str); 3] = [("Cel", "[degF]"), ("[degF]", "Cel"), ("K", "Cel")];
+
+fn converter_convert_slice_affine_group(c: &mut Criterion) {
+    c.bench_function_over_inputs(
+        "Converter::convert_slice(affine)",
+        |b, &(lhs_string, rhs_string)| {
+            let lhs = Unit::from_str(lhs_string).unwrap();
+            let rhs = Unit::from_str(rhs_string).unwrap();
+            let converter = Converter::new(&lhs, &rhs).unwrap();
+            let values: Vec<f64> = (0..1000).map(f64::from).collect();
+            let mut output = vec![0.0; values.len()];
+
+            b.iter(|| converter.convert_slice(&values, &mut output));
+        },
+        &AFFINE_UNIT_PAIRS,
+    );
+}
+
 criterion_group!(
     measurement_benches,
     new_group,
+    clone_group,
     convert_to_str_group,
     convert_to_unit_group,
+    converter_convert_slice_group,
+    converter_convert_slice_affine_group,
 );
 criterion_main!(measurement_benches);
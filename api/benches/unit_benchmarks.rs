@@ -8,6 +8,9 @@ use criterion::Criterion;
 use std::str::FromStr;
 use wise_units::{Composable, IsCompatibleWith, UcumUnit, Unit};
 
+#[global_allocator]
+static ALLOCATOR: common::CountingAllocator = common::CountingAllocator;
+
 macro_rules! bench_over_inputs_method {
     ($function_name:ident, $test_name:expr, $method_name:ident) => {
         fn $function_name(c: &mut Criterion) {
@@ -66,6 +69,28 @@ fn is_compatible_with_group(c: &mut Criterion) {
     );
 }
 
+//-----------------------------------------------------------------------------
+// impl Clone
+//-----------------------------------------------------------------------------
+fn clone_group(c: &mut Criterion) {
+    c.bench_function_over_inputs(
+        "Unit::clone()",
+        |b, &unit_string| {
+            let unit = Unit::from_str(unit_string).unwrap();
+
+            assert_eq!(
+                common::count_allocations(|| unit.clone()),
+                0,
+                "cloning {} allocated",
+                unit_string
+            );
+
+            b.iter(|| unit.clone());
+        },
+        &common::UNIT_STRINGS,
+    );
+}
+
 //-----------------------------------------------------------------------------
 // impl Display
 //-----------------------------------------------------------------------------
@@ -113,6 +138,7 @@ criterion_group!(
     expression_reduced_group,
     composition_group,
     is_compatible_with_group,
+    clone_group,
     display_group,
     from_str_group,
     partial_eq_group,
@@ -0,0 +1,313 @@
+//! Sprayer calibration and tank-mix calculations. Each function takes `Measurement`s in any
+//! compatible units and checks them before doing any math, so a ground speed can be given in
+//! `[mi_i]/h` or `km/h`, a nozzle spacing in `[in_i]` or `cm`, and so on.
+//!
+//! ```
+//! use wise_units::{agronomy, Convertible, Measurement};
+//!
+//! let nozzle_flow = Measurement::try_new(0.4, "[gal_us]/min").unwrap();
+//! let ground_speed = Measurement::try_new(8.0, "[mi_i]/h").unwrap();
+//! let nozzle_spacing = Measurement::try_new(20.0, "[in_i]").unwrap();
+//!
+//! let carrier_rate =
+//!     agronomy::carrier_rate(&nozzle_flow, &ground_speed, &nozzle_spacing).unwrap();
+//! assert!((carrier_rate.value() - 14.85).abs() < 0.001);
+//!
+//! let tank = Measurement::try_new(300.0, "[gal_us]").unwrap();
+//! let acres = agronomy::area_per_tank(&tank, &carrier_rate).unwrap();
+//! assert!((acres.value() - 20.202).abs() < 0.001);
+//!
+//! let product_rate = Measurement::try_new(32.0, "[foz_us]/[acr_us]").unwrap();
+//! let product = agronomy::product_per_tank(&product_rate, &tank, &carrier_rate).unwrap();
+//! let product = product.convert_to("[gal_us]").unwrap();
+//! assert!((product.value() - 5.051).abs() < 0.001);
+//! ```
+//!
+use crate::{
+    as_fraction::AsFraction, is_compatible_with::IsCompatibleWith, parser::Composable, Composition,
+    Convertible, Error, Measurement, Unit,
+};
+use std::str::FromStr;
+
+/// The `Unit` that carrier (and other volume/area) rates are returned in.
+///
+const CARRIER_RATE_UNIT: &str = "[gal_us]/[acr_us]";
+
+/// The `Unit` that nozzle flows are returned in.
+///
+const NOZZLE_FLOW_UNIT: &str = "[gal_us]/min";
+
+/// The `Unit` that areas are returned in.
+///
+const AREA_UNIT: &str = "[acr_us]";
+
+const SPEED_UNIT: &str = "m/s";
+const LENGTH_UNIT: &str = "m";
+const VOLUME_UNIT: &str = "L";
+
+/// How much carrier a boom puts down per area, given the flow from each nozzle, the ground speed,
+/// and the distance between nozzles. The result is in `[gal_us]/[acr_us]`.
+///
+/// # Errors
+///
+/// Returns an `Error::IncompatibleUnitTypes` if `nozzle_flow` isn't a volume/time,
+/// `ground_speed` isn't a length/time, or `nozzle_spacing` isn't a length.
+///
+pub fn carrier_rate(
+    nozzle_flow: &Measurement,
+    ground_speed: &Measurement,
+    nozzle_spacing: &Measurement,
+) -> Result<Measurement, Error> {
+    expect_compatible(nozzle_flow, NOZZLE_FLOW_UNIT)?;
+    expect_compatible(ground_speed, SPEED_UNIT)?;
+    expect_compatible(nozzle_spacing, LENGTH_UNIT)?;
+    expect_nonzero(ground_speed)?;
+    expect_nonzero(nozzle_spacing)?;
+
+    (nozzle_flow / &(ground_speed * nozzle_spacing)?)?.convert_to(CARRIER_RATE_UNIT)
+}
+
+/// The flow each nozzle needs to put down `carrier_rate` at `ground_speed`, with nozzles
+/// `nozzle_spacing` apart; useful for picking nozzles. The result is in `[gal_us]/min`.
+///
+/// # Errors
+///
+/// Returns an `Error::IncompatibleUnitTypes` if `carrier_rate` isn't a volume/area,
+/// `ground_speed` isn't a length/time, or `nozzle_spacing` isn't a length.
+///
+pub fn nozzle_flow(
+    carrier_rate: &Measurement,
+    ground_speed: &Measurement,
+    nozzle_spacing: &Measurement,
+) -> Result<Measurement, Error> {
+    expect_compatible(carrier_rate, CARRIER_RATE_UNIT)?;
+    expect_compatible(ground_speed, SPEED_UNIT)?;
+    expect_compatible(nozzle_spacing, LENGTH_UNIT)?;
+
+    (&(carrier_rate * ground_speed)? * nozzle_spacing)?.convert_to(NOZZLE_FLOW_UNIT)
+}
+
+/// How much area one tank of `tank_volume` covers at `carrier_rate`. The result is in
+/// `[acr_us]`.
+///
+/// # Errors
+///
+/// Returns an `Error::IncompatibleUnitTypes` if `tank_volume` isn't a volume or `carrier_rate`
+/// isn't a volume/area, or an `Error::DivideByZero` if `carrier_rate` is 0.
+///
+pub fn area_per_tank(
+    tank_volume: &Measurement,
+    carrier_rate: &Measurement,
+) -> Result<Measurement, Error> {
+    expect_compatible(tank_volume, VOLUME_UNIT)?;
+    expect_compatible(carrier_rate, CARRIER_RATE_UNIT)?;
+    expect_nonzero(carrier_rate)?;
+
+    (tank_volume / carrier_rate)?.convert_to(AREA_UNIT)
+}
+
+/// How much of a tank-mix product goes in one tank of `tank_volume`, given the product's rate
+/// per area (ex. `[foz_us]/[acr_us]` or `[lb_av]/[acr_us]`) and the `carrier_rate`. The result
+/// is in the numerator of `product_rate`'s `Unit` (ex. `[foz_us]`).
+///
+/// # Errors
+///
+/// Returns an `Error::IncompatibleUnitTypes` if `product_rate` isn't (something)/area,
+/// `tank_volume` isn't a volume, or `carrier_rate` isn't a volume/area, or an
+/// `Error::DivideByZero` if `carrier_rate` is 0.
+///
+pub fn product_per_tank(
+    product_rate: &Measurement,
+    tank_volume: &Measurement,
+    carrier_rate: &Measurement,
+) -> Result<Measurement, Error> {
+    let area = area_per_tank(tank_volume, carrier_rate)?;
+
+    let (numerator, denominator) = match product_rate.unit().as_fraction() {
+        (Some(numerator), Some(denominator))
+            if denominator.composition() == Composition::new_length(2) =>
+        {
+            (numerator, denominator)
+        }
+        _ => {
+            return Err(Error::IncompatibleUnitTypes {
+                lhs: product_rate.unit().expression(),
+                rhs: format!("/{}", AREA_UNIT),
+            })
+        }
+    };
+
+    let area = area.convert_to(&denominator)?;
+
+    Measurement::new(product_rate.value() * area.value(), numerator)
+}
+
+fn expect_compatible(measurement: &Measurement, expected: &str) -> Result<(), Error> {
+    let unit = Unit::from_str(expected)?;
+
+    if measurement.is_compatible_with(&unit) {
+        Ok(())
+    } else {
+        Err(Error::IncompatibleUnitTypes {
+            lhs: measurement.unit().expression(),
+            rhs: unit.expression(),
+        })
+    }
+}
+
+fn expect_nonzero(measurement: &Measurement) -> Result<(), Error> {
+    if measurement.value() == 0.0 {
+        Err(Error::DivideByZero)
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn m(value: f64, unit: &str) -> Measurement {
+        Measurement::try_new(value, unit).unwrap()
+    }
+
+    #[test]
+    fn validate_carrier_rate() {
+        // GPA = GPM * 5940 / (MPH * inches)
+        let rate = carrier_rate(
+            &m(0.4, "[gal_us]/min"),
+            &m(8.0, "[mi_i]/h"),
+            &m(20.0, "[in_i]"),
+        )
+        .unwrap();
+
+        assert_relative_eq!(
+            rate.value(),
+            0.4 * 5940.0 / (8.0 * 20.0),
+            max_relative = 1e-4
+        );
+        assert_eq!(rate.unit().expression(), "[gal_us]/[acr_us]");
+    }
+
+    #[test]
+    fn validate_carrier_rate_metric() {
+        // L/ha = L/min * 60,000 / (km/h * cm)
+        let rate = carrier_rate(&m(1.5, "L/min"), &m(10.0, "km/h"), &m(50.0, "cm"))
+            .unwrap()
+            .convert_to("L/har")
+            .unwrap();
+
+        assert_relative_eq!(
+            rate.value(),
+            1.5 * 60_000.0 / (10.0 * 50.0),
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn validate_carrier_rate_misuse() {
+        assert_eq!(
+            carrier_rate(
+                &m(8.0, "[mi_i]/h"),
+                &m(0.4, "[gal_us]/min"),
+                &m(20.0, "[in_i]"),
+            )
+            .unwrap_err(),
+            Error::IncompatibleUnitTypes {
+                lhs: "[mi_i]/h".to_string(),
+                rhs: "[gal_us]/min".to_string()
+            }
+        );
+        assert!(carrier_rate(
+            &m(0.4, "[gal_us]/min"),
+            &m(8.0, "[mi_i]"),
+            &m(20.0, "[in_i]"),
+        )
+        .is_err());
+        assert_eq!(
+            carrier_rate(
+                &m(0.4, "[gal_us]/min"),
+                &m(0.0, "[mi_i]/h"),
+                &m(20.0, "[in_i]"),
+            )
+            .unwrap_err(),
+            Error::DivideByZero
+        );
+    }
+
+    #[test]
+    fn validate_nozzle_flow() {
+        let flow = nozzle_flow(
+            &m(15.0, "[gal_us]/[acr_us]"),
+            &m(8.0, "[mi_i]/h"),
+            &m(20.0, "[in_i]"),
+        )
+        .unwrap();
+
+        assert_relative_eq!(
+            flow.value(),
+            15.0 * 8.0 * 20.0 / 5940.0,
+            max_relative = 1e-4
+        );
+        assert_eq!(flow.unit().expression(), "[gal_us]/min");
+    }
+
+    #[test]
+    fn validate_area_per_tank() {
+        let area = area_per_tank(&m(1000.0, "L"), &m(100.0, "L/har")).unwrap();
+
+        assert_relative_eq!(
+            area.value(),
+            10.0 / 0.404_687_260_987_425_2,
+            max_relative = 1e-12
+        );
+        assert_eq!(area.unit().expression(), "[acr_us]");
+
+        assert!(area_per_tank(&m(1000.0, "kg"), &m(100.0, "L/har")).is_err());
+        assert_eq!(
+            area_per_tank(&m(1000.0, "L"), &m(0.0, "L/har")).unwrap_err(),
+            Error::DivideByZero
+        );
+    }
+
+    #[test]
+    fn validate_product_per_tank() {
+        let product = product_per_tank(
+            &m(1.5, "[lb_av]/[acr_us]"),
+            &m(500.0, "[gal_us]"),
+            &m(10.0, "[gal_us]/[acr_us]"),
+        )
+        .unwrap();
+
+        assert_relative_eq!(product.value(), 75.0, max_relative = 1e-12);
+        assert_eq!(product.unit().expression(), "[lb_av]");
+
+        let product =
+            product_per_tank(&m(2.0, "L/har"), &m(1000.0, "L"), &m(100.0, "L/har")).unwrap();
+        assert_relative_eq!(product.value(), 20.0, max_relative = 1e-12);
+        assert_eq!(product.unit().expression(), "L");
+    }
+
+    #[test]
+    fn validate_product_per_tank_misuse() {
+        assert_eq!(
+            product_per_tank(
+                &m(1.5, "[lb_av]/[ft_i]"),
+                &m(500.0, "[gal_us]"),
+                &m(10.0, "[gal_us]/[acr_us]"),
+            )
+            .unwrap_err(),
+            Error::IncompatibleUnitTypes {
+                lhs: "[lb_av]/[ft_i]".to_string(),
+                rhs: "/[acr_us]".to_string()
+            }
+        );
+        assert!(product_per_tank(
+            &m(1.5, "[lb_av]"),
+            &m(500.0, "[gal_us]"),
+            &m(10.0, "[gal_us]/[acr_us]"),
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,483 @@
+//! Bushel-weight conversions for grain commodities. UCUM defines `[bu_us]` as a volume, but grain
+//! is bought and sold by weight, using a standard test weight (ex. 56 `[lb_av]` per bushel for
+//! corn) at a standard moisture content.
+//!
+use crate::{units, ConversionContext, Error, Measurement, Unit};
+
+/// Errors specific to looking up `Commodity`s and applying moisture contents.
+///
+#[derive(Clone, Debug, thiserror::Error, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum CommodityError {
+    #[error("No commodity registered named {0:?}")]
+    Unknown(String),
+
+    /// Moisture contents are percentages, and must be in `[0, 100)`.
+    ///
+    #[error("Invalid moisture percentage: {0}")]
+    InvalidMoisture(f64),
+}
+
+/// A grain commodity, defined by its standard test weight and the moisture content that test
+/// weight applies to.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Commodity {
+    name: String,
+    test_weight: Measurement,
+    standard_moisture: f64,
+    context: ConversionContext,
+}
+
+impl Commodity {
+    /// `test_weight` must be a mass per volume (ex. 56 `[lb_av]/[bu_us]`); `standard_moisture` is
+    /// a percentage (ex. 15.5).
+    ///
+    /// # Errors
+    ///
+    /// * `Error::IncompatibleUnitTypes` if `test_weight` isn't a mass per volume.
+    /// * `CommodityError::InvalidMoisture` if `standard_moisture` isn't in `[0, 100)`.
+    ///
+    pub fn new(
+        name: &str,
+        test_weight: Measurement,
+        standard_moisture: f64,
+    ) -> Result<Self, Error> {
+        validate_moisture(standard_moisture)?;
+
+        let context = ConversionContext::new().with_density(name, test_weight.clone())?;
+
+        Ok(Self {
+            name: name.to_string(),
+            test_weight,
+            standard_moisture,
+            context,
+        })
+    }
+
+    /// Like `new()`, for arguments that are known to be valid (ex. the built-ins).
+    ///
+    fn new_unchecked(name: &str, test_weight: Measurement, standard_moisture: f64) -> Self {
+        let context = ConversionContext::new().with_ratio_unchecked(name, test_weight.clone());
+
+        Self {
+            name: name.to_string(),
+            test_weight,
+            standard_moisture,
+            context,
+        }
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub const fn test_weight(&self) -> &Measurement {
+        &self.test_weight
+    }
+
+    /// The moisture content, as a percentage, that `test_weight` applies to.
+    ///
+    #[must_use]
+    pub const fn standard_moisture(&self) -> f64 {
+        self.standard_moisture
+    }
+
+    /// Converts `measurement` to `unit`, using the commodity's test weight to get between volumes
+    /// (bushels) and masses. Rates work too, ex. `[bu_us]/[acr_us]` to `t/har`. Annotations of the
+    /// commodity's name (ex. `[bu_us]{corn}`) are ignored.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wise_units::{commodity::CommodityRegistry, Measurement, Unit};
+    ///
+    /// let registry = CommodityRegistry::new();
+    /// let corn = registry.get("corn").unwrap();
+    ///
+    /// let yield_rate = Measurement::try_new(200.0, "[bu_us]/[acr_us]").unwrap();
+    /// let metric = corn
+    ///     .convert(&yield_rate, &Unit::from_str("t/har").unwrap())
+    ///     .unwrap();
+    ///
+    /// assert!((metric.value() - 12.553_5).abs() < 0.000_1);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::IncompatibleUnitTypes` if `measurement` can't be converted to `unit`,
+    /// even with the test weight.
+    ///
+    pub fn convert(&self, measurement: &Measurement, unit: &Unit) -> Result<Measurement, Error> {
+        let source = Measurement::new(
+            measurement.value(),
+            measurement.unit().without_annotation(&self.name),
+        )?;
+
+        source
+            .convert_to_with(&unit.without_annotation(&self.name), &self.context)
+            .and_then(|conversion| Measurement::new(conversion.measurement().value(), unit.clone()))
+    }
+
+    /// The standard moisture shrink factor: how much of a wet weight at `moisture` (a percentage)
+    /// is left at the commodity's standard moisture. That's
+    /// `(100 - moisture) / (100 - standard_moisture)`, but at most 1: grain drier than standard
+    /// isn't credited for the missing water, so its dry-equivalent weight is its actual weight.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CommodityError::InvalidMoisture` if `moisture` isn't in `[0, 100)`.
+    ///
+    pub fn shrink_factor(&self, moisture: f64) -> Result<f64, Error> {
+        validate_moisture(moisture)?;
+
+        Ok(((100.0 - moisture) / (100.0 - self.standard_moisture)).min(1.0))
+    }
+
+    /// Applies the moisture shrink to `wet`, a weight (or weight-based rate) measured at
+    /// `moisture`, then converts it to `unit`; typically dry-equivalent bushels.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wise_units::{commodity::CommodityRegistry, Measurement, Unit};
+    ///
+    /// let registry = CommodityRegistry::new();
+    /// let corn = registry.get("corn").unwrap();
+    ///
+    /// // 5,600 lb of corn at 20% moisture is less than 100 bushels at 15.5%.
+    /// let wet = Measurement::try_new(5_600.0, "[lb_av]").unwrap();
+    /// let dry = corn
+    ///     .to_dry_equivalent(&wet, 20.0, &Unit::from_str("[bu_us]").unwrap())
+    ///     .unwrap();
+    ///
+    /// assert!((dry.value() - 94.674_6).abs() < 0.000_1);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * `CommodityError::InvalidMoisture` if `moisture` isn't in `[0, 100)`.
+    /// * `Error::IncompatibleUnitTypes` if `wet` can't be converted to `unit`.
+    ///
+    pub fn to_dry_equivalent(
+        &self,
+        wet: &Measurement,
+        moisture: f64,
+        unit: &Unit,
+    ) -> Result<Measurement, Error> {
+        let dry = (wet * self.shrink_factor(moisture)?)?;
+
+        self.convert(&dry, unit)
+    }
+}
+
+/// Name, test weight (in `[lb_av]/[bu_us]`), and standard moisture of each built-in `Commodity`.
+///
+const BUILT_INS: [(&str, f64, f64); 3] = [
+    ("corn", 56.0, 15.5),
+    ("soybeans", 60.0, 13.0),
+    ("wheat", 60.0, 13.5),
+];
+
+/// Holds the `Commodity`s that can be looked up by name. `CommodityRegistry::new()` starts with
+/// built-in defaults (corn, soybeans, and wheat); more can be added using `register()`.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct CommodityRegistry {
+    commodities: Vec<Commodity>,
+}
+
+impl CommodityRegistry {
+    /// A registry with the built-in defaults:
+    ///
+    /// * corn: 56 `[lb_av]/[bu_us]` at 15.5% moisture
+    /// * soybeans: 60 `[lb_av]/[bu_us]` at 13% moisture
+    /// * wheat: 60 `[lb_av]/[bu_us]` at 13.5% moisture
+    ///
+    #[must_use]
+    pub fn new() -> Self {
+        let commodities = BUILT_INS
+            .iter()
+            .map(|(name, pounds, moisture)| {
+                let test_weight =
+                    Measurement::new_unchecked(*pounds, units::POUND_AVOIRDUPOIS_PER_BUSHEL_US);
+
+                Commodity::new_unchecked(name, test_weight, *moisture)
+            })
+            .collect();
+
+        Self { commodities }
+    }
+
+    /// A registry without any of the built-in defaults.
+    ///
+    #[must_use]
+    pub const fn empty() -> Self {
+        Self {
+            commodities: Vec::new(),
+        }
+    }
+
+    /// Adds `commodity`, replacing (and returning) any existing one with the same name.
+    ///
+    pub fn register(&mut self, commodity: Commodity) -> Option<Commodity> {
+        match self
+            .commodities
+            .iter_mut()
+            .find(|existing| existing.name == commodity.name)
+        {
+            Some(existing) => Some(std::mem::replace(existing, commodity)),
+            None => {
+                self.commodities.push(commodity);
+                None
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&Commodity> {
+        self.commodities
+            .iter()
+            .find(|commodity| commodity.name == name)
+    }
+
+    /// Like `Commodity::convert()`, but the commodity is picked using the annotations on
+    /// `measurement`'s `Unit` and/or `unit`, ex. `[bu_us]{corn}`.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wise_units::{commodity::CommodityRegistry, Measurement, Unit};
+    ///
+    /// let registry = CommodityRegistry::new();
+    /// let bushels = Measurement::try_new(100.0, "[bu_us]{soybeans}").unwrap();
+    /// let pounds = registry
+    ///     .convert(&bushels, &Unit::from_str("[lb_av]").unwrap())
+    ///     .unwrap();
+    ///
+    /// assert!((pounds.value() - 6_000.0).abs() < 0.000_001);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * `CommodityError::Unknown` if an annotation doesn't name a registered commodity.
+    /// * `Error::IncompatibleUnitTypes` if neither side has an annotation, if they name different
+    ///   commodities, or if the conversion isn't possible with the commodity's test weight.
+    ///
+    pub fn convert(&self, measurement: &Measurement, unit: &Unit) -> Result<Measurement, Error> {
+        let incompatible = || Error::IncompatibleUnitTypes {
+            lhs: measurement.unit().expression(),
+            rhs: unit.expression(),
+        };
+
+        let name = match (
+            measurement.unit().first_annotation(),
+            unit.first_annotation(),
+        ) {
+            (Some(lhs), Some(rhs)) if lhs != rhs => return Err(incompatible()),
+            (Some(name), _) | (None, Some(name)) => name,
+            (None, None) => return Err(incompatible()),
+        };
+
+        self.get(name)
+            .ok_or_else(|| CommodityError::Unknown(name.to_string()))?
+            .convert(measurement, unit)
+    }
+}
+
+impl Default for CommodityRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn validate_moisture(moisture: f64) -> Result<(), Error> {
+    if (0.0..100.0).contains(&moisture) {
+        Ok(())
+    } else {
+        Err(CommodityError::InvalidMoisture(moisture).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Commodity, CommodityError, CommodityRegistry, BUILT_INS};
+    use crate::{Error, Measurement, Unit};
+    use approx::assert_relative_eq;
+    use std::str::FromStr;
+
+    fn unit(expression: &str) -> Unit {
+        Unit::from_str(expression).unwrap()
+    }
+
+    #[test]
+    fn validate_defaults() {
+        let registry = CommodityRegistry::new();
+
+        let corn = registry.get("corn").unwrap();
+        assert_relative_eq!(corn.test_weight().value(), 56.0);
+        assert_relative_eq!(corn.standard_moisture(), 15.5);
+
+        let soybeans = registry.get("soybeans").unwrap();
+        assert_relative_eq!(soybeans.test_weight().value(), 60.0);
+        assert_relative_eq!(soybeans.standard_moisture(), 13.0);
+
+        assert!(registry.get("wheat").is_some());
+        assert!(registry.get("barley").is_none());
+
+        assert_eq!(registry.commodities.len(), 3);
+        assert!(CommodityRegistry::empty().get("corn").is_none());
+    }
+
+    #[test]
+    fn validate_built_ins_are_valid() {
+        // The built-ins skip `Commodity::new()`'s checks, so make sure they'd pass them.
+        let registry = CommodityRegistry::new();
+
+        for (name, pounds, moisture) in &BUILT_INS {
+            let test_weight = Measurement::try_new(*pounds, "[lb_av]/[bu_us]").unwrap();
+            let checked = Commodity::new(name, test_weight, *moisture).unwrap();
+
+            assert_eq!(registry.get(name), Some(&checked));
+        }
+    }
+
+    #[test]
+    fn validate_new_invalid() {
+        let test_weight = Measurement::try_new(48.0, "[lb_av]/[acr_us]").unwrap();
+        assert!(matches!(
+            Commodity::new("barley", test_weight, 14.5),
+            Err(Error::IncompatibleUnitTypes { .. })
+        ));
+
+        let test_weight = Measurement::try_new(48.0, "[lb_av]/[bu_us]").unwrap();
+        assert_eq!(
+            Commodity::new("barley", test_weight, 100.0).unwrap_err(),
+            Error::Commodity(CommodityError::InvalidMoisture(100.0))
+        );
+    }
+
+    #[test]
+    fn validate_register() {
+        let mut registry = CommodityRegistry::new();
+        let test_weight = Measurement::try_new(48.0, "[lb_av]/[bu_us]").unwrap();
+        let barley = Commodity::new("barley", test_weight, 14.5).unwrap();
+
+        assert!(registry.register(barley.clone()).is_none());
+        assert_eq!(registry.get("barley"), Some(&barley));
+
+        let test_weight = Measurement::try_new(25.4, "kg/[bu_us]").unwrap();
+        let corn = Commodity::new("corn", test_weight, 15.0).unwrap();
+        let replaced = registry.register(corn).unwrap();
+        assert_relative_eq!(replaced.test_weight().value(), 56.0);
+        assert_relative_eq!(registry.get("corn").unwrap().standard_moisture(), 15.0);
+    }
+
+    #[test]
+    fn validate_bushels_to_weight() {
+        let registry = CommodityRegistry::new();
+        let corn = registry.get("corn").unwrap();
+        let bushels = Measurement::try_new(10.0, "[bu_us]").unwrap();
+
+        let pounds = corn.convert(&bushels, &unit("[lb_av]")).unwrap();
+        assert_relative_eq!(pounds.value(), 560.0, max_relative = 1e-12);
+
+        let kilograms = corn.convert(&bushels, &unit("kg")).unwrap();
+        assert_relative_eq!(kilograms.value(), 254.011_727_2, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn validate_weight_to_bushels() {
+        let registry = CommodityRegistry::new();
+        let soybeans = registry.get("soybeans").unwrap();
+        let pounds = Measurement::try_new(600.0, "[lb_av]").unwrap();
+
+        let bushels = soybeans.convert(&pounds, &unit("[bu_us]")).unwrap();
+        assert_relative_eq!(bushels.value(), 10.0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn validate_yield() {
+        let registry = CommodityRegistry::new();
+        let corn = registry.get("corn").unwrap();
+
+        let imperial = Measurement::try_new(200.0, "[bu_us]/[acr_us]").unwrap();
+        let metric = corn.convert(&imperial, &unit("t/har")).unwrap();
+        assert_relative_eq!(metric.value(), 12.553_478_4, max_relative = 1e-6);
+
+        let back = corn.convert(&metric, &unit("[bu_us]/[acr_us]")).unwrap();
+        assert_relative_eq!(back.value(), 200.0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn validate_shrink() {
+        let registry = CommodityRegistry::new();
+        let corn = registry.get("corn").unwrap();
+
+        assert_relative_eq!(corn.shrink_factor(15.5).unwrap(), 1.0);
+        assert_relative_eq!(corn.shrink_factor(25.0).unwrap(), 75.0 / 84.5);
+        // Drier than standard isn't inflated.
+        assert_relative_eq!(corn.shrink_factor(10.0).unwrap(), 1.0);
+        assert_relative_eq!(corn.shrink_factor(0.0).unwrap(), 1.0);
+        assert_eq!(
+            corn.shrink_factor(-1.0).unwrap_err(),
+            Error::Commodity(CommodityError::InvalidMoisture(-1.0))
+        );
+
+        let wet = Measurement::try_new(10_000.0, "[lb_av]/[acr_us]").unwrap();
+        let dry = corn
+            .to_dry_equivalent(&wet, 25.0, &unit("[bu_us]/[acr_us]"))
+            .unwrap();
+        assert_relative_eq!(
+            dry.value(),
+            10_000.0 * 75.0 / 84.5 / 56.0,
+            max_relative = 1e-12
+        );
+
+        let dry = corn
+            .to_dry_equivalent(&wet, 10.0, &unit("[bu_us]/[acr_us]"))
+            .unwrap();
+        assert_relative_eq!(dry.value(), 10_000.0 / 56.0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn validate_registry_convert_with_annotations() {
+        let registry = CommodityRegistry::new();
+
+        let bushels = Measurement::try_new(150.0, "[bu_us]{corn}/[acr_us]").unwrap();
+        let metric = registry.convert(&bushels, &unit("t/har")).unwrap();
+        assert_relative_eq!(metric.value(), 9.415_108_8, max_relative = 1e-6);
+        assert_eq!(metric.unit().expression(), "t/har");
+
+        let pounds = Measurement::try_new(5_600.0, "[lb_av]").unwrap();
+        let bushels = registry.convert(&pounds, &unit("[bu_us]{corn}")).unwrap();
+        assert_relative_eq!(bushels.value(), 100.0, max_relative = 1e-12);
+        assert_eq!(bushels.unit().expression(), "[bu_us]{corn}");
+    }
+
+    #[test]
+    fn validate_registry_convert_errors() {
+        let registry = CommodityRegistry::new();
+
+        let bushels = Measurement::try_new(1.0, "[bu_us]{oats}").unwrap();
+        assert_eq!(
+            registry.convert(&bushels, &unit("[lb_av]")).unwrap_err(),
+            Error::Commodity(CommodityError::Unknown("oats".to_string()))
+        );
+
+        let bushels = Measurement::try_new(1.0, "[bu_us]").unwrap();
+        assert!(matches!(
+            registry.convert(&bushels, &unit("[lb_av]")),
+            Err(Error::IncompatibleUnitTypes { .. })
+        ));
+
+        let bushels = Measurement::try_new(1.0, "[bu_us]{corn}").unwrap();
+        assert!(matches!(
+            registry.convert(&bushels, &unit("[lb_av]{soybeans}")),
+            Err(Error::IncompatibleUnitTypes { .. })
+        ));
+        assert!(matches!(
+            registry.convert(&bushels, &unit("s")),
+            Err(Error::IncompatibleUnitTypes { .. })
+        ));
+    }
+}
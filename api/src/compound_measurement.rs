@@ -0,0 +1,640 @@
+//! A `CompoundMeasurement` is a quantity expressed as the sum of several compatible
+//! `Measurement`s, ex. "5 [ft_i] 9 [in_i]" or "1 h 30 min".
+//!
+use crate::{
+    is_compatible_with::IsCompatibleWith, parser::Error as ParserError, Convertible, Error,
+    Measurement, Unit,
+};
+use std::{cmp::Ordering, fmt, str::FromStr};
+
+/// Common, non-UCUM abbreviations that are accepted when parsing a `CompoundMeasurement`, along
+/// with the UCUM expression they stand for.
+///
+const ALIASES: [(&str, &str); 9] = [
+    ("ft", "[ft_i]"),
+    ("hr", "h"),
+    ("in", "[in_i]"),
+    ("lb", "[lb_av]"),
+    ("mi", "[mi_i]"),
+    ("oz", "[oz_av]"),
+    ("sec", "s"),
+    ("wks", "wk"),
+    ("yd", "[yd_i]"),
+];
+
+/// How much a value can be off from a whole number and still count as that whole number when
+/// splitting off leading components. Guards against results like "5 [ft_i] 12 [in_i]" that come
+/// from floating point noise.
+///
+const WHOLE_NUMBER_TOLERANCE: f64 = 1.0e-9;
+
+/// Determines what happens to the last component when decomposing a `Measurement` into a
+/// `CompoundMeasurement`.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rounding {
+    /// Keep the last component as-is, fractional part and all.
+    None,
+
+    /// Round the last component to the nearest whole number.
+    Nearest,
+
+    /// Round the last component down to a whole number.
+    Down,
+
+    /// Round the last component up to a whole number.
+    Up,
+}
+
+impl Rounding {
+    fn apply(self, value: f64) -> f64 {
+        match self {
+            Self::None => value,
+            Self::Nearest => value.round(),
+            Self::Down => value.floor(),
+            Self::Up => value.ceil(),
+        }
+    }
+}
+
+impl Default for Rounding {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+/// An ordered list of compatible `Measurement`s whose sum is the quantity being represented.
+///
+/// ```
+/// use std::str::FromStr;
+/// use wise_units::{compound_measurement::Rounding, CompoundMeasurement, Measurement, Unit};
+///
+/// let height = Measurement::try_new(175.0, "cm").unwrap();
+/// let units = [
+///     Unit::from_str("[ft_i]").unwrap(),
+///     Unit::from_str("[in_i]").unwrap(),
+/// ];
+///
+/// let compound = CompoundMeasurement::decompose(&height, &units, Rounding::Nearest).unwrap();
+/// assert_eq!(compound.to_string(), "5 [ft_i] 9 [in_i]");
+///
+/// let parsed = CompoundMeasurement::from_str("5 ft 9 in").unwrap();
+/// assert_eq!(parsed, compound);
+/// assert_eq!(parsed.to_measurement().unwrap().unit().expression(), "[ft_i]");
+/// ```
+///
+#[derive(Clone, Debug, Default)]
+pub struct CompoundMeasurement {
+    components: Vec<Measurement>,
+}
+
+impl CompoundMeasurement {
+    /// Builds a `CompoundMeasurement` from `components`, in the order given.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::IncompatibleUnitTypes` if any of the components aren't compatible with
+    /// the first one.
+    ///
+    pub fn try_new(components: Vec<Measurement>) -> Result<Self, Error> {
+        if let Some((first, rest)) = components.split_first() {
+            for component in rest {
+                if !first.unit().is_compatible_with(component.unit()) {
+                    return Err(Error::IncompatibleUnitTypes {
+                        lhs: first.unit().expression(),
+                        rhs: component.unit().expression(),
+                    });
+                }
+            }
+        }
+
+        Ok(Self { components })
+    }
+
+    /// Splits `measurement` into one component per `Unit` in `units`. Every component but the
+    /// last holds a whole number; the last one holds what's left over, rounded according to
+    /// `rounding`. Rounding carries into the leading components, so 5 ft 11.8 in rounded to the
+    /// nearest inch gives "6 [ft_i] 0 [in_i]".
+    ///
+    /// Carrying only works if each leading unit is a whole number of the last unit (ex. feet and
+    /// inches, but not meters and feet), with the largest first; any `Rounding` other than
+    /// `Rounding::None` requires that.
+    ///
+    /// If `units` is empty, the result just wraps `measurement`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::IncompatibleUnitTypes` if any of `units` aren't compatible with
+    /// `measurement`'s `Unit`, or, when rounding, if a leading unit isn't a whole number of the
+    /// unit after it.
+    ///
+    pub fn decompose(
+        measurement: &Measurement,
+        units: &[Unit],
+        rounding: Rounding,
+    ) -> Result<Self, Error> {
+        let (last_unit, leading_units) = match units.split_last() {
+            Some(split) => split,
+            None => {
+                return Ok(Self {
+                    components: vec![measurement.clone()],
+                })
+            }
+        };
+
+        if rounding != Rounding::None {
+            check_whole_multiples(units)?;
+        }
+
+        // Round the total first so that any carrying happens naturally when splitting off the
+        // leading components.
+        let total = measurement.convert_to(last_unit)?;
+        let mut remaining = Measurement::new(rounding.apply(total.value()), last_unit.clone())?;
+        let mut components = Vec::with_capacity(units.len());
+
+        for unit in leading_units {
+            let converted = remaining.convert_to(unit)?;
+            let component = Measurement::new(whole_part(converted.value()), unit.clone())?;
+            remaining = (&remaining - &component)?;
+            components.push(component);
+        }
+
+        // Every unit is a whole number of the last one, so this only drops the floating point
+        // noise from subtracting.
+        if rounding != Rounding::None {
+            remaining = Measurement::new(remaining.value().round(), last_unit.clone())?;
+        }
+
+        components.push(remaining);
+
+        Ok(Self { components })
+    }
+
+    /// Accessor for the components, in order.
+    ///
+    #[must_use]
+    pub fn components(&self) -> &[Measurement] {
+        &self.components
+    }
+
+    /// Sums all components into a single `Measurement`, using the `Unit` of the first component.
+    /// Returns `None` if there are no components.
+    ///
+    #[must_use]
+    pub fn to_measurement(&self) -> Option<Measurement> {
+        let unit = self.components.first()?.unit();
+
+        self.convert_to(unit).ok()
+    }
+}
+
+/// Checks that each of `units` is a whole number (at least 1) of the unit after it, which makes
+/// them whole numbers of the last unit too.
+///
+fn check_whole_multiples(units: &[Unit]) -> Result<(), Error> {
+    for pair in units.windows(2) {
+        let (larger, smaller) = (&pair[0], &pair[1]);
+        let ratio = Measurement::new(1.0, larger.clone())?
+            .convert_to(smaller)?
+            .value();
+
+        let rounded = ratio.round();
+
+        if rounded < 1.0 || (ratio - rounded).abs() > WHOLE_NUMBER_TOLERANCE * rounded {
+            return Err(Error::IncompatibleUnitTypes {
+                lhs: larger.expression(),
+                rhs: smaller.expression(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Parsed values that are within `WHOLE_NUMBER_TOLERANCE` of a whole number are snapped to it;
+/// anything else is truncated towards zero.
+///
+fn whole_part(value: f64) -> f64 {
+    let rounded = value.round();
+
+    if (value - rounded).abs() <= WHOLE_NUMBER_TOLERANCE * rounded.abs().max(1.0) {
+        rounded
+    } else {
+        value.trunc()
+    }
+}
+
+fn parse_unit(expression: &str) -> Result<Unit, Error> {
+    let expression = ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == expression)
+        .map_or(expression, |(_, ucum)| ucum);
+
+    Unit::from_str(expression)
+}
+
+//-----------------------------------------------------------------------------
+// impl Convertible
+//-----------------------------------------------------------------------------
+/// Sums all components into a single `Measurement` of `unit`.
+///
+impl<'a> Convertible<&'a Unit> for CompoundMeasurement {
+    type Output = Measurement;
+    type ConversionError = Error;
+
+    fn convert_to(&self, unit: &'a Unit) -> Result<Self::Output, Self::ConversionError> {
+        self.components
+            .iter()
+            .try_fold(Measurement::new(0.0, unit.clone())?, |sum, component| {
+                sum + component
+            })
+    }
+}
+
+impl<'a> Convertible<&'a str> for CompoundMeasurement {
+    type Output = Measurement;
+    type ConversionError = Error;
+
+    fn convert_to(&self, expression: &'a str) -> Result<Self::Output, Self::ConversionError> {
+        let unit = Unit::from_str(expression)?;
+
+        self.convert_to(&unit)
+    }
+}
+
+//-----------------------------------------------------------------------------
+// impl FromStr
+//-----------------------------------------------------------------------------
+/// Parses whitespace-separated value/unit pairs, ex. "5 [ft_i] 9 [in_i]". Along with UCUM
+/// expressions, a handful of common abbreviations are accepted for units (ex. "5 ft 9 in").
+///
+impl FromStr for CompoundMeasurement {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokens = tokenize(s);
+        let mut components = Vec::new();
+
+        while let Some((position, value)) = tokens.next() {
+            let value = f64::from_str(value).map_err(|_| ParserError::BadFragment {
+                fragment: value.to_string(),
+                position,
+            })?;
+
+            let expression = match tokens.next() {
+                Some((_, expression)) => expression,
+                None => return Err(ParserError::UnknownUnitString(s.to_string()).into()),
+            };
+
+            components.push(Measurement::new(value, parse_unit(expression)?)?);
+        }
+
+        if components.is_empty() {
+            return Err(ParserError::UnknownUnitString(s.to_string()).into());
+        }
+
+        Self::try_new(components)
+    }
+}
+
+/// Splits `s` on whitespace, keeping track of where each token starts.
+///
+fn tokenize(s: &str) -> impl Iterator<Item = (usize, &str)> {
+    s.split_whitespace()
+        .map(move |token| (token.as_ptr() as usize - s.as_ptr() as usize, token))
+}
+
+//-----------------------------------------------------------------------------
+// impl Display
+//-----------------------------------------------------------------------------
+impl fmt::Display for CompoundMeasurement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut components = self.components.iter();
+
+        if let Some(first) = components.next() {
+            write!(f, "{}", first)?;
+        }
+
+        for component in components {
+            write!(f, " {}", component)?;
+        }
+
+        Ok(())
+    }
+}
+
+//-----------------------------------------------------------------------------
+// impl PartialEq, PartialOrd
+//-----------------------------------------------------------------------------
+/// Compares the sums of each `CompoundMeasurement`'s components; those with no components are
+/// treated as 0.
+///
+impl PartialEq for CompoundMeasurement {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for CompoundMeasurement {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let unit = match (self.components.first(), other.components.first()) {
+            (Some(component), _) | (None, Some(component)) => component.unit(),
+            (None, None) => return Some(Ordering::Equal),
+        };
+
+        let lhs = self.convert_to(unit).ok()?;
+        let rhs = other.convert_to(unit).ok()?;
+
+        if lhs == rhs {
+            Some(Ordering::Equal)
+        } else {
+            lhs.value().partial_cmp(&rhs.value())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CompoundMeasurement, Rounding};
+    use crate::{parser::Error as ParserError, Convertible, Error, Measurement, Unit};
+    use approx::assert_relative_eq;
+    use std::str::FromStr;
+
+    fn units(expressions: &[&str]) -> Vec<Unit> {
+        expressions
+            .iter()
+            .map(|expression| Unit::from_str(expression).unwrap())
+            .collect()
+    }
+
+    fn values(compound: &CompoundMeasurement) -> Vec<f64> {
+        compound
+            .components()
+            .iter()
+            .map(Measurement::value)
+            .collect()
+    }
+
+    #[test]
+    fn validate_try_new() {
+        let compound = CompoundMeasurement::try_new(vec![
+            Measurement::try_new(1.0, "h").unwrap(),
+            Measurement::try_new(30.0, "min").unwrap(),
+        ])
+        .unwrap();
+        assert_eq!(compound.components().len(), 2);
+
+        let error = CompoundMeasurement::try_new(vec![
+            Measurement::try_new(1.0, "h").unwrap(),
+            Measurement::try_new(30.0, "m").unwrap(),
+        ])
+        .unwrap_err();
+        assert_eq!(
+            error,
+            Error::IncompatibleUnitTypes {
+                lhs: "h".to_string(),
+                rhs: "m".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn validate_decompose_feet_inches() {
+        let measurement = Measurement::try_new(69.0, "[in_i]").unwrap();
+        let compound = CompoundMeasurement::decompose(
+            &measurement,
+            &units(&["[ft_i]", "[in_i]"]),
+            Rounding::None,
+        )
+        .unwrap();
+
+        assert_relative_eq!(compound.components()[0].value(), 5.0);
+        assert_relative_eq!(compound.components()[1].value(), 9.0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn validate_decompose_exact_multiple() {
+        let measurement = Measurement::try_new(72.0, "[in_i]").unwrap();
+        let compound = CompoundMeasurement::decompose(
+            &measurement,
+            &units(&["[ft_i]", "[in_i]"]),
+            Rounding::None,
+        )
+        .unwrap();
+
+        assert_eq!(values(&compound), vec![6.0, 0.0]);
+    }
+
+    #[test]
+    fn validate_decompose_rounding() {
+        let measurement = Measurement::try_new(1.8, "m").unwrap();
+        let units = units(&["[ft_i]", "[in_i]"]);
+
+        let compound =
+            CompoundMeasurement::decompose(&measurement, &units, Rounding::None).unwrap();
+        assert_relative_eq!(compound.components()[0].value(), 5.0);
+        assert_relative_eq!(
+            compound.components()[1].value(),
+            10.866_141_732_283_47,
+            max_relative = 1e-12
+        );
+
+        let compound =
+            CompoundMeasurement::decompose(&measurement, &units, Rounding::Nearest).unwrap();
+        assert_eq!(values(&compound), vec![5.0, 11.0]);
+
+        let compound =
+            CompoundMeasurement::decompose(&measurement, &units, Rounding::Down).unwrap();
+        assert_eq!(values(&compound), vec![5.0, 10.0]);
+
+        let compound = CompoundMeasurement::decompose(&measurement, &units, Rounding::Up).unwrap();
+        assert_eq!(values(&compound), vec![5.0, 11.0]);
+    }
+
+    #[test]
+    fn validate_decompose_rounding_carries() {
+        let measurement = Measurement::try_new(71.8, "[in_i]").unwrap();
+        let compound = CompoundMeasurement::decompose(
+            &measurement,
+            &units(&["[ft_i]", "[in_i]"]),
+            Rounding::Nearest,
+        )
+        .unwrap();
+
+        assert_eq!(values(&compound), vec![6.0, 0.0]);
+    }
+
+    #[test]
+    fn validate_decompose_three_components() {
+        let measurement = Measurement::try_new(93_784.0, "s").unwrap();
+        let compound = CompoundMeasurement::decompose(
+            &measurement,
+            &units(&["d", "h", "min", "s"]),
+            Rounding::Nearest,
+        )
+        .unwrap();
+
+        assert_eq!(values(&compound), vec![1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(compound.to_string(), "1 d 2 h 3 min 4 s");
+    }
+
+    #[test]
+    fn validate_decompose_negative() {
+        let measurement = Measurement::try_new(-69.0, "[in_i]").unwrap();
+        let compound = CompoundMeasurement::decompose(
+            &measurement,
+            &units(&["[ft_i]", "[in_i]"]),
+            Rounding::None,
+        )
+        .unwrap();
+
+        assert_relative_eq!(compound.components()[0].value(), -5.0);
+        assert_relative_eq!(compound.components()[1].value(), -9.0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn validate_decompose_non_whole_multiples() {
+        let measurement = Measurement::try_new(10.0, "[ft_i]").unwrap();
+        let meters_feet = units(&["m", "[ft_i]"]);
+
+        let result = CompoundMeasurement::decompose(&measurement, &meters_feet, Rounding::Nearest);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::IncompatibleUnitTypes {
+                lhs: "m".to_string(),
+                rhs: "[ft_i]".to_string()
+            }
+        );
+
+        // Without rounding, the components still sum to the input.
+        let compound =
+            CompoundMeasurement::decompose(&measurement, &meters_feet, Rounding::None).unwrap();
+        assert_relative_eq!(compound.components()[0].value(), 3.0);
+        assert_relative_eq!(
+            compound
+                .to_measurement()
+                .unwrap()
+                .convert_to("[ft_i]")
+                .unwrap()
+                .value(),
+            10.0,
+            max_relative = 1e-12
+        );
+
+        // Smallest first can't carry either.
+        let result = CompoundMeasurement::decompose(
+            &measurement,
+            &units(&["[in_i]", "[ft_i]"]),
+            Rounding::Down,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn validate_decompose_no_units() {
+        let measurement = Measurement::try_new(69.0, "[in_i]").unwrap();
+        let compound =
+            CompoundMeasurement::decompose(&measurement, &[], Rounding::Nearest).unwrap();
+
+        assert_eq!(compound.components(), &[measurement]);
+    }
+
+    #[test]
+    fn validate_decompose_incompatible() {
+        let measurement = Measurement::try_new(69.0, "[in_i]").unwrap();
+        let result =
+            CompoundMeasurement::decompose(&measurement, &units(&["h", "min"]), Rounding::None);
+
+        assert!(matches!(result, Err(Error::IncompatibleUnitTypes { .. })));
+    }
+
+    #[test]
+    fn validate_from_str_ucum() {
+        let compound = CompoundMeasurement::from_str("5 [ft_i] 9 [in_i]").unwrap();
+
+        assert_eq!(values(&compound), vec![5.0, 9.0]);
+        assert_eq!(compound.components()[0].unit().expression(), "[ft_i]");
+        assert_eq!(compound.components()[1].unit().expression(), "[in_i]");
+    }
+
+    #[test]
+    fn validate_from_str_aliases() {
+        let compound = CompoundMeasurement::from_str("5 ft  9.5 in").unwrap();
+
+        assert_eq!(values(&compound), vec![5.0, 9.5]);
+        assert_eq!(compound.components()[0].unit().expression(), "[ft_i]");
+        assert_eq!(compound.components()[1].unit().expression(), "[in_i]");
+
+        let compound = CompoundMeasurement::from_str("1 hr 30 min").unwrap();
+        assert_eq!(compound.to_string(), "1 h 30 min");
+    }
+
+    #[test]
+    fn validate_from_str_errors() {
+        assert_eq!(
+            CompoundMeasurement::from_str("5 ft nine in").unwrap_err(),
+            Error::ParsingFailed(ParserError::BadFragment {
+                fragment: "nine".to_string(),
+                position: 5
+            })
+        );
+        assert_eq!(
+            CompoundMeasurement::from_str("5 ft 9").unwrap_err(),
+            Error::ParsingFailed(ParserError::UnknownUnitString("5 ft 9".to_string()))
+        );
+        assert_eq!(
+            CompoundMeasurement::from_str("  ").unwrap_err(),
+            Error::ParsingFailed(ParserError::UnknownUnitString("  ".to_string()))
+        );
+        assert!(matches!(
+            CompoundMeasurement::from_str("5 ft 9 s").unwrap_err(),
+            Error::IncompatibleUnitTypes { .. }
+        ));
+        assert!(matches!(
+            CompoundMeasurement::from_str("5 ft 9 zzz").unwrap_err(),
+            Error::ParsingFailed(_)
+        ));
+    }
+
+    #[test]
+    fn validate_to_measurement() {
+        let compound = CompoundMeasurement::from_str("5 ft 9 in").unwrap();
+        let measurement = compound.to_measurement().unwrap();
+
+        assert_eq!(measurement.unit().expression(), "[ft_i]");
+        assert_relative_eq!(measurement.value(), 5.75);
+
+        assert!(CompoundMeasurement::default().to_measurement().is_none());
+    }
+
+    #[test]
+    fn validate_convert_to() {
+        let compound = CompoundMeasurement::from_str("5 ft 9 in").unwrap();
+
+        let measurement = compound.convert_to("[in_i]").unwrap();
+        assert_relative_eq!(measurement.value(), 69.0);
+
+        let measurement = compound.convert_to("cm").unwrap();
+        assert_relative_eq!(measurement.value(), 175.26, max_relative = 1e-12);
+
+        assert!(compound.convert_to("s").is_err());
+    }
+
+    #[test]
+    fn validate_partial_ord() {
+        let short = CompoundMeasurement::from_str("5 ft 9 in").unwrap();
+        let tall = CompoundMeasurement::from_str("6 ft 1 in").unwrap();
+        let same = CompoundMeasurement::from_str("69 in").unwrap();
+        let time = CompoundMeasurement::from_str("1 h").unwrap();
+
+        assert!(short < tall);
+        assert!(tall > short);
+        assert!(short == same);
+        assert!(short != time);
+        assert_eq!(short.partial_cmp(&time), None);
+        assert_eq!(
+            CompoundMeasurement::default(),
+            CompoundMeasurement::default()
+        );
+    }
+}
@@ -0,0 +1,422 @@
+//! Conversions between `Unit`s that aren't commensurable on their own, but are for a given
+//! substance: ex. volume to mass using a product's density, mass to amount using molar mass, or
+//! arbitrary units to mass using a potency.
+//!
+use crate::{
+    is_compatible_with::IsCompatibleWith,
+    parser::{Atom, Composable, Composition, Dimension},
+    ucum_unit::UcumUnit,
+    Error, Measurement, Unit,
+};
+
+/// A named ratio that relates two otherwise-incompatible `Composition`s, ex. a density of
+/// 1.2 kg/L relates mass and volume.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Equivalence {
+    name: String,
+    ratio: Measurement,
+}
+
+impl Equivalence {
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The `Measurement` that values get multiplied (or divided) by when this `Equivalence` is
+    /// used.
+    ///
+    #[must_use]
+    pub const fn ratio(&self) -> &Measurement {
+        &self.ratio
+    }
+}
+
+/// Holds the `Equivalence`s that `Measurement::convert_to_with()` may use.
+///
+/// ```
+/// use std::str::FromStr;
+/// use wise_units::{ConversionContext, Measurement, Unit};
+///
+/// let density = Measurement::try_new(8.34, "[lb_av]/[gal_us]").unwrap();
+/// let context = ConversionContext::new()
+///     .with_density("water", density)
+///     .unwrap();
+///
+/// let rate = Measurement::try_new(2.0, "[gal_us]/[acr_us]").unwrap();
+/// let lb_per_acre = Unit::from_str("[lb_av]/[acr_us]").unwrap();
+/// let converted = rate.convert_to_with(&lb_per_acre, &context).unwrap();
+///
+/// assert!((converted.measurement().value() - 16.68).abs() < 0.000_001);
+/// assert_eq!(converted.equivalences(), &["water"]);
+/// ```
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConversionContext {
+    equivalences: Vec<Equivalence>,
+}
+
+impl ConversionContext {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            equivalences: Vec::new(),
+        }
+    }
+
+    /// Adds an `Equivalence` that says `lhs` is the same amount of substance as `rhs`, ex.
+    /// 1 `[gal_us]` of a product is 8.34 `[lb_av]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::DivideByZero` if `lhs` is 0, or an `Error::NonFiniteValue` if the ratio
+    /// of `rhs` to `lhs` isn't finite.
+    ///
+    pub fn with_equivalence(
+        mut self,
+        name: &str,
+        lhs: &Measurement,
+        rhs: &Measurement,
+    ) -> Result<Self, Error> {
+        self.equivalences.push(Equivalence {
+            name: name.to_string(),
+            ratio: (rhs / lhs)?,
+        });
+
+        Ok(self)
+    }
+
+    /// Adds a density (mass/volume), ex. `kg/L`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::IncompatibleUnitTypes` if `density` isn't a mass per volume.
+    ///
+    pub fn with_density(self, name: &str, density: Measurement) -> Result<Self, Error> {
+        let expected = Composition::new_mass(1).with(Dimension::Length, -3);
+
+        self.with_ratio(name, density, expected, "g/L", <[(Atom, i32)]>::is_empty)
+    }
+
+    /// Adds a molar mass (mass/amount), ex. `g/mol`. In UCUM, `mol` is a dimensionless count, so
+    /// molar masses have the same `Composition` as a mass.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::IncompatibleUnitTypes` if `molar_mass` isn't a mass per `mol`.
+    ///
+    pub fn with_molar_mass(self, name: &str, molar_mass: Measurement) -> Result<Self, Error> {
+        self.with_ratio(
+            name,
+            molar_mass,
+            Composition::new_mass(1),
+            "g/mol",
+            |counts| matches!(counts, [(Atom::Mole, -1)]),
+        )
+    }
+
+    /// Adds a potency (mass/arbitrary unit), ex. `ug/[iU]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::IncompatibleUnitTypes` if `potency` isn't a mass per a single arbitrary
+    /// unit.
+    ///
+    pub fn with_potency(self, name: &str, potency: Measurement) -> Result<Self, Error> {
+        self.with_ratio(
+            name,
+            potency,
+            Composition::new_mass(1),
+            "ug/[iU]",
+            |counts| matches!(counts, [(atom, -1)] if atom.is_arbitrary()),
+        )
+    }
+
+    /// The `Equivalence`s that have been added, in order.
+    ///
+    #[must_use]
+    pub fn equivalences(&self) -> &[Equivalence] {
+        &self.equivalences
+    }
+
+    /// Adds `ratio` without checking it; for ratios that are known to be valid (ex. the built-in
+    /// `Commodity` test weights).
+    ///
+    pub(crate) fn with_ratio_unchecked(mut self, name: &str, ratio: Measurement) -> Self {
+        self.equivalences.push(Equivalence {
+            name: name.to_string(),
+            ratio,
+        });
+
+        self
+    }
+
+    fn with_ratio(
+        self,
+        name: &str,
+        ratio: Measurement,
+        expected: Composition,
+        expected_expression: &str,
+        expected_counts: fn(&[(Atom, i32)]) -> bool,
+    ) -> Result<Self, Error> {
+        if ratio.unit().composition() != expected
+            || !expected_counts(&counted_atoms(ratio.unit(), &[], 1))
+        {
+            return Err(Error::IncompatibleUnitTypes {
+                lhs: ratio.unit().expression(),
+                rhs: expected_expression.to_string(),
+            });
+        }
+
+        Ok(self.with_ratio_unchecked(name, ratio))
+    }
+
+    /// Searches for the combination of the fewest `Equivalence`s (each used at most once, either
+    /// multiplied or divided) that turns `measurement` into something compatible with `unit`.
+    /// Since the order that ratios are applied in doesn't matter, only combinations (not
+    /// permutations) are checked.
+    ///
+    /// Amounts (`mol`) and arbitrary units (ex. `[iU]`) are dimensionless, so `Composition`s alone
+    /// can't tell a molar mass from a potency; candidates also have to end up with the same net
+    /// exponents of those `Atom`s as `unit`.
+    ///
+    /// Whether a candidate can be converted only depends on its `Composition` and those counts, so
+    /// once a combination has reached some pair of them, longer combinations that reach the same
+    /// pair aren't searched any further. That keeps the search to the number of distinct pairs
+    /// instead of every combination of `Equivalence`s.
+    ///
+    pub(crate) fn find_path(
+        &self,
+        measurement: &Measurement,
+        unit: &Unit,
+    ) -> Option<(Measurement, Vec<usize>)> {
+        // Multiplying `Measurement`s can fold dimensionless `Atom`s into the value, so their
+        // counts are tracked separately from each candidate's `Unit`.
+        let target_counts = counted_atoms(unit, &[], 1);
+        let start_counts = counted_atoms(measurement.unit(), &[], 1);
+        let mut visited = vec![(measurement.unit().composition(), start_counts.clone())];
+        let mut frontier = vec![(measurement.clone(), Vec::<usize>::new(), start_counts)];
+
+        for _ in 0..=self.equivalences.len() {
+            if let Some((candidate, path, _)) = frontier.iter().find(|(candidate, _, counts)| {
+                candidate.unit().is_compatible_with(unit) && *counts == target_counts
+            }) {
+                return Some((candidate.clone(), path.clone()));
+            }
+
+            let mut next = Vec::new();
+
+            for (candidate, used, counts) in &frontier {
+                let start = used.last().map_or(0, |last| last + 1);
+
+                for (index, equivalence) in self.equivalences.iter().enumerate().skip(start) {
+                    let mut path = used.clone();
+                    path.push(index);
+
+                    // Candidates whose values overflow can't be the answer, so they're dropped.
+                    let products = vec![
+                        (candidate * &equivalence.ratio, 1),
+                        (candidate / &equivalence.ratio, -1),
+                    ];
+
+                    for (product, sign) in products {
+                        let product = match product {
+                            Ok(product) => product,
+                            Err(_) => continue,
+                        };
+                        let state = (
+                            product.unit().composition(),
+                            counted_atoms(equivalence.ratio.unit(), counts, sign),
+                        );
+
+                        if !visited.contains(&state) {
+                            next.push((product, path.clone(), state.1.clone()));
+                            visited.push(state);
+                        }
+                    }
+                }
+            }
+
+            frontier = next;
+        }
+
+        None
+    }
+}
+
+/// Adds the net exponents (times `sign`) of the dimensionless, but still meaningful, `Atom`s in
+/// `unit` to `counts`. The result is sorted by `Atom`.
+///
+fn counted_atoms(unit: &Unit, counts: &[(Atom, i32)], sign: i32) -> Vec<(Atom, i32)> {
+    let mut counts = counts.to_vec();
+
+    for term in unit.terms() {
+        let atom = match term.atom {
+            Some(atom) if atom == Atom::Mole || atom.is_arbitrary() => atom,
+            _ => continue,
+        };
+        let exponent = term.exponent.unwrap_or(1) * sign;
+
+        match counts.iter_mut().find(|(counted, _)| *counted == atom) {
+            Some((_, sum)) => *sum += exponent,
+            None => counts.push((atom, exponent)),
+        }
+    }
+
+    counts.retain(|(_, exponent)| *exponent != 0);
+    counts.sort_by_key(|(key, _)| *key);
+
+    counts
+}
+
+/// The result of `Measurement::convert_to_with()`: the converted `Measurement`, plus the names of
+/// the `Equivalence`s that were used to get there.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContextConversion {
+    measurement: Measurement,
+    equivalences: Vec<String>,
+}
+
+impl ContextConversion {
+    pub(crate) fn new(measurement: Measurement, equivalences: Vec<String>) -> Self {
+        Self {
+            measurement,
+            equivalences,
+        }
+    }
+
+    #[must_use]
+    pub const fn measurement(&self) -> &Measurement {
+        &self.measurement
+    }
+
+    /// Names of the `Equivalence`s that were used; empty if the `Unit`s were already compatible.
+    ///
+    #[must_use]
+    pub fn equivalences(&self) -> &[String] {
+        &self.equivalences
+    }
+
+    #[must_use]
+    pub fn into_measurement(self) -> Measurement {
+        self.measurement
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConversionContext;
+    use crate::{Error, Measurement};
+    use std::str::FromStr;
+
+    #[test]
+    fn validate_with_density() {
+        let density = Measurement::try_new(1.2, "kg/L").unwrap();
+        let context = ConversionContext::new()
+            .with_density("foo", density)
+            .unwrap();
+
+        assert_eq!(context.equivalences().len(), 1);
+        assert_eq!(context.equivalences()[0].name(), "foo");
+    }
+
+    #[test]
+    fn validate_with_density_wrong_composition() {
+        let density = Measurement::try_new(1.2, "kg/m").unwrap();
+        let error = ConversionContext::new()
+            .with_density("foo", density)
+            .unwrap_err();
+
+        assert_eq!(
+            error,
+            Error::IncompatibleUnitTypes {
+                lhs: "kg/m".to_string(),
+                rhs: "g/L".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn validate_with_molar_mass_wrong_composition() {
+        let molar_mass = Measurement::try_new(180.0, "g/L").unwrap();
+        assert!(ConversionContext::new()
+            .with_molar_mass("glucose", molar_mass)
+            .is_err());
+    }
+
+    #[test]
+    fn validate_with_molar_mass_wrong_amount() {
+        for expression in &["g", "g/[iU]", "g.mol", "g/mol2"] {
+            let molar_mass = Measurement::try_new(180.0, expression).unwrap();
+            assert_eq!(
+                ConversionContext::new()
+                    .with_molar_mass("glucose", molar_mass)
+                    .unwrap_err(),
+                Error::IncompatibleUnitTypes {
+                    lhs: (*expression).to_string(),
+                    rhs: "g/mol".to_string()
+                }
+            );
+        }
+
+        let molar_mass = Measurement::try_new(0.18, "kg/mmol").unwrap();
+        assert!(ConversionContext::new()
+            .with_molar_mass("glucose", molar_mass)
+            .is_ok());
+    }
+
+    #[test]
+    fn validate_with_potency_wrong_unit() {
+        for expression in &["ug", "ug/mol", "ug.[iU]", "ug/([iU].[USP'U])"] {
+            let potency = Measurement::try_new(0.025, expression).unwrap();
+            assert!(
+                ConversionContext::new()
+                    .with_potency("vitamin D", potency)
+                    .is_err(),
+                "{}",
+                expression
+            );
+        }
+
+        let potency = Measurement::try_new(0.025, "mg/[USP'U]").unwrap();
+        assert!(ConversionContext::new()
+            .with_potency("vitamin D", potency)
+            .is_ok());
+    }
+
+    #[test]
+    fn validate_find_path_many_equivalences() {
+        // Without pruning, this would check 3^40 combinations.
+        let mut context = ConversionContext::new();
+
+        for index in 0..40 {
+            let density = Measurement::try_new(1.0 + f64::from(index), "kg/L").unwrap();
+            context = context.with_density(&index.to_string(), density).unwrap();
+        }
+
+        let volume = Measurement::try_new(1.0, "L").unwrap();
+        let unit = crate::Unit::from_str("g/mol").unwrap();
+        assert!(context.find_path(&volume, &unit).is_none());
+
+        let unit = crate::Unit::from_str("kg").unwrap();
+        let (measurement, path) = context.find_path(&volume, &unit).unwrap();
+        assert_eq!(path, vec![0]);
+        assert!((measurement.value() - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn validate_with_equivalence() {
+        let gallon = Measurement::try_new(1.0, "[gal_us]").unwrap();
+        let pounds = Measurement::try_new(8.34, "[lb_av]").unwrap();
+        let context = ConversionContext::new()
+            .with_equivalence("water", &gallon, &pounds)
+            .unwrap();
+
+        assert_eq!(
+            context.equivalences()[0].ratio().unit().expression(),
+            "[lb_av]/[gal_us]"
+        );
+    }
+}
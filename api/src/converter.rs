@@ -0,0 +1,347 @@
+//! Converting many values from one `Unit` to another, ex. a column of yield-monitor readings.
+//!
+//! `Measurement::convert_to()` checks compatibility and calculates both `Unit`s' scalars for each
+//! value it converts; a `Converter` does that once, up front, then applies the result to each
+//! value.
+//!
+use crate::{
+    is_compatible_with::IsCompatibleWith,
+    measurement::converted_value,
+    parser::{Atom, Term},
+    ucum_unit::UcumUnit,
+    Error, Unit,
+};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// How many values each task converts in the `par_*` methods. Each chunk is converted by the
+/// same loop as `convert_slice()`, so it can still be vectorized.
+///
+#[cfg(feature = "rayon")]
+const PARALLEL_CHUNK_SIZE: usize = 4096;
+
+/// What a `Converter` does to each value.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Plan {
+    /// Neither `Unit` is special, so converting is multiplying by the ratio of their scalars.
+    Factor(f64),
+
+    /// Temperatures (ex. `Cel` to `[degF]`): multiply, then add.
+    Affine { factor: f64, offset: f64 },
+
+    /// Any other special `Unit`s: each value goes through their conversion functions.
+    Special,
+}
+
+/// Converts values from one `Unit` to another, having checked once that the `Unit`s are
+/// compatible.
+///
+/// ```
+/// use std::str::FromStr;
+/// use wise_units::{Converter, Unit};
+///
+/// let from = Unit::from_str("[bu_us]/[acr_us]").unwrap();
+/// let to = Unit::from_str("L/har").unwrap();
+/// let converter = Converter::new(&from, &to).unwrap();
+///
+/// let yields = [150.0, 175.5, 201.25];
+/// let mut converted = [0.0; 3];
+/// converter.convert_slice(&yields, &mut converted);
+///
+/// assert!((converted[0] - 13_061.59).abs() < 0.01);
+/// ```
+///
+/// Results aren't checked like `Measurement::convert_to()` checks them; converting with special
+/// units (ex. `[pH]`) can produce NaN or infinite values.
+///
+#[derive(Clone, Debug)]
+pub struct Converter {
+    from: Unit,
+    to: Unit,
+    plan: Plan,
+}
+
+impl Converter {
+    /// # Errors
+    ///
+    /// Returns an `Error::IncompatibleUnitTypes` if `from` can't be converted to `to`.
+    ///
+    pub fn new(from: &Unit, to: &Unit) -> Result<Self, Error> {
+        if !from.is_compatible_with(to) {
+            return Err(Error::IncompatibleUnitTypes {
+                lhs: from.expression(),
+                rhs: to.expression(),
+            });
+        }
+
+        let plan = if !from.is_special() && !to.is_special() {
+            Plan::Factor(from.scalar() / to.scalar())
+        } else if is_affine(from) && is_affine(to) {
+            let offset = converted_value(from, 0.0, to);
+
+            Plan::Affine {
+                factor: converted_value(from, 1.0, to) - offset,
+                offset,
+            }
+        } else {
+            Plan::Special
+        };
+
+        Ok(Self {
+            from: from.clone(),
+            to: to.clone(),
+            plan,
+        })
+    }
+
+    #[must_use]
+    pub const fn from(&self) -> &Unit {
+        &self.from
+    }
+
+    #[must_use]
+    pub const fn to(&self) -> &Unit {
+        &self.to
+    }
+
+    /// Converts one `value` of `from()` to `to()`.
+    ///
+    #[must_use]
+    #[inline]
+    pub fn convert(&self, value: f64) -> f64 {
+        match self.plan {
+            Plan::Factor(factor) => value * factor,
+            Plan::Affine { factor, offset } => affine(value, factor, offset),
+            Plan::Special => converted_value(&self.from, value, &self.to),
+        }
+    }
+
+    /// Converts each of `values` into the same index of `output`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` and `output` aren't the same length.
+    ///
+    pub fn convert_slice(&self, values: &[f64], output: &mut [f64]) {
+        assert_eq!(
+            values.len(),
+            output.len(),
+            "values and output must be the same length"
+        );
+
+        // Matching once, outside of the loops, leaves each loop simple enough to vectorize.
+        match self.plan {
+            Plan::Factor(factor) => {
+                for (out, value) in output.iter_mut().zip(values) {
+                    *out = value * factor;
+                }
+            }
+            Plan::Affine { factor, offset } => {
+                for (out, value) in output.iter_mut().zip(values) {
+                    *out = affine(*value, factor, offset);
+                }
+            }
+            Plan::Special => {
+                for (out, &value) in output.iter_mut().zip(values) {
+                    *out = converted_value(&self.from, value, &self.to);
+                }
+            }
+        }
+    }
+
+    /// Like `convert_slice()`, but replaces each of `values` with its converted value.
+    ///
+    pub fn convert_in_place(&self, values: &mut [f64]) {
+        match self.plan {
+            Plan::Factor(factor) => {
+                for value in values {
+                    *value *= factor;
+                }
+            }
+            Plan::Affine { factor, offset } => {
+                for value in values {
+                    *value = affine(*value, factor, offset);
+                }
+            }
+            Plan::Special => {
+                for value in values {
+                    *value = converted_value(&self.from, *value, &self.to);
+                }
+            }
+        }
+    }
+
+    /// Like `convert_slice()`, but splits the values into chunks that get converted in parallel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` and `output` aren't the same length.
+    ///
+    #[cfg(feature = "rayon")]
+    pub fn par_convert_slice(&self, values: &[f64], output: &mut [f64]) {
+        assert_eq!(
+            values.len(),
+            output.len(),
+            "values and output must be the same length"
+        );
+
+        output
+            .par_chunks_mut(PARALLEL_CHUNK_SIZE)
+            .zip(values.par_chunks(PARALLEL_CHUNK_SIZE))
+            .for_each(|(output, values)| self.convert_slice(values, output));
+    }
+
+    /// Like `convert_in_place()`, but splits the values into chunks that get converted in
+    /// parallel.
+    ///
+    #[cfg(feature = "rayon")]
+    pub fn par_convert_in_place(&self, values: &mut [f64]) {
+        values
+            .par_chunks_mut(PARALLEL_CHUNK_SIZE)
+            .for_each(|values| self.convert_in_place(values));
+    }
+}
+
+// `mul_add()` is a slow library call on targets without FMA instructions, and keeps loops from
+// being vectorized.
+#[allow(clippy::suboptimal_flops)]
+#[inline]
+fn affine(value: f64, factor: f64, offset: f64) -> f64 {
+    value * factor + offset
+}
+
+/// Whether `unit` converts by a scale and an offset: it's either not special, or its only
+/// special `Term` is a temperature like `Cel` (with no exponent, since squaring an offset isn't
+/// affine).
+///
+fn is_affine(unit: &Unit) -> bool {
+    let mut special_terms = unit.terms().iter().filter(|term| term.is_special());
+
+    match (special_terms.next(), special_terms.next()) {
+        (None, _) => true,
+        (Some(term), None) => is_affine_term(term),
+        (Some(_), Some(_)) => false,
+    }
+}
+
+/// The special atoms whose conversion functions are just a scale and an offset.
+///
+const fn is_affine_term(term: &Term) -> bool {
+    matches!(term.exponent, None | Some(1))
+        && matches!(
+            term.atom,
+            Some(Atom::DegreeCelsius | Atom::DegreeFahrenheit | Atom::DegreeReaumur)
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Converter, Plan};
+    use crate::{Convertible, Measurement, Unit};
+    use approx::assert_relative_eq;
+    use std::str::FromStr;
+
+    fn converter(from: &str, to: &str) -> Converter {
+        Converter::new(&Unit::from_str(from).unwrap(), &Unit::from_str(to).unwrap()).unwrap()
+    }
+
+    /// Checks that `converter` gets (nearly) the same results as `Measurement::convert_to()`.
+    ///
+    fn validate_matches_convert_to(converter: &Converter, values: &[f64]) {
+        let mut output = vec![0.0; values.len()];
+        converter.convert_slice(values, &mut output);
+
+        let mut in_place = values.to_vec();
+        converter.convert_in_place(&mut in_place);
+
+        for ((&value, &converted), &converted_in_place) in values.iter().zip(&output).zip(&in_place)
+        {
+            let expected = Measurement::new(value, converter.from().clone())
+                .unwrap()
+                .convert_to(converter.to())
+                .unwrap()
+                .value();
+
+            assert_relative_eq!(converter.convert(value), expected, max_relative = 1e-12);
+            assert_relative_eq!(converted, expected, max_relative = 1e-12);
+            assert_relative_eq!(converted_in_place, expected, max_relative = 1e-12);
+        }
+    }
+
+    #[test]
+    fn validate_factor() {
+        let converter = converter("[bu_us]/[acr_us]", "L/har");
+        assert!(matches!(converter.plan, Plan::Factor(_)));
+
+        validate_matches_convert_to(&converter, &[0.0, 1.0, 150.0, 175.5, -3.25]);
+    }
+
+    #[test]
+    fn validate_same_unit() {
+        let converter = converter("kg", "kg");
+        assert_eq!(converter.plan, Plan::Factor(1.0));
+    }
+
+    #[test]
+    fn validate_affine() {
+        for (from, to) in &[
+            ("Cel", "[degF]"),
+            ("[degF]", "Cel"),
+            ("K", "Cel"),
+            ("Cel", "K"),
+            ("[degRe]", "[degF]"),
+        ] {
+            let converter = converter(from, to);
+            assert!(matches!(converter.plan, Plan::Affine { .. }), "{}", from);
+
+            validate_matches_convert_to(&converter, &[-40.0, 0.0, 1.0, 37.0, 100.0]);
+        }
+
+        assert_relative_eq!(
+            converter("Cel", "[degF]").convert(100.0),
+            212.0,
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn validate_special() {
+        let converter = converter("[pH]", "mol/l");
+        assert_eq!(converter.plan, Plan::Special);
+
+        validate_matches_convert_to(&converter, &[1.0, 7.0, 14.0]);
+    }
+
+    #[test]
+    fn validate_incompatible() {
+        let result = Converter::new(&Unit::from_str("m").unwrap(), &Unit::from_str("g").unwrap());
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "values and output must be the same length")]
+    fn validate_convert_slice_lengths() {
+        converter("m", "km").convert_slice(&[1.0, 2.0], &mut [0.0]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn validate_parallel() {
+        let converter = converter("Cel", "[degF]");
+        let values: Vec<f64> = (0..10_000).map(f64::from).collect();
+
+        let mut expected = vec![0.0; values.len()];
+        converter.convert_slice(&values, &mut expected);
+
+        let mut output = vec![0.0; values.len()];
+        converter.par_convert_slice(&values, &mut output);
+        assert_eq!(output, expected);
+
+        let mut in_place = values;
+        converter.par_convert_in_place(&mut in_place);
+        assert_eq!(in_place, expected);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::parser::Error as ParserError;
+use crate::{commodity::CommodityError, parser::Error as ParserError};
 
 #[derive(Clone, Debug, thiserror::Error, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
@@ -11,4 +11,53 @@ pub enum Error {
 
     #[error("Operation caused a divide by 0")]
     DivideByZero,
+
+    /// A `Measurement`'s value (given, or resulting from an operation) was NaN or infinite. Use
+    /// `Measurement::new_unchecked()` to create one anyway.
+    ///
+    #[error("Value is not finite: {0}")]
+    NonFiniteValue(f64),
+
+    /// Special units (ex. `Cel`, `[pH]`) aren't simple multiples of their base units, so they
+    /// can't be raised to a power or have a root taken.
+    ///
+    #[error("Operation not supported for special unit: {0:?}")]
+    SpecialUnitNotSupported(String),
+
+    #[error("Exponent overflowed raising {unit:?} to the power of {exponent}")]
+    ExponentOverflow { unit: String, exponent: i32 },
+
+    /// Multiplying or dividing `Unit`s would have overflowed one of the resulting exponents.
+    ///
+    #[error("Exponent overflowed combining {lhs:?} and {rhs:?}")]
+    CombinedExponentOverflow { lhs: String, rhs: String },
+
+    #[error(
+        "Unable to take root {degree} of {unit:?}; all exponents must be divisible by {degree}"
+    )]
+    NoExactRoot { unit: String, degree: u32 },
+
+    /// A `UnitBuilder` was used to build something that isn't a valid `Unit`.
+    ///
+    #[error("Invalid unit: {0}")]
+    InvalidUnit(String),
+
+    #[error(transparent)]
+    Commodity(#[from] CommodityError),
+
+    /// Conversion factors (ex. for `NutrientTable`) have to be finite and greater than 0.
+    ///
+    #[error("Invalid conversion factor: {0}")]
+    InvalidFactor(f64),
+
+    /// The `Measurement` (shown in the message) is too large, negative, or not finite for the
+    /// duration type it was being converted to.
+    ///
+    #[error("Measurement can't be represented as a duration: {0}")]
+    DurationOutOfRange(String),
+
+    /// Operations between two `MeasurementVec`s need them to have the same number of values.
+    ///
+    #[error("Lengths don't match: {lhs}, {rhs}")]
+    LengthMismatch { lhs: usize, rhs: usize },
 }
@@ -4,7 +4,7 @@ use crate::parser::Composable;
 /// compatible. Typically this comparison is made via each type's `Composition`, but the trait
 /// does not enforce that.
 ///
-pub trait IsCompatibleWith<RHS = Self> {
+pub trait IsCompatibleWith<RHS: ?Sized = Self> {
     fn is_compatible_with(&self, rhs: &RHS) -> bool;
 }
 
@@ -20,27 +20,48 @@
 #[macro_use]
 mod macros;
 
+pub mod agronomy;
 pub mod as_fraction;
+pub mod commodity;
+pub mod compound_measurement;
+pub mod conversion_context;
+pub mod converter;
 pub mod convertible;
 pub mod error;
 pub mod field_eq;
 pub mod invert;
 pub mod is_compatible_with;
 pub mod measurement;
+pub mod measurement_vec;
+pub mod nutrient;
 pub mod parser;
+#[cfg(feature = "typenum")]
+pub mod quantity;
 pub mod reduce;
+pub mod row_spacing;
 pub mod unit;
+pub mod unit_preferences;
+pub mod units;
 
 mod reducible;
 mod ucum_unit;
 
+pub use crate::compound_measurement::CompoundMeasurement;
+pub use crate::conversion_context::ConversionContext;
+pub use crate::converter::Converter;
 pub use crate::convertible::Convertible;
 pub use crate::error::Error;
 pub use crate::field_eq::FieldEq;
 pub use crate::is_compatible_with::IsCompatibleWith;
-pub use crate::measurement::Measurement;
+#[cfg(feature = "chrono")]
+pub use crate::measurement::CalendarPolicy;
+pub use crate::measurement::{Measurement, ScalePolicy};
+pub use crate::measurement_vec::MeasurementVec;
 pub use crate::parser::{
     Atom, Classification, Composable, Composition, Dimension, Prefix, Property, Term, UcumSymbol,
 };
+#[cfg(feature = "typenum")]
+pub use crate::quantity::Quantity;
 pub use crate::ucum_unit::UcumUnit;
-pub use crate::unit::Unit;
+pub use crate::unit::{Unit, UnitBuilder};
+pub use crate::unit_preferences::UnitPreferences;
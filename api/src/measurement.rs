@@ -1,16 +1,28 @@
+#[cfg(feature = "chrono")]
+mod calendar;
 mod composable;
+mod convert_to_reciprocal;
+mod convert_to_with;
 mod convertible;
 mod display;
+mod duration;
 mod field_eq;
 mod invert;
 mod is_compatible_with;
 mod ops;
 mod partial_eq;
 mod partial_ord;
+mod pow;
 mod reducible;
+mod to_preferred;
 mod to_reduced;
+mod to_scaled;
 mod ucum_unit;
 
+#[cfg(feature = "chrono")]
+pub use self::calendar::CalendarPolicy;
+pub use self::to_scaled::ScalePolicy;
+
 use crate::error::Error;
 use crate::reducible::Reducible;
 use crate::ucum_unit::UcumUnit;
@@ -41,6 +53,7 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "cffi", derive(FFI))]
 #[derive(Clone, Debug)]
 pub struct Measurement {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_finite"))]
     value: f64,
     unit: Unit,
 }
@@ -50,15 +63,14 @@ impl Measurement {
     ///
     /// # Errors
     ///
-    /// Returns an `Error` if `expression` isn't one that represents a valid `Unit`.
+    /// Returns an `Error` if `expression` isn't one that represents a valid `Unit`, or an
+    /// `Error::NonFiniteValue` if `value` is NaN or infinite.
     ///
     #[inline]
     pub fn try_new(value: f64, expression: &str) -> Result<Self, Error> {
         let unit = Unit::from_str(expression)?;
 
-        let m = Self { value, unit };
-
-        Ok(m)
+        Self::new(value, unit)
     }
 
     /// Standard constructor.
@@ -67,13 +79,38 @@ impl Measurement {
     /// use wise_units::Measurement;
     ///
     /// let m1 = Measurement::try_new(10.0, "m").unwrap();
-    /// let m2 = Measurement::new(10.0, m1.unit().clone());
+    /// let m2 = Measurement::new(10.0, m1.unit().clone()).unwrap();
     ///
     /// assert_eq!(m1, m2);
+    /// assert!(Measurement::new(f64::NAN, m1.unit().clone()).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::NonFiniteValue` if `value` is NaN or infinite.
+    ///
+    #[inline]
+    pub fn new(value: f64, unit: Unit) -> Result<Self, Error> {
+        Ok(Self {
+            value: finite(value)?,
+            unit,
+        })
+    }
+
+    /// Creates a new `Measurement` without checking that `value` is finite, for callers that want
+    /// to carry NaN or infinite values. Operations on the result (arithmetic, conversions, etc.)
+    /// still return an `Error::NonFiniteValue` if their result isn't finite.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wise_units::{Measurement, Unit};
+    ///
+    /// let m = Measurement::new_unchecked(f64::INFINITY, Unit::from_str("m").unwrap());
+    /// assert!(m.value().is_infinite());
     /// ```
     ///
     #[must_use]
-    pub const fn new(value: f64, unit: Unit) -> Self {
+    pub const fn new_unchecked(value: f64, unit: Unit) -> Self {
         Self { value, unit }
     }
 
@@ -94,27 +131,55 @@ impl Measurement {
     }
 
     /// The value of the `Measurement` in terms of `other_unit`. Only used for
-    /// converting, and does not check the compatibility of units.
+    /// converting, and does not check the compatibility of units (nor that the result is finite).
     ///
     fn converted_scalar(&self, other_unit: &Unit) -> f64 {
-        if self.is_special() && other_unit.is_special() {
-            let ts = self.unit.reduce_value(self.value);
-            other_unit.calculate_magnitude(ts)
-        } else if self.is_special() {
-            self.unit.reduce_value(self.value)
-        } else if other_unit.is_special() {
-            other_unit.calculate_magnitude(self.value)
-        } else {
-            self.scalar() / other_unit.reduce_value(1.0)
-        }
+        converted_value(&self.unit, self.value, other_unit)
+    }
+}
+
+/// `value` of `unit`, in terms of `other_unit`. Doesn't check the compatibility of the units
+/// (nor that the result is finite).
+///
+pub(crate) fn converted_value(unit: &Unit, value: f64, other_unit: &Unit) -> f64 {
+    if unit.is_special() && other_unit.is_special() {
+        let ts = unit.reduce_value(value);
+        other_unit.calculate_magnitude(ts)
+    } else if unit.is_special() {
+        unit.reduce_value(value)
+    } else if other_unit.is_special() {
+        other_unit.calculate_magnitude(value)
+    } else {
+        value * unit.scalar() / other_unit.scalar()
     }
 }
 
+/// Passes `value` through if it's finite; otherwise returns an `Error::NonFiniteValue`. Every
+/// operation that produces a new value for a `Measurement` runs its result through this.
+///
+pub(crate) fn finite(value: f64) -> Result<f64, Error> {
+    if value.is_finite() {
+        Ok(value)
+    } else {
+        Err(Error::NonFiniteValue(value))
+    }
+}
+
+#[cfg(feature = "serde")]
+fn deserialize_finite<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value = f64::deserialize(deserializer)?;
+
+    finite(value).map_err(serde::de::Error::custom)
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::parser::{Atom, Term};
     use super::*;
-    use crate::unit::Unit;
+    use crate::{unit::Unit, Convertible};
     use approx::{assert_relative_eq, assert_ulps_eq};
     use std::str::FromStr;
 
@@ -127,6 +192,35 @@ mod tests {
         assert_eq!(m.unit, Unit::new(vec![term!(Meter)]));
     }
 
+    #[test]
+    fn validate_new_non_finite() {
+        let unit = Unit::from_str("m").unwrap();
+
+        assert_eq!(
+            Measurement::new(f64::INFINITY, unit.clone()).unwrap_err(),
+            Error::NonFiniteValue(f64::INFINITY)
+        );
+        assert_eq!(
+            Measurement::try_new(f64::NEG_INFINITY, "m").unwrap_err(),
+            Error::NonFiniteValue(f64::NEG_INFINITY)
+        );
+        assert!(matches!(
+            Measurement::try_new(f64::NAN, "m").unwrap_err(),
+            Error::NonFiniteValue(value) if value.is_nan()
+        ));
+
+        let m = Measurement::new_unchecked(f64::NAN, unit);
+        assert!(m.value().is_nan());
+        assert!(matches!(
+            m.convert_to("km").unwrap_err(),
+            Error::NonFiniteValue(_)
+        ));
+        assert!(matches!(
+            m.convert_to("m").unwrap_err(),
+            Error::NonFiniteValue(_)
+        ));
+    }
+
     #[test]
     fn validate_converted_scalar() {
         // No special units
@@ -222,6 +316,15 @@ mod tests {
             assert!(measurement.is_err());
         }
 
+        #[test]
+        fn validate_deserialize_non_finite() {
+            let unchecked = Measurement::new_unchecked(f64::NAN, crate::Unit::new_unity());
+            let encoded: Vec<u8> = bincode::serialize(&unchecked).unwrap();
+            let decoded: Result<Measurement, _> = bincode::deserialize(&encoded);
+
+            assert!(decoded.is_err());
+        }
+
         #[allow(box_pointers)]
         #[test]
         fn validate_bincode_serde() {
@@ -0,0 +1,526 @@
+use super::{
+    duration::{duration_to_seconds, seconds_to_duration},
+    Measurement,
+};
+use crate::{
+    parser::{Atom, Composable, Composition},
+    Convertible, Error, Unit,
+};
+use chrono::{Datelike, Duration, Months, NaiveDate, NaiveDateTime};
+use std::convert::TryFrom;
+
+/// UCUM's month (`mo`) and year (`a`) are mean durations (1 `mo` is 30.4375 days), so an amount
+/// of them isn't an exact amount of time until it's anchored to a date. This decides whether
+/// they're counted as calendar months and years, and how.
+///
+/// The explicitly mean Julian and Gregorian units (`mo_j`, `mo_g`, `a_j`, `a_g`), astronomical
+/// units (`mo_s`, `a_t`), and everything else with a time dimension (`s`, `d`, `wk`, ...) are
+/// always exact (ex. 1 `mo_g` is 30.436875 days).
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CalendarPolicy {
+    /// Ignore the calendar and use the UCUM mean durations for `mo` and `a` too.
+    ///
+    MeanDuration,
+
+    /// Count calendar months; if the anchor's day doesn't exist in the resulting month, use the
+    /// last day of that month (3 `mo` from 2026-01-31 is 2026-04-30). A fractional month is that
+    /// fraction of the calendar month that follows the whole months.
+    ///
+    Clamp,
+
+    /// Count calendar months; if the anchor's day doesn't exist in the resulting month, carry
+    /// the extra days into the next month (1 `mo` from 2026-01-31 is 2026-03-03). A fractional
+    /// month is handled the same as in `Clamp`.
+    ///
+    Overflow,
+}
+
+impl Default for CalendarPolicy {
+    fn default() -> Self {
+        Self::Clamp
+    }
+}
+
+impl Measurement {
+    /// Same as `to_calendar_duration_with()`, using `CalendarPolicy::Clamp`.
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use wise_units::Measurement;
+    ///
+    /// let anchor = NaiveDate::from_ymd_opt(2026, 1, 31)
+    ///     .unwrap()
+    ///     .and_hms_opt(0, 0, 0)
+    ///     .unwrap();
+    /// let three_months = Measurement::try_new(3.0, "mo").unwrap();
+    ///
+    /// let duration = three_months.to_calendar_duration(anchor).unwrap();
+    /// assert_eq!(duration.num_days(), 89); // 2026-04-30
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// See `to_calendar_duration_with()`.
+    ///
+    pub fn to_calendar_duration(&self, anchor: NaiveDateTime) -> Result<Duration, Error> {
+        self.to_calendar_duration_with(anchor, CalendarPolicy::default())
+    }
+
+    /// How much time `self` is, starting at `anchor`. Months and years are counted on the
+    /// calendar as `policy` says; all other time units are converted exactly.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::IncompatibleUnitTypes` if `self` isn't a time, or an
+    /// `Error::DurationOutOfRange` if the result doesn't fit in a `chrono::Duration`.
+    ///
+    pub fn to_calendar_duration_with(
+        &self,
+        anchor: NaiveDateTime,
+        policy: CalendarPolicy,
+    ) -> Result<Duration, Error> {
+        expect_time(&self.unit)?;
+
+        let months_per_unit = match calendar_months(&self.unit, policy) {
+            Some(months_per_unit) => months_per_unit,
+            None => {
+                let seconds = self.convert_to("s")?.value;
+
+                return seconds_to_duration(seconds).ok_or_else(|| self.out_of_range());
+            }
+        };
+
+        let months = self.value * months_per_unit;
+
+        if !months.is_finite() {
+            return Err(self.out_of_range());
+        }
+
+        let whole = months.floor();
+        let fraction = months - whole;
+
+        #[allow(clippy::cast_possible_truncation)]
+        let whole = i32::try_from(whole as i64).map_err(|_| self.out_of_range())?;
+
+        let start = add_months(anchor, whole, policy).ok_or_else(|| self.out_of_range())?;
+
+        let end = if fraction > 0.0 {
+            let next = whole
+                .checked_add(1)
+                .and_then(|next| add_months(anchor, next, policy))
+                .ok_or_else(|| self.out_of_range())?;
+            let month_seconds = duration_to_seconds(next - start);
+            let extra =
+                seconds_to_duration(month_seconds * fraction).ok_or_else(|| self.out_of_range())?;
+
+            start + extra
+        } else {
+            start
+        };
+
+        Ok(end - anchor)
+    }
+
+    /// Same as `from_calendar_span_with()`, using `CalendarPolicy::Clamp`.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use chrono::NaiveDate;
+    /// use wise_units::{Measurement, Unit};
+    ///
+    /// let start = NaiveDate::from_ymd_opt(2026, 1, 31)
+    ///     .unwrap()
+    ///     .and_hms_opt(0, 0, 0)
+    ///     .unwrap();
+    /// let end = NaiveDate::from_ymd_opt(2026, 4, 30)
+    ///     .unwrap()
+    ///     .and_hms_opt(0, 0, 0)
+    ///     .unwrap();
+    ///
+    /// let span = Measurement::from_calendar_span(start, end, &Unit::from_str("mo").unwrap())
+    ///     .unwrap();
+    /// assert_eq!(span.value(), 3.0);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// See `from_calendar_span_with()`.
+    ///
+    pub fn from_calendar_span(
+        start: NaiveDateTime,
+        end: NaiveDateTime,
+        unit: &Unit,
+    ) -> Result<Self, Error> {
+        Self::from_calendar_span_with(start, end, unit, CalendarPolicy::default())
+    }
+
+    /// The time between `start` and `end`, in `unit`. Months and years are counted on the
+    /// calendar as `policy` says (this is the inverse of `to_calendar_duration_with()`); all other
+    /// time units are converted exactly.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::IncompatibleUnitTypes` if `unit` isn't a time, or an
+    /// `Error::DurationOutOfRange` if the span can't be counted in calendar months.
+    ///
+    pub fn from_calendar_span_with(
+        start: NaiveDateTime,
+        end: NaiveDateTime,
+        unit: &Unit,
+        policy: CalendarPolicy,
+    ) -> Result<Self, Error> {
+        expect_time(unit)?;
+
+        let months_per_unit = match calendar_months(unit, policy) {
+            Some(months_per_unit) => months_per_unit,
+            None => {
+                let seconds = Self::try_new(duration_to_seconds(end - start), "s")?;
+
+                return seconds.convert_to(unit);
+            }
+        };
+
+        let out_of_range = || Error::DurationOutOfRange(format!("{} - {}", end, start));
+
+        // Start from the difference in calendar months, then step until
+        // `start + whole <= end < start + whole + 1`.
+        let months = (i64::from(end.year()) - i64::from(start.year())) * 12
+            + i64::from(end.month())
+            - i64::from(start.month());
+        let mut whole = i32::try_from(months).map_err(|_| out_of_range())?;
+        let mut lower = add_months(start, whole, policy).ok_or_else(out_of_range)?;
+
+        while lower > end {
+            whole -= 1;
+            lower = add_months(start, whole, policy).ok_or_else(out_of_range)?;
+        }
+
+        let mut upper = whole
+            .checked_add(1)
+            .and_then(|next| add_months(start, next, policy))
+            .ok_or_else(out_of_range)?;
+
+        while upper <= end {
+            whole += 1;
+            lower = upper;
+            upper = whole
+                .checked_add(1)
+                .and_then(|next| add_months(start, next, policy))
+                .ok_or_else(out_of_range)?;
+        }
+
+        let fraction = duration_to_seconds(end - lower) / duration_to_seconds(upper - lower);
+        let months = f64::from(whole) + fraction;
+
+        Ok(Self {
+            value: months / months_per_unit,
+            unit: unit.clone(),
+        })
+    }
+
+    fn out_of_range(&self) -> Error {
+        Error::DurationOutOfRange(self.to_string())
+    }
+}
+
+fn expect_time(unit: &Unit) -> Result<(), Error> {
+    if unit.composition() == Composition::new_time(1) {
+        Ok(())
+    } else {
+        Err(Error::IncompatibleUnitTypes {
+            lhs: unit.expression(),
+            rhs: "s".to_string(),
+        })
+    }
+}
+
+/// If `unit` should be counted in calendar months under `policy`, how many months one of it is.
+/// Only a single, unprefixed `mo` or `a` (not `mo_g`, `mo2`, or `10.a`) counts.
+///
+fn calendar_months(unit: &Unit, policy: CalendarPolicy) -> Option<f64> {
+    if policy == CalendarPolicy::MeanDuration {
+        return None;
+    }
+
+    match unit.terms() {
+        [term]
+            if term.prefix.is_none()
+                && term.factor.is_none()
+                && term.exponent.map_or(true, |exponent| exponent == 1) =>
+        {
+            match term.atom? {
+                Atom::Month => Some(1.0),
+                Atom::Year => Some(12.0),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn add_months(anchor: NaiveDateTime, months: i32, policy: CalendarPolicy) -> Option<NaiveDateTime> {
+    if policy == CalendarPolicy::Overflow {
+        let total = anchor
+            .year()
+            .checked_mul(12)?
+            .checked_add(i32::try_from(anchor.month0()).ok()?)?
+            .checked_add(months)?;
+        let month = u32::try_from(total.rem_euclid(12)).ok()? + 1;
+        let first = NaiveDate::from_ymd_opt(total.div_euclid(12), month, 1)?;
+        let date = first.checked_add_signed(Duration::days(i64::from(anchor.day0())))?;
+
+        return Some(date.and_time(anchor.time()));
+    }
+
+    if months < 0 {
+        anchor.checked_sub_months(Months::new(months.unsigned_abs()))
+    } else {
+        anchor.checked_add_months(Months::new(months.unsigned_abs()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CalendarPolicy;
+    use crate::{Error, Measurement, Unit};
+    use approx::assert_relative_eq;
+    use chrono::{NaiveDate, NaiveDateTime};
+    use std::str::FromStr;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+    }
+
+    fn duration(value: f64, unit: &str, anchor: NaiveDateTime, policy: CalendarPolicy) -> i64 {
+        Measurement::try_new(value, unit)
+            .unwrap()
+            .to_calendar_duration_with(anchor, policy)
+            .unwrap()
+            .num_seconds()
+    }
+
+    fn span(start: NaiveDateTime, end: NaiveDateTime, unit: &str, policy: CalendarPolicy) -> f64 {
+        Measurement::from_calendar_span_with(start, end, &Unit::from_str(unit).unwrap(), policy)
+            .unwrap()
+            .value()
+    }
+
+    #[test]
+    fn validate_clamp() {
+        let anchor = date(2026, 1, 31);
+
+        assert_eq!(
+            duration(3.0, "mo", anchor, CalendarPolicy::Clamp),
+            (date(2026, 4, 30) - anchor).num_seconds()
+        );
+
+        assert_eq!(
+            duration(1.0, "mo", anchor, CalendarPolicy::Clamp),
+            (date(2026, 2, 28) - anchor).num_seconds()
+        );
+        assert_eq!(
+            duration(-2.0, "mo", anchor, CalendarPolicy::Clamp),
+            (date(2025, 11, 30) - anchor).num_seconds()
+        );
+    }
+
+    #[test]
+    fn validate_overflow() {
+        let anchor = date(2026, 1, 31);
+
+        assert_eq!(
+            duration(1.0, "mo", anchor, CalendarPolicy::Overflow),
+            (date(2026, 3, 3) - anchor).num_seconds()
+        );
+        assert_eq!(
+            duration(3.0, "mo", anchor, CalendarPolicy::Overflow),
+            (date(2026, 5, 1) - anchor).num_seconds()
+        );
+    }
+
+    #[test]
+    fn validate_mean_duration() {
+        let anchor = date(2026, 1, 31);
+
+        assert_eq!(
+            duration(1.0, "mo_g", anchor, CalendarPolicy::MeanDuration),
+            2_629_746
+        );
+        assert_eq!(
+            duration(1.0, "a_j", anchor, CalendarPolicy::MeanDuration),
+            31_557_600
+        );
+    }
+
+    #[test]
+    fn validate_years() {
+        let anchor = date(2024, 2, 29);
+
+        assert_eq!(
+            duration(1.0, "a", anchor, CalendarPolicy::Clamp),
+            (date(2025, 2, 28) - anchor).num_seconds()
+        );
+        assert_eq!(
+            duration(4.0, "a", anchor, CalendarPolicy::Clamp),
+            (date(2028, 2, 29) - anchor).num_seconds()
+        );
+    }
+
+    #[test]
+    fn validate_mean_atoms() {
+        let anchor = date(2026, 1, 31);
+
+        for policy in &[CalendarPolicy::Clamp, CalendarPolicy::Overflow] {
+            // 30.436875 days.
+            assert_eq!(duration(1.0, "mo_g", anchor, *policy), 2_629_746);
+            // 30.4375 days.
+            assert_eq!(duration(1.0, "mo_j", anchor, *policy), 2_629_800);
+            // 365.25 days.
+            assert_eq!(duration(1.0, "a_j", anchor, *policy), 31_557_600);
+            // 365.2425 days.
+            assert_eq!(duration(1.0, "a_g", anchor, *policy), 31_556_952);
+        }
+
+        assert_relative_eq!(
+            span(anchor, date(2026, 3, 2), "mo_g", CalendarPolicy::Clamp),
+            30.0 / 30.436_875,
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn validate_fractional_month() {
+        // Half of February 2026 (28 days) is 14 days.
+        let anchor = date(2026, 1, 1);
+        assert_eq!(
+            duration(1.5, "mo", anchor, CalendarPolicy::Clamp),
+            (date(2026, 2, 15) - anchor).num_seconds()
+        );
+    }
+
+    #[test]
+    fn validate_exact_units() {
+        let anchor = date(2026, 1, 31);
+
+        assert_eq!(
+            duration(2.0, "wk", anchor, CalendarPolicy::Clamp),
+            1_209_600
+        );
+        assert_eq!(
+            duration(1.0, "mo_s", anchor, CalendarPolicy::Clamp),
+            2_551_442
+        );
+        assert_eq!(
+            duration(1.0, "10.mo", anchor, CalendarPolicy::Clamp),
+            26_298_000
+        );
+    }
+
+    #[test]
+    fn validate_not_time() {
+        let error = Measurement::try_new(1.0, "m")
+            .unwrap()
+            .to_calendar_duration(date(2026, 1, 1))
+            .unwrap_err();
+
+        assert_eq!(
+            error,
+            Error::IncompatibleUnitTypes {
+                lhs: "m".to_string(),
+                rhs: "s".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn validate_out_of_range() {
+        assert!(matches!(
+            Measurement::try_new(1.0e300, "a")
+                .unwrap()
+                .to_calendar_duration(date(2026, 1, 1))
+                .unwrap_err(),
+            Error::DurationOutOfRange(_)
+        ));
+    }
+
+    #[test]
+    fn validate_out_of_range_months() {
+        let anchor = date(2026, 1, 1);
+
+        for policy in &[CalendarPolicy::Clamp, CalendarPolicy::Overflow] {
+            for value in &[2_147_483_647.0, 2_147_483_647.5, -2_147_483_648.0] {
+                let result = Measurement::try_new(*value, "mo")
+                    .unwrap()
+                    .to_calendar_duration_with(anchor, *policy);
+
+                assert!(
+                    matches!(result, Err(Error::DurationOutOfRange(_))),
+                    "{} {:?}",
+                    value,
+                    policy
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn validate_span() {
+        let start = date(2026, 1, 31);
+
+        assert_relative_eq!(
+            span(start, date(2026, 4, 30), "mo", CalendarPolicy::Clamp),
+            3.0
+        );
+        assert_relative_eq!(
+            span(start, date(2026, 5, 1), "mo", CalendarPolicy::Overflow),
+            3.0
+        );
+        assert_relative_eq!(
+            span(start, date(2025, 11, 30), "mo", CalendarPolicy::Clamp),
+            -2.0
+        );
+        assert_relative_eq!(
+            span(
+                date(2024, 2, 29),
+                date(2025, 2, 28),
+                "a",
+                CalendarPolicy::Clamp
+            ),
+            1.0
+        );
+        assert_relative_eq!(
+            span(start, date(2026, 2, 14), "d", CalendarPolicy::Clamp),
+            14.0
+        );
+        assert_relative_eq!(
+            span(
+                date(2026, 1, 1),
+                date(2026, 2, 15),
+                "mo",
+                CalendarPolicy::Clamp
+            ),
+            1.5
+        );
+    }
+
+    #[test]
+    fn validate_span_round_trip() {
+        let start = date(2026, 1, 31);
+
+        for policy in &[CalendarPolicy::Clamp, CalendarPolicy::Overflow] {
+            for value in &[0.25, 1.0, 2.5, 13.75] {
+                let duration = Measurement::try_new(*value, "mo")
+                    .unwrap()
+                    .to_calendar_duration_with(start, *policy)
+                    .unwrap();
+                let months = span(start, start + duration, "mo", *policy);
+
+                assert_relative_eq!(months, *value, max_relative = 1e-9);
+            }
+        }
+    }
+}
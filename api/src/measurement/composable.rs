@@ -27,7 +27,7 @@ mod tests {
 
         let m = Measurement::try_new(1.0, "m2/s").unwrap();
         let mut expected = Composition::new(Dimension::Length, 2);
-        expected.insert(Dimension::Time, -1);
+        expected.insert(Dimension::Time, -1).unwrap();
 
         assert_eq!(m.composition(), expected);
     }
@@ -0,0 +1,121 @@
+use super::Measurement;
+use crate::{
+    invert::ToInverse, is_compatible_with::IsCompatibleWith, ucum_unit::UcumUnit, Convertible,
+    Error, Unit,
+};
+
+impl Measurement {
+    /// Converts `self` to a `Unit` whose `Composition` is the inverse of `self`'s, ex. fuel
+    /// consumption (`L/100km`) to fuel economy (`[mi_i]/[gal_us]`), or pace (`min/km`) to speed
+    /// (`km/h`). `convert_to()` never does this on its own, since a value and its reciprocal are
+    /// different quantities.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wise_units::{Measurement, Unit};
+    ///
+    /// let pace = Measurement::try_new(5.0, "min/km").unwrap();
+    /// let speed = pace
+    ///     .convert_to_reciprocal(&Unit::from_str("km/h").unwrap())
+    ///     .unwrap();
+    ///
+    /// assert!((speed.value() - 12.0).abs() < 0.000_001);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::IncompatibleUnitTypes` if `unit` isn't compatible with the inverse of
+    /// `self`'s `Unit`, an `Error::SpecialUnitNotSupported` if either `Unit` is special, or an
+    /// `Error::DivideByZero` if `self`'s value is 0.
+    ///
+    pub fn convert_to_reciprocal(&self, unit: &Unit) -> Result<Self, Error> {
+        if self.unit.is_special() {
+            return Err(Error::SpecialUnitNotSupported(self.unit.expression()));
+        }
+
+        if unit.is_special() {
+            return Err(Error::SpecialUnitNotSupported(unit.expression()));
+        }
+
+        if !self.unit.to_inverse().is_compatible_with(unit) {
+            return Err(Error::IncompatibleUnitTypes {
+                lhs: self.unit.expression(),
+                rhs: unit.expression(),
+            });
+        }
+
+        self.to_inverse()?.convert_to(unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Convertible, Error, Measurement, Unit};
+    use approx::assert_relative_eq;
+    use std::str::FromStr;
+
+    fn convert(value: f64, from: &str, to: &str) -> Result<Measurement, Error> {
+        Measurement::try_new(value, from)
+            .unwrap()
+            .convert_to_reciprocal(&Unit::from_str(to).unwrap())
+    }
+
+    #[test]
+    fn validate_fuel_economy() {
+        let converted = convert(7.84, "L/100km", "[mi_i]/[gal_us]").unwrap();
+        assert_relative_eq!(
+            converted.value(),
+            100.0 / 7.84 * 3.785_411_784 / 1.609_344,
+            max_relative = 1e-12
+        );
+        assert_eq!(converted.unit().expression(), "[mi_i]/[gal_us]");
+
+        let converted = convert(converted.value(), "[mi_i]/[gal_us]", "L/100km").unwrap();
+        assert_relative_eq!(converted.value(), 7.84, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn validate_pace() {
+        let converted = convert(5.0, "min/km", "km/h").unwrap();
+        assert_relative_eq!(converted.value(), 12.0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn validate_specific_volume() {
+        let converted = convert(0.001, "m3/kg", "kg/L").unwrap();
+        assert_relative_eq!(converted.value(), 1.0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn validate_zero() {
+        assert_eq!(
+            convert(0.0, "min/km", "km/h").unwrap_err(),
+            Error::DivideByZero
+        );
+    }
+
+    #[test]
+    fn validate_not_reciprocal() {
+        assert_eq!(
+            convert(5.0, "min/km", "min/[mi_i]").unwrap_err(),
+            Error::IncompatibleUnitTypes {
+                lhs: "min/km".to_string(),
+                rhs: "min/[mi_i]".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn validate_special() {
+        assert!(matches!(
+            convert(5.0, "Cel", "K-1").unwrap_err(),
+            Error::SpecialUnitNotSupported(_)
+        ));
+    }
+
+    #[test]
+    fn validate_convert_to_is_not_implicit() {
+        let measurement = Measurement::try_new(5.0, "min/km").unwrap();
+        assert!(measurement.convert_to("km/h").is_err());
+    }
+}
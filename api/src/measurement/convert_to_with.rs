@@ -0,0 +1,184 @@
+use super::Measurement;
+use crate::{
+    conversion_context::{ContextConversion, ConversionContext},
+    Convertible, Error, Unit,
+};
+
+impl Measurement {
+    /// Like `convert_to()`, but allows for using the `Equivalence`s in `context` to convert
+    /// between `Unit`s that aren't otherwise compatible. The fewest `Equivalence`s needed are
+    /// used, and each is used at most once; the returned `ContextConversion` reports which were
+    /// used.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wise_units::{ConversionContext, Measurement, Unit};
+    ///
+    /// let molar_mass = Measurement::try_new(180.156, "g/mol").unwrap();
+    /// let context = ConversionContext::new()
+    ///     .with_molar_mass("glucose", molar_mass)
+    ///     .unwrap();
+    ///
+    /// let concentration = Measurement::try_new(900.78, "mg/L").unwrap();
+    /// let mmol_per_liter = Unit::from_str("mmol/L").unwrap();
+    /// let converted = concentration
+    ///     .convert_to_with(&mmol_per_liter, &context)
+    ///     .unwrap();
+    ///
+    /// assert!((converted.measurement().value() - 5.0).abs() < 0.000_001);
+    /// assert_eq!(converted.equivalences(), &["glucose"]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::IncompatibleUnitTypes` if there's no way to get from `self`'s `Unit` to
+    /// `unit` using the `Equivalence`s in `context`.
+    ///
+    pub fn convert_to_with(
+        &self,
+        unit: &Unit,
+        context: &ConversionContext,
+    ) -> Result<ContextConversion, Error> {
+        let (intermediate, path) =
+            context
+                .find_path(self, unit)
+                .ok_or_else(|| Error::IncompatibleUnitTypes {
+                    lhs: self.unit.expression(),
+                    rhs: unit.expression(),
+                })?;
+
+        let names = path
+            .into_iter()
+            .map(|index| context.equivalences()[index].name().to_string())
+            .collect();
+
+        Ok(ContextConversion::new(
+            intermediate.convert_to(unit)?,
+            names,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ConversionContext, Convertible, Error, Measurement, Unit};
+    use approx::assert_relative_eq;
+    use std::str::FromStr;
+
+    fn context() -> ConversionContext {
+        ConversionContext::new()
+            .with_density("herbicide", Measurement::try_new(1.2, "kg/L").unwrap())
+            .unwrap()
+            .with_molar_mass("nitrate", Measurement::try_new(62.004, "g/mol").unwrap())
+            .unwrap()
+            .with_potency("vitamin D", Measurement::try_new(0.025, "ug/[iU]").unwrap())
+            .unwrap()
+    }
+
+    #[test]
+    fn validate_already_compatible() {
+        let measurement = Measurement::try_new(1.0, "km").unwrap();
+        let unit = Unit::from_str("m").unwrap();
+        let converted = measurement.convert_to_with(&unit, &context()).unwrap();
+
+        assert_relative_eq!(converted.measurement().value(), 1000.0);
+        assert!(converted.equivalences().is_empty());
+    }
+
+    #[test]
+    fn validate_volume_rate_to_mass_rate() {
+        let measurement = Measurement::try_new(1.0, "[gal_us]/[acr_us]").unwrap();
+        let unit = Unit::from_str("[lb_av]/[acr_us]").unwrap();
+        let converted = measurement.convert_to_with(&unit, &context()).unwrap();
+
+        // 1 gal = 3.785411784 L -> 4.5424941408 kg -> 10.0144... lb
+        assert_relative_eq!(
+            converted.measurement().value(),
+            10.014_485_342_423_198,
+            max_relative = 1e-12
+        );
+        assert_eq!(
+            converted.measurement().unit().expression(),
+            "[lb_av]/[acr_us]"
+        );
+        assert_eq!(converted.equivalences(), &["herbicide"]);
+    }
+
+    #[test]
+    fn validate_mass_to_volume() {
+        let measurement = Measurement::try_new(6.0, "kg").unwrap();
+        let unit = Unit::from_str("L").unwrap();
+        let converted = measurement.convert_to_with(&unit, &context()).unwrap();
+
+        assert_relative_eq!(converted.measurement().value(), 5.0, max_relative = 1e-12);
+        assert_eq!(converted.equivalences(), &["herbicide"]);
+    }
+
+    #[test]
+    fn validate_mass_concentration_to_amount_concentration() {
+        let measurement = Measurement::try_new(62.004, "mg/L").unwrap();
+        let unit = Unit::from_str("mmol/L").unwrap();
+        let converted = measurement.convert_to_with(&unit, &context()).unwrap();
+
+        assert_relative_eq!(converted.measurement().value(), 1.0, max_relative = 1e-12);
+        assert_eq!(converted.equivalences(), &["nitrate"]);
+    }
+
+    #[test]
+    fn validate_arbitrary_to_mass() {
+        let measurement = Measurement::try_new(400.0, "[iU]").unwrap();
+        let unit = Unit::from_str("ug").unwrap();
+        let converted = measurement.convert_to_with(&unit, &context()).unwrap();
+
+        assert_relative_eq!(converted.measurement().value(), 10.0, max_relative = 1e-12);
+        assert_eq!(converted.equivalences(), &["vitamin D"]);
+    }
+
+    #[test]
+    fn validate_multiple_equivalences() {
+        // Volume -> mass (density) -> amount (molar mass).
+        let measurement = Measurement::try_new(1.0, "mL").unwrap();
+        let unit = Unit::from_str("mmol").unwrap();
+        let converted = measurement.convert_to_with(&unit, &context()).unwrap();
+
+        assert_relative_eq!(
+            converted.measurement().value(),
+            1200.0 / 62.004,
+            max_relative = 1e-12
+        );
+        assert_eq!(converted.equivalences(), &["herbicide", "nitrate"]);
+    }
+
+    #[test]
+    fn validate_no_path() {
+        let measurement = Measurement::try_new(1.0, "[gal_us]").unwrap();
+        let unit = Unit::from_str("s").unwrap();
+
+        assert_eq!(
+            measurement.convert_to_with(&unit, &context()).unwrap_err(),
+            Error::IncompatibleUnitTypes {
+                lhs: "[gal_us]".to_string(),
+                rhs: "s".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn validate_empty_context() {
+        let measurement = Measurement::try_new(1.0, "[gal_us]").unwrap();
+        let unit = Unit::from_str("[lb_av]").unwrap();
+
+        assert!(measurement
+            .convert_to_with(&unit, &ConversionContext::new())
+            .is_err());
+    }
+
+    #[test]
+    fn validate_convert_to_still_refuses() {
+        let measurement = Measurement::try_new(1.0, "[gal_us]/[acr_us]").unwrap();
+        assert!(measurement.convert_to("[lb_av]/[acr_us]").is_err());
+
+        let measurement = Measurement::try_new(1.0, "[iU]").unwrap();
+        assert!(measurement.convert_to("ug").is_err());
+    }
+}
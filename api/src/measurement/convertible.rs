@@ -1,4 +1,6 @@
-use crate::{Convertible, Error, FieldEq, IsCompatibleWith, Measurement, Unit};
+use crate::{
+    measurement::finite, Convertible, Error, FieldEq, IsCompatibleWith, Measurement, Unit,
+};
 use std::str::FromStr;
 
 /// This implementation of `Convertible` lets you pass in a `&str` for the
@@ -35,7 +37,7 @@ impl<'a> Convertible<&'a Unit> for Measurement {
 fn convert_measurement(lhs: &Measurement, dest_unit: &Unit) -> Result<Measurement, Error> {
     // Short-circuit if `dest_unit` is the same as the Measurement's Unit.
     if lhs.unit.field_eq(dest_unit) {
-        return Ok(lhs.clone());
+        return finite(lhs.value).map(|_| lhs.clone());
     }
 
     let source_unit = &lhs.unit;
@@ -48,8 +50,9 @@ fn convert_measurement(lhs: &Measurement, dest_unit: &Unit) -> Result<Measuremen
         return Err(e);
     }
 
+    // Special units' functions can produce NaN (ex. the log of a negative value for `[pH]`).
     let new_measurement = Measurement {
-        value: lhs.converted_scalar(dest_unit),
+        value: finite(lhs.converted_scalar(dest_unit))?,
         unit: dest_unit.clone(),
     };
 
@@ -130,4 +133,29 @@ mod tests {
         assert_relative_eq!(converted.value, 0.5);
         assert_ulps_eq!(converted.value, 0.5);
     }
+
+    #[test]
+    fn validate_convert_to_special_non_finite() {
+        // log10 of a negative ratio
+        let ratio = Measurement::try_new(-1.0, "1").unwrap();
+        assert!(matches!(
+            ratio.convert_to("B").unwrap_err(),
+            Error::NonFiniteValue(value) if value.is_nan()
+        ));
+
+        let ratio = Measurement::try_new(0.0, "1").unwrap();
+        assert_eq!(
+            ratio.convert_to("B").unwrap_err(),
+            Error::NonFiniteValue(f64::NEG_INFINITY)
+        );
+    }
+
+    #[test]
+    fn validate_convert_to_overflow() {
+        let m = Measurement::try_new(f64::MAX, "km").unwrap();
+        assert_eq!(
+            m.convert_to("mm").unwrap_err(),
+            Error::NonFiniteValue(f64::INFINITY)
+        );
+    }
 }
@@ -0,0 +1,344 @@
+use super::Measurement;
+use crate::{
+    parser::{Atom, Composable, Composition, Term},
+    ucum_unit::UcumUnit,
+    Convertible, Error, Unit,
+};
+use std::{convert::TryFrom, time::Duration};
+
+impl Measurement {
+    /// Converts a `Duration` (from `std`, or from `chrono` or `time` when those features are
+    /// enabled) to a `Measurement` in `unit`.
+    ///
+    /// ```
+    /// use std::{str::FromStr, time::Duration};
+    /// use wise_units::{Measurement, Unit};
+    ///
+    /// let unit = Unit::from_str("min").unwrap();
+    /// let minutes = Measurement::from_duration(Duration::from_secs(90), &unit).unwrap();
+    /// assert_eq!(minutes.value(), 1.5);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::IncompatibleUnitTypes` if `unit` isn't a time.
+    ///
+    pub fn from_duration<D>(duration: D, unit: &Unit) -> Result<Self, Error>
+    where
+        D: Into<Self>,
+    {
+        duration.into().convert_to(unit)
+    }
+
+    /// The value of `self` in seconds, after checking that it's a time that can be converted to a
+    /// duration type.
+    ///
+    fn duration_seconds(&self) -> Result<f64, Error> {
+        if self.unit.is_special() {
+            return Err(Error::SpecialUnitNotSupported(self.unit.expression()));
+        }
+
+        if self.unit.composition() != Composition::new_time(1) {
+            return Err(Error::IncompatibleUnitTypes {
+                lhs: self.unit.expression(),
+                rhs: "s".to_string(),
+            });
+        }
+
+        if !self.value.is_finite() {
+            return Err(Error::DurationOutOfRange(self.to_string()));
+        }
+
+        self.convert_to("s")
+            .map(|seconds| seconds.value)
+            .map_err(|_| Error::DurationOutOfRange(self.to_string()))
+    }
+}
+
+/// ```
+/// use std::{convert::TryFrom, time::Duration};
+/// use wise_units::Measurement;
+///
+/// let measurement = Measurement::try_new(1.5, "min").unwrap();
+/// assert_eq!(Duration::try_from(&measurement).unwrap(), Duration::from_secs(90));
+/// ```
+///
+impl TryFrom<&Measurement> for Duration {
+    type Error = Error;
+
+    /// # Errors
+    ///
+    /// Returns an `Error::IncompatibleUnitTypes` if `measurement` isn't a time, an
+    /// `Error::SpecialUnitNotSupported` if its `Unit` is special, or an
+    /// `Error::DurationOutOfRange` if its value is negative, not finite, or too large.
+    ///
+    fn try_from(measurement: &Measurement) -> Result<Self, Self::Error> {
+        let seconds = measurement.duration_seconds()?;
+
+        Self::try_from_secs_f64(seconds)
+            .map_err(|_| Error::DurationOutOfRange(measurement.to_string()))
+    }
+}
+
+/// The `Measurement` is in seconds; see `Measurement::from_duration()` for other `Unit`s.
+///
+impl From<Duration> for Measurement {
+    fn from(duration: Duration) -> Self {
+        Self {
+            value: duration.as_secs_f64(),
+            unit: second(),
+        }
+    }
+}
+
+fn second() -> Unit {
+    Unit::new(vec![Term::new(None, Some(Atom::Second))])
+}
+
+//-----------------------------------------------------------------------------
+// chrono
+//-----------------------------------------------------------------------------
+#[cfg(feature = "chrono")]
+impl TryFrom<&Measurement> for chrono::Duration {
+    type Error = Error;
+
+    /// Unlike `std::time::Duration`, `chrono::Duration`s may be negative.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::IncompatibleUnitTypes` if `measurement` isn't a time, an
+    /// `Error::SpecialUnitNotSupported` if its `Unit` is special, or an
+    /// `Error::DurationOutOfRange` if its value is not finite or too large.
+    ///
+    fn try_from(measurement: &Measurement) -> Result<Self, Self::Error> {
+        let seconds = measurement.duration_seconds()?;
+
+        seconds_to_duration(seconds)
+            .ok_or_else(|| Error::DurationOutOfRange(measurement.to_string()))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::Duration> for Measurement {
+    fn from(duration: chrono::Duration) -> Self {
+        Self {
+            value: duration_to_seconds(duration),
+            unit: second(),
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[allow(clippy::cast_precision_loss)]
+pub(super) fn duration_to_seconds(duration: chrono::Duration) -> f64 {
+    duration.num_seconds() as f64 + f64::from(duration.subsec_nanos()) / 1_000_000_000.0
+}
+
+#[cfg(feature = "chrono")]
+#[allow(clippy::cast_possible_truncation)]
+pub(super) fn seconds_to_duration(seconds: f64) -> Option<chrono::Duration> {
+    // chrono::Duration is limited to i64::MAX milliseconds.
+    if !seconds.is_finite() || seconds.abs() >= 9_223_372_036_854_775.0 {
+        return None;
+    }
+
+    let whole = seconds.trunc();
+    let nanos = ((seconds - whole) * 1_000_000_000.0).round();
+
+    chrono::Duration::try_seconds(whole as i64)?
+        .checked_add(&chrono::Duration::nanoseconds(nanos as i64))
+}
+
+//-----------------------------------------------------------------------------
+// time
+//-----------------------------------------------------------------------------
+#[cfg(feature = "time")]
+impl TryFrom<&Measurement> for time::Duration {
+    type Error = Error;
+
+    /// Unlike `std::time::Duration`, `time::Duration`s may be negative.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::IncompatibleUnitTypes` if `measurement` isn't a time, an
+    /// `Error::SpecialUnitNotSupported` if its `Unit` is special, or an
+    /// `Error::DurationOutOfRange` if its value is not finite or too large.
+    ///
+    fn try_from(measurement: &Measurement) -> Result<Self, Self::Error> {
+        let seconds = measurement.duration_seconds()?;
+
+        Self::checked_seconds_f64(seconds)
+            .ok_or_else(|| Error::DurationOutOfRange(measurement.to_string()))
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::Duration> for Measurement {
+    fn from(duration: time::Duration) -> Self {
+        Self {
+            value: duration.as_seconds_f64(),
+            unit: second(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Measurement, Unit};
+    use approx::assert_relative_eq;
+    use std::{convert::TryFrom, str::FromStr, time::Duration};
+
+    fn measurement(value: f64, unit: &str) -> Measurement {
+        Measurement::try_new(value, unit).unwrap()
+    }
+
+    fn unchecked(value: f64, unit: &str) -> Measurement {
+        Measurement::new_unchecked(value, Unit::from_str(unit).unwrap())
+    }
+
+    #[test]
+    fn validate_try_from_measurement() {
+        assert_eq!(
+            Duration::try_from(&measurement(1.5, "min")).unwrap(),
+            Duration::from_secs(90)
+        );
+        assert_eq!(
+            Duration::try_from(&measurement(250.0, "ms")).unwrap(),
+            Duration::from_millis(250)
+        );
+        assert_eq!(
+            Duration::try_from(&measurement(1.0, "d")).unwrap(),
+            Duration::from_secs(86_400)
+        );
+        assert_eq!(
+            Duration::try_from(&measurement(0.0, "h")).unwrap(),
+            Duration::from_secs(0)
+        );
+    }
+
+    #[test]
+    fn validate_try_from_measurement_not_time() {
+        assert_eq!(
+            Duration::try_from(&measurement(1.0, "m")).unwrap_err(),
+            Error::IncompatibleUnitTypes {
+                lhs: "m".to_string(),
+                rhs: "s".to_string()
+            }
+        );
+        assert!(Duration::try_from(&measurement(1.0, "Cel")).is_err());
+    }
+
+    #[test]
+    fn validate_try_from_measurement_out_of_range() {
+        assert_eq!(
+            Duration::try_from(&measurement(-1.0, "s")).unwrap_err(),
+            Error::DurationOutOfRange("-1 s".to_string())
+        );
+        assert!(matches!(
+            Duration::try_from(&unchecked(f64::INFINITY, "s")).unwrap_err(),
+            Error::DurationOutOfRange(_)
+        ));
+        assert!(matches!(
+            Duration::try_from(&unchecked(f64::NAN, "s")).unwrap_err(),
+            Error::DurationOutOfRange(_)
+        ));
+        assert!(matches!(
+            Duration::try_from(&measurement(1.0e30, "a")).unwrap_err(),
+            Error::DurationOutOfRange(_)
+        ));
+    }
+
+    #[test]
+    fn validate_from_duration() {
+        let measurement = Measurement::from(Duration::from_millis(1500));
+
+        assert_relative_eq!(measurement.value(), 1.5);
+        assert_eq!(measurement.unit().expression(), "s");
+    }
+
+    #[test]
+    fn validate_from_duration_in_unit() {
+        let unit = Unit::from_str("h").unwrap();
+        let measurement = Measurement::from_duration(Duration::from_secs(5400), &unit).unwrap();
+
+        assert_relative_eq!(measurement.value(), 1.5);
+        assert_eq!(measurement.unit().expression(), "h");
+
+        let unit = Unit::from_str("m").unwrap();
+        assert!(Measurement::from_duration(Duration::from_secs(1), &unit).is_err());
+    }
+
+    #[test]
+    fn validate_round_trip() {
+        let duration = Duration::new(12_345, 678_000_000);
+        let measurement = Measurement::from(duration);
+
+        assert_eq!(Duration::try_from(&measurement).unwrap(), duration);
+    }
+
+    #[cfg(feature = "chrono")]
+    mod with_chrono {
+        use super::{measurement, unchecked};
+        use crate::{Error, Measurement};
+        use approx::assert_relative_eq;
+        use std::convert::TryFrom;
+
+        #[test]
+        fn validate_try_from_measurement() {
+            assert_eq!(
+                chrono::Duration::try_from(&measurement(1.5, "min")).unwrap(),
+                chrono::Duration::seconds(90)
+            );
+            assert_eq!(
+                chrono::Duration::try_from(&measurement(-2.0, "h")).unwrap(),
+                chrono::Duration::hours(-2)
+            );
+            assert!(chrono::Duration::try_from(&measurement(1.0, "g")).is_err());
+            assert!(matches!(
+                chrono::Duration::try_from(&unchecked(f64::NAN, "s")).unwrap_err(),
+                Error::DurationOutOfRange(_)
+            ));
+        }
+
+        #[test]
+        fn validate_from_duration() {
+            let measurement = Measurement::from(chrono::Duration::milliseconds(-1500));
+
+            assert_relative_eq!(measurement.value(), -1.5);
+            assert_eq!(measurement.unit().expression(), "s");
+        }
+    }
+
+    #[cfg(feature = "time")]
+    mod with_time {
+        use super::{measurement, unchecked};
+        use crate::{Error, Measurement};
+        use approx::assert_relative_eq;
+        use std::convert::TryFrom;
+
+        #[test]
+        fn validate_try_from_measurement() {
+            assert_eq!(
+                time::Duration::try_from(&measurement(1.5, "min")).unwrap(),
+                time::Duration::seconds(90)
+            );
+            assert_eq!(
+                time::Duration::try_from(&measurement(-2.0, "h")).unwrap(),
+                time::Duration::hours(-2)
+            );
+            assert!(time::Duration::try_from(&measurement(1.0, "g")).is_err());
+            assert!(matches!(
+                time::Duration::try_from(&unchecked(f64::INFINITY, "s")).unwrap_err(),
+                Error::DurationOutOfRange(_)
+            ));
+        }
+
+        #[test]
+        fn validate_from_duration() {
+            let measurement = Measurement::from(time::Duration::milliseconds(-1500));
+
+            assert_relative_eq!(measurement.value(), -1.5);
+            assert_eq!(measurement.unit().expression(), "s");
+        }
+    }
+}
@@ -1,14 +1,21 @@
-use super::Measurement;
+use super::{finite, Measurement};
 use crate::{
     invert::{Invert, ToInverse},
     Error,
 };
 
 impl Invert for &mut Measurement {
+    /// Inverts `self` in place, unless its inverse wouldn't be finite (ex. its value is 0), in
+    /// which case `self` is left unchanged; use `ToInverse` to get an `Error` for that instead.
+    ///
     #[inline]
     fn invert(self) {
-        self.value = 1.0 / self.value;
-        self.unit.invert();
+        let new_value = 1.0 / self.value;
+
+        if new_value.is_finite() {
+            self.value = new_value;
+            self.unit.invert();
+        }
     }
 }
 
@@ -24,7 +31,7 @@ impl ToInverse for Measurement {
         }
 
         Ok(Self {
-            value: new_value,
+            value: finite(new_value)?,
             unit: self.unit.to_inverse(),
         })
     }
@@ -72,6 +79,18 @@ mod tests {
                 Measurement::try_new(0.1, "s2.g4.har5/m2.km4").unwrap()
             );
         }
+
+        #[test]
+        fn validate_zero_value() {
+            let mut measurement = Measurement::try_new(0.0, "m2/s2").unwrap();
+            measurement.invert();
+            assert_eq!(measurement, Measurement::try_new(0.0, "m2/s2").unwrap());
+            assert_eq!(measurement.unit().expression(), "m2/s2");
+
+            let mut measurement = Measurement::try_new(f64::MIN_POSITIVE / 4.0, "m").unwrap();
+            measurement.invert();
+            assert_eq!(measurement.unit().expression(), "m");
+        }
     }
 
     mod to_inverse {
@@ -1,6 +1,6 @@
 use crate::convertible::Convertible;
 use crate::error::Error;
-use crate::measurement::Measurement;
+use crate::measurement::{finite, Measurement};
 use std::ops::{Add, Div, Mul, Sub};
 
 //-----------------------------------------------------------------------------
@@ -11,7 +11,7 @@ fn add_measurements(lhs: &Measurement, rhs: &Measurement) -> Result<Measurement,
     let new_value = lhs.value + rhs_converted.value;
 
     Ok(Measurement {
-        value: new_value,
+        value: finite(new_value)?,
         unit: lhs.unit.clone(),
     })
 }
@@ -60,7 +60,7 @@ fn sub_measurements(lhs: &Measurement, rhs: &Measurement) -> Result<Measurement,
     let new_value = lhs.value - rhs_converted.value;
 
     Ok(Measurement {
-        value: new_value,
+        value: finite(new_value)?,
         unit: lhs.unit.clone(),
     })
 }
@@ -104,20 +104,20 @@ impl<'a> Sub<Measurement> for &'a Measurement {
 //-----------------------------------------------------------------------------
 // impl Mul
 //-----------------------------------------------------------------------------
-fn mul_measurements(lhs: &Measurement, rhs: &Measurement) -> Measurement {
+fn mul_measurements(lhs: &Measurement, rhs: &Measurement) -> Result<Measurement, Error> {
     let converted_rhs = rhs.convert_to(&lhs.unit);
     let actual_rhs = converted_rhs.as_ref().unwrap_or(rhs);
     let new_value = lhs.value * actual_rhs.value;
-    let new_unit = &lhs.unit * &actual_rhs.unit;
+    let new_unit = lhs.unit.checked_mul(&actual_rhs.unit)?;
 
-    Measurement {
-        value: new_value,
+    Ok(Measurement {
+        value: finite(new_value)?,
         unit: new_unit,
-    }
+    })
 }
 
 impl Mul for Measurement {
-    type Output = Self;
+    type Output = Result<Self, Error>;
 
     #[inline]
     fn mul(self, other: Self) -> Self::Output {
@@ -126,7 +126,7 @@ impl Mul for Measurement {
 }
 
 impl<'a> Mul<&'a Self> for Measurement {
-    type Output = Self;
+    type Output = Result<Self, Error>;
 
     #[inline]
     fn mul(self, other: &'a Self) -> Self::Output {
@@ -135,7 +135,7 @@ impl<'a> Mul<&'a Self> for Measurement {
 }
 
 impl<'a> Mul for &'a Measurement {
-    type Output = Measurement;
+    type Output = Result<Measurement, Error>;
 
     #[inline]
     fn mul(self, other: &'a Measurement) -> Self::Output {
@@ -144,7 +144,7 @@ impl<'a> Mul for &'a Measurement {
 }
 
 impl<'a> Mul<Measurement> for &'a Measurement {
-    type Output = Measurement;
+    type Output = Result<Measurement, Error>;
 
     #[inline]
     fn mul(self, other: Measurement) -> Self::Output {
@@ -156,50 +156,55 @@ impl<'a> Mul<Measurement> for &'a Measurement {
 /// `Measurement`.
 ///
 impl Mul<f64> for Measurement {
-    type Output = Self;
+    type Output = Result<Self, Error>;
 
     #[inline]
     fn mul(self, other: f64) -> Self::Output {
         let new_value = self.value * other;
 
-        Self {
-            value: new_value,
+        Ok(Self {
+            value: finite(new_value)?,
             unit: self.unit,
-        }
+        })
     }
 }
 
 impl<'a> Mul<f64> for &'a Measurement {
-    type Output = Measurement;
+    type Output = Result<Measurement, Error>;
 
     #[inline]
     fn mul(self, other: f64) -> Self::Output {
         let new_value = self.value * other;
 
-        Measurement {
-            value: new_value,
+        Ok(Measurement {
+            value: finite(new_value)?,
             unit: self.unit.clone(),
-        }
+        })
     }
 }
 
 //-----------------------------------------------------------------------------
 // impl Div
 //-----------------------------------------------------------------------------
-fn div_measurements(lhs: &Measurement, rhs: &Measurement) -> Measurement {
+fn div_measurements(lhs: &Measurement, rhs: &Measurement) -> Result<Measurement, Error> {
     let converted_rhs = rhs.convert_to(&lhs.unit);
     let actual_rhs = converted_rhs.as_ref().unwrap_or(rhs);
+
+    if actual_rhs.value == 0.0 {
+        return Err(Error::DivideByZero);
+    }
+
     let new_value = lhs.value / actual_rhs.value;
-    let new_unit = &lhs.unit / &actual_rhs.unit;
+    let new_unit = lhs.unit.checked_div(&actual_rhs.unit)?;
 
-    Measurement {
-        value: new_value,
+    Ok(Measurement {
+        value: finite(new_value)?,
         unit: new_unit,
-    }
+    })
 }
 
 impl Div for Measurement {
-    type Output = Self;
+    type Output = Result<Self, Error>;
 
     #[inline]
     fn div(self, other: Self) -> Self::Output {
@@ -208,7 +213,7 @@ impl Div for Measurement {
 }
 
 impl<'a> Div for &'a Measurement {
-    type Output = Measurement;
+    type Output = Result<Measurement, Error>;
 
     #[inline]
     fn div(self, other: &'a Measurement) -> Self::Output {
@@ -217,7 +222,7 @@ impl<'a> Div for &'a Measurement {
 }
 
 impl<'a> Div<&'a Self> for Measurement {
-    type Output = Self;
+    type Output = Result<Self, Error>;
 
     #[inline]
     fn div(self, other: &'a Self) -> Self::Output {
@@ -226,7 +231,7 @@ impl<'a> Div<&'a Self> for Measurement {
 }
 
 impl<'a> Div<Measurement> for &'a Measurement {
-    type Output = Measurement;
+    type Output = Result<Measurement, Error>;
 
     #[inline]
     fn div(self, other: Measurement) -> Self::Output {
@@ -238,46 +243,51 @@ impl<'a> Div<Measurement> for &'a Measurement {
 /// `Measurement`.
 ///
 impl Div<f64> for Measurement {
-    type Output = Self;
+    type Output = Result<Self, Error>;
 
     #[inline]
     fn div(self, other: f64) -> Self::Output {
-        let new_value = self.value / other;
-
-        Self {
-            value: new_value,
+        Ok(Self {
+            value: div_value(self.value, other)?,
             unit: self.unit,
-        }
+        })
     }
 }
 
 impl<'a> Div<f64> for &'a Measurement {
-    type Output = Measurement;
+    type Output = Result<Measurement, Error>;
 
     #[inline]
     fn div(self, other: f64) -> Self::Output {
-        let new_value = self.value / other;
-
-        Measurement {
-            value: new_value,
+        Ok(Measurement {
+            value: div_value(self.value, other)?,
             unit: self.unit.clone(),
-        }
+        })
     }
 }
 
+fn div_value(value: f64, other: f64) -> Result<f64, Error> {
+    if other == 0.0 {
+        return Err(Error::DivideByZero);
+    }
+
+    finite(value / other)
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::measurement::Measurement;
+    use crate::{measurement::Measurement, Error};
 
     macro_rules! validate_op {
         ($result:expr, $expected:expr) => {
+            let result = $result.unwrap();
             let expected = $expected.unwrap();
             assert_eq!(
-                $result,
+                result,
                 expected,
                 "expected Measurements to be equal;\nresult: {:?};\nunit string: {}",
-                $result,
-                $result.unit.to_string()
+                result,
+                result.unit.to_string()
             );
         };
     }
@@ -311,6 +321,16 @@ mod tests {
     mod add {
         use super::*;
 
+        #[test]
+        fn validate_add_non_finite() {
+            let max = Measurement::try_new(f64::MAX, "m").unwrap();
+
+            assert_eq!(
+                (&max + &max).unwrap_err(),
+                Error::NonFiniteValue(f64::INFINITY)
+            );
+        }
+
         #[test]
         fn validate_add_owned() {
             let m1 = Measurement::try_new(1.0, "m").unwrap();
@@ -351,6 +371,17 @@ mod tests {
     mod sub {
         use super::*;
 
+        #[test]
+        fn validate_sub_non_finite() {
+            let max = Measurement::try_new(f64::MAX, "m").unwrap();
+            let min = Measurement::try_new(f64::MIN, "m").unwrap();
+
+            assert_eq!(
+                (max - min).unwrap_err(),
+                Error::NonFiniteValue(f64::INFINITY)
+            );
+        }
+
         #[test]
         fn validate_sub_owned() {
             let m1 = Measurement::try_new(1.0, "m").unwrap();
@@ -484,7 +515,22 @@ mod tests {
             let m = Measurement::try_new(10.0, "m").unwrap();
             let expected = Measurement::try_new(200.0, "m").unwrap();
 
-            assert_eq!(m.mul(20.0), expected);
+            assert_eq!(m.mul(20.0).unwrap(), expected);
+        }
+
+        #[test]
+        fn validate_mul_non_finite() {
+            let m = Measurement::try_new(f64::MAX, "m").unwrap();
+
+            assert_eq!(
+                (&m * 2.0).unwrap_err(),
+                Error::NonFiniteValue(f64::INFINITY)
+            );
+            assert_eq!((&m * &m).unwrap_err(), Error::NonFiniteValue(f64::INFINITY));
+            assert!(matches!(
+                (m * f64::NAN).unwrap_err(),
+                Error::NonFiniteValue(value) if value.is_nan()
+            ));
         }
     }
 
@@ -564,7 +610,31 @@ mod tests {
             let m = Measurement::try_new(10.0, "m").unwrap();
             let expected = Measurement::try_new(2.0, "m").unwrap();
 
-            assert_eq!(m.div(5.0), expected);
+            assert_eq!(m.div(5.0).unwrap(), expected);
+        }
+
+        #[test]
+        fn validate_div_by_zero() {
+            let m = Measurement::try_new(10.0, "m").unwrap();
+            let zero = Measurement::try_new(0.0, "s").unwrap();
+
+            assert_eq!((&m / 0.0).unwrap_err(), Error::DivideByZero);
+            assert_eq!((&m / -0.0).unwrap_err(), Error::DivideByZero);
+            assert_eq!((&m / &zero).unwrap_err(), Error::DivideByZero);
+        }
+
+        #[test]
+        fn validate_div_non_finite() {
+            let m = Measurement::try_new(f64::MAX, "m").unwrap();
+
+            assert_eq!(
+                (&m / 0.5).unwrap_err(),
+                Error::NonFiniteValue(f64::INFINITY)
+            );
+            assert_eq!(
+                (&m / &Measurement::try_new(f64::MIN_POSITIVE, "s").unwrap()).unwrap_err(),
+                Error::NonFiniteValue(f64::INFINITY)
+            );
         }
     }
 }
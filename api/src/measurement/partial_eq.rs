@@ -85,7 +85,7 @@ mod tests {
         let m1 = Measurement::try_new(1.0, "[ft_i]").unwrap();
         let m2 = Measurement::try_new(12.0, "[in_i]").unwrap();
         let m3 = Measurement::try_new(12.0, "[ft_i].[in_i]").unwrap();
-        assert!((&m1 * &m2) == m3);
-        assert!((&m2 * &m1) == m3);
+        assert!((&m1 * &m2).unwrap() == m3);
+        assert!((&m2 * &m1).unwrap() == m3);
     }
 }
@@ -0,0 +1,197 @@
+use super::Measurement;
+use crate::{Convertible, Error, Unit};
+
+impl Measurement {
+    /// Raises both the value and the `Unit` to the power of `exponent`; useful for getting areas
+    /// and volumes from lengths.
+    ///
+    /// ```
+    /// use wise_units::Measurement;
+    ///
+    /// let side = Measurement::try_new(3.0, "m").unwrap();
+    /// let area = side.powi(2).unwrap();
+    ///
+    /// assert_eq!(area, Measurement::try_new(9.0, "m2").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// See `Unit::pow()`.
+    ///
+    pub fn powi(&self, exponent: i32) -> Result<Self, Error> {
+        let unit = self.unit.pow(exponent)?;
+
+        Self::new(self.value.powi(exponent), unit)
+    }
+
+    /// Takes the `degree`th root of both the value and the `Unit`. For odd `degree`s, negative
+    /// values keep their sign.
+    ///
+    /// # Errors
+    ///
+    /// See `Unit::root()`.
+    ///
+    pub fn root(&self, degree: u32) -> Result<Self, Error> {
+        let unit = self.unit.root(degree)?;
+
+        let value = match degree {
+            1 => self.value,
+            2 => self.value.sqrt(),
+            3 => self.value.cbrt(),
+            _ if degree % 2 == 1 && self.value.is_sign_negative() => {
+                -(-self.value).powf(1.0 / f64::from(degree))
+            }
+            _ => self.value.powf(1.0 / f64::from(degree)),
+        };
+
+        Self::new(value, unit)
+    }
+
+    /// Takes the square root of `self`.
+    ///
+    /// ```
+    /// use wise_units::Measurement;
+    ///
+    /// let area = Measurement::try_new(16.0, "m2").unwrap();
+    /// assert_eq!(area.sqrt().unwrap(), Measurement::try_new(4.0, "m").unwrap());
+    ///
+    /// // The acre's exponent is 1, so there's no exact root...
+    /// let area = Measurement::try_new(1.0, "[acr_us]").unwrap();
+    /// assert!(area.sqrt().is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// See `Unit::root()`.
+    ///
+    pub fn sqrt(&self) -> Result<Self, Error> {
+        self.root(2)
+    }
+
+    /// Converts `self` to `unit` and then takes the `degree`th root. This allows for taking roots
+    /// of units whose exponents don't divide evenly by `degree`, ex. converting `[acr_us]` to
+    /// `m2` before taking the square root.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::IncompatibleUnitTypes` if `self` can't be converted to `unit`.
+    /// * Anything `Unit::root()` can return for `unit`.
+    ///
+    pub fn root_via(&self, degree: u32, unit: &Unit) -> Result<Self, Error> {
+        self.convert_to(unit)?.root(degree)
+    }
+
+    /// Converts `self` to `unit` and then takes the square root.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wise_units::{Measurement, Unit};
+    ///
+    /// let area = Measurement::try_new(1.0, "[acr_us]").unwrap();
+    /// let m2 = Unit::from_str("m2").unwrap();
+    /// let side = area.sqrt_via(&m2).unwrap();
+    ///
+    /// assert_eq!(side.unit().expression(), "m");
+    /// assert!((side.value() - 63.615).abs() < 0.001);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// See `Measurement::root_via()`.
+    ///
+    pub fn sqrt_via(&self, unit: &Unit) -> Result<Self, Error> {
+        self.root_via(2, unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Measurement, Unit};
+    use approx::assert_relative_eq;
+    use std::str::FromStr;
+
+    #[test]
+    fn validate_powi() {
+        let measurement = Measurement::try_new(2.0, "m").unwrap();
+
+        let squared = measurement.powi(2).unwrap();
+        assert_relative_eq!(squared.value(), 4.0);
+        assert_eq!(squared.unit().expression(), "m2");
+
+        let cubed = measurement.powi(3).unwrap();
+        assert_relative_eq!(cubed.value(), 8.0);
+        assert_eq!(cubed.unit().expression(), "m3");
+
+        let inverse = measurement.powi(-1).unwrap();
+        assert_relative_eq!(inverse.value(), 0.5);
+        assert_eq!(inverse.unit().expression(), "/m");
+    }
+
+    #[test]
+    fn validate_powi_scalar() {
+        let measurement = Measurement::try_new(3.0, "cm").unwrap();
+        let squared = measurement.powi(2).unwrap();
+
+        assert_eq!(squared, Measurement::try_new(0.0009, "m2").unwrap());
+    }
+
+    #[test]
+    fn validate_powi_special() {
+        let measurement = Measurement::try_new(20.0, "Cel").unwrap();
+        assert_eq!(
+            measurement.powi(2).unwrap_err(),
+            Error::SpecialUnitNotSupported("Cel".to_string())
+        );
+    }
+
+    #[test]
+    fn validate_sqrt() {
+        let measurement = Measurement::try_new(25.0, "m2/s2").unwrap();
+        let root = measurement.sqrt().unwrap();
+
+        assert_relative_eq!(root.value(), 5.0);
+        assert_eq!(root.unit().expression(), "m/s");
+    }
+
+    #[test]
+    fn validate_sqrt_not_divisible() {
+        let measurement = Measurement::try_new(1.0, "[acr_us]").unwrap();
+        assert!(matches!(
+            measurement.sqrt(),
+            Err(Error::NoExactRoot { degree: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn validate_root() {
+        let measurement = Measurement::try_new(27.0, "m3").unwrap();
+        let root = measurement.root(3).unwrap();
+        assert_relative_eq!(root.value(), 3.0);
+        assert_eq!(root.unit().expression(), "m");
+
+        let measurement = Measurement::try_new(-32.0, "m5").unwrap();
+        let root = measurement.root(5).unwrap();
+        assert_relative_eq!(root.value(), -2.0);
+        assert_eq!(root.unit().expression(), "m");
+
+        let measurement = Measurement::try_new(16.0, "m4").unwrap();
+        let root = measurement.root(4).unwrap();
+        assert_relative_eq!(root.value(), 2.0);
+    }
+
+    #[test]
+    fn validate_sqrt_via() {
+        let measurement = Measurement::try_new(1.0, "har").unwrap();
+        let m2 = Unit::from_str("m2").unwrap();
+        let root = measurement.sqrt_via(&m2).unwrap();
+
+        assert_relative_eq!(root.value(), 100.0);
+        assert_eq!(root.unit().expression(), "m");
+
+        let s2 = Unit::from_str("s2").unwrap();
+        assert!(matches!(
+            measurement.sqrt_via(&s2),
+            Err(Error::IncompatibleUnitTypes { .. })
+        ));
+    }
+}
@@ -5,7 +5,7 @@ impl Reducible for Measurement {
         if self.is_special() {
             self.unit.reduce_value(value)
         } else {
-            value * self.unit.reduce_value(1.0)
+            value * self.unit.scalar()
         }
     }
 
@@ -0,0 +1,82 @@
+use super::Measurement;
+use crate::{Convertible, Error, UnitPreferences};
+
+impl Measurement {
+    /// Converts `self` to the `Unit` that `preferences` picks for it (see `UnitPreferences` for
+    /// how that's done), or returns a copy of `self` if there's nothing to change.
+    ///
+    /// ```
+    /// use wise_units::{Measurement, UnitPreferences};
+    ///
+    /// let density = Measurement::try_new(1.0, "kg/L").unwrap();
+    /// let density = density.to_preferred(&UnitPreferences::us_customary()).unwrap();
+    ///
+    /// // There's no preference for mass/volume, so each term is converted.
+    /// assert_eq!(density.unit().expression(), "[lb_av]/[gal_us]");
+    /// assert!((density.value() - 8.345).abs() < 0.001);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if `self` can't be converted to the preferred `Unit`.
+    ///
+    pub fn to_preferred(&self, preferences: &UnitPreferences) -> Result<Self, Error> {
+        match preferences.preferred_unit(&self.unit) {
+            Some(unit) => self.convert_to(&unit),
+            None => Ok(self.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Measurement, UnitPreferences};
+    use approx::assert_relative_eq;
+
+    fn m(value: f64, unit: &str) -> Measurement {
+        Measurement::try_new(value, unit).unwrap()
+    }
+
+    #[test]
+    fn validate_to_preferred_si() {
+        let si = UnitPreferences::si();
+
+        let area = m(1.0, "[acr_us]").to_preferred(&si).unwrap();
+        assert_relative_eq!(area.value(), 0.404_687_260_987_425_2, max_relative = 1e-12);
+        assert_eq!(area.unit().expression(), "har");
+
+        let temperature = m(212.0, "[degF]").to_preferred(&si).unwrap();
+        assert_relative_eq!(temperature.value(), 100.0, max_relative = 1e-9);
+        assert_eq!(temperature.unit().expression(), "Cel");
+
+        let rate = m(1.0, "[lb_av]/[gal_us]").to_preferred(&si).unwrap();
+        assert_relative_eq!(
+            rate.value(),
+            0.453_592_37 / 3.785_411_784,
+            max_relative = 1e-12
+        );
+        assert_eq!(rate.unit().expression(), "kg/L");
+    }
+
+    #[test]
+    fn validate_to_preferred_us_customary() {
+        let us = UnitPreferences::us_customary();
+
+        let temperature = m(100.0, "Cel").to_preferred(&us).unwrap();
+        assert_relative_eq!(temperature.value(), 212.0, max_relative = 1e-9);
+        assert_eq!(temperature.unit().expression(), "[degF]");
+
+        let rate = m(100.0, "L/har").to_preferred(&us).unwrap();
+        assert_relative_eq!(rate.value(), 10.690_9, max_relative = 1e-4);
+        assert_eq!(rate.unit().expression(), "[gal_us]/[acr_us]");
+    }
+
+    #[test]
+    fn validate_to_preferred_unchanged() {
+        let distance = m(12.5, "km");
+        let preferred = distance.to_preferred(&UnitPreferences::si()).unwrap();
+
+        assert_eq!(preferred.unit().expression(), "km");
+        assert_relative_eq!(preferred.value(), 12.5);
+    }
+}
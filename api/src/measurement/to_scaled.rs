@@ -0,0 +1,443 @@
+use super::Measurement;
+use crate::{
+    parser::{Atom, Prefix, Term, UcumSymbol},
+    ucum_unit::UcumUnit,
+    unit::Unit,
+};
+
+const DECIMAL_PREFIXES: [Prefix; 20] = [
+    Prefix::Yotta,
+    Prefix::Zetta,
+    Prefix::Exa,
+    Prefix::Peta,
+    Prefix::Tera,
+    Prefix::Giga,
+    Prefix::Mega,
+    Prefix::Kilo,
+    Prefix::Hecto,
+    Prefix::Deka,
+    Prefix::Deci,
+    Prefix::Centi,
+    Prefix::Milli,
+    Prefix::Micro,
+    Prefix::Nano,
+    Prefix::Pico,
+    Prefix::Femto,
+    Prefix::Atto,
+    Prefix::Zepto,
+    Prefix::Yocto,
+];
+
+const ENGINEERING_PREFIXES: [Prefix; 16] = [
+    Prefix::Yotta,
+    Prefix::Zetta,
+    Prefix::Exa,
+    Prefix::Peta,
+    Prefix::Tera,
+    Prefix::Giga,
+    Prefix::Mega,
+    Prefix::Kilo,
+    Prefix::Milli,
+    Prefix::Micro,
+    Prefix::Nano,
+    Prefix::Pico,
+    Prefix::Femto,
+    Prefix::Atto,
+    Prefix::Zepto,
+    Prefix::Yocto,
+];
+
+const BINARY_PREFIXES: [Prefix; 4] = [Prefix::Tebi, Prefix::Gibi, Prefix::Mebi, Prefix::Kibi];
+
+/// Determines how `Measurement::to_scaled()` picks a `Prefix` for a `Measurement`'s `Unit`.
+///
+/// By default, any decimal `Prefix` can be chosen and the target range for the resulting value is
+/// `[1, 1000)`.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScalePolicy {
+    engineering_only: bool,
+    binary: bool,
+    min: f64,
+    max: f64,
+}
+
+impl ScalePolicy {
+    /// Allows any of the decimal `Prefix`es (including deka, hecto, deci, and centi) and targets
+    /// values in `[1, 1000)`.
+    ///
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            engineering_only: false,
+            binary: false,
+            min: 1.0,
+            max: 1000.0,
+        }
+    }
+
+    /// Only allows `Prefix`es that are a power of 10^3 (kilo, mega, milli, micro, etc).
+    ///
+    #[must_use]
+    pub const fn engineering() -> Self {
+        Self {
+            engineering_only: true,
+            binary: false,
+            min: 1.0,
+            max: 1000.0,
+        }
+    }
+
+    /// Uses the binary `Prefix`es (kibi, mebi, gibi, tebi) for bits (`bit`) and bytes (`By`);
+    /// all other metric units are scaled using engineering `Prefix`es.
+    ///
+    #[must_use]
+    pub const fn binary() -> Self {
+        Self {
+            engineering_only: true,
+            binary: true,
+            min: 1.0,
+            max: 1024.0,
+        }
+    }
+
+    /// Sets the range, `[min, max)`, that the absolute value of the scaled `Measurement` should
+    /// land in. Returns `None` unless `min` is positive and finite, and `max` is greater than
+    /// `min`.
+    ///
+    #[must_use]
+    pub fn with_range(self, min: f64, max: f64) -> Option<Self> {
+        if min > 0.0 && min.is_finite() && max > min {
+            Some(Self { min, max, ..self })
+        } else {
+            None
+        }
+    }
+
+    fn candidate_prefixes(&self, atom: Atom) -> &'static [Prefix] {
+        if self.binary && matches!(atom, Atom::Bit | Atom::Byte) {
+            &BINARY_PREFIXES
+        } else if self.engineering_only {
+            &ENGINEERING_PREFIXES
+        } else {
+            &DECIMAL_PREFIXES
+        }
+    }
+
+    fn contains(&self, value: f64) -> bool {
+        let abs = value.abs();
+
+        abs >= self.min && abs < self.max
+    }
+
+    /// How far `value` is from the target range, in orders of magnitude. `0.0` means it's in the
+    /// range.
+    ///
+    fn distance(&self, value: f64) -> f64 {
+        let abs = value.abs();
+
+        if abs < self.min {
+            (self.min / abs).log10()
+        } else if abs >= self.max {
+            (abs / self.max).log10()
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Default for ScalePolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Measurement {
+    /// Picks the `Prefix` for the `Measurement`'s leading numerator `Term` so that the resulting
+    /// value lands in the range defined by `policy`, then converts to that new `Unit`. If no
+    /// `Prefix` lands the value in that range, the closest one is used.
+    ///
+    /// Only metric, non-special `Term`s are scaled; if the leading numerator `Term` isn't one of
+    /// those (or the value is 0 or not finite), an unchanged clone of `self` is returned. The same
+    /// goes for `Measurement`s whose value already lands in the range.
+    ///
+    /// ```
+    /// use wise_units::{Measurement, ScalePolicy};
+    ///
+    /// let m = Measurement::try_new(0.0005, "g").unwrap();
+    /// assert_eq!(m.to_scaled(ScalePolicy::default()).to_string(), "500 ug");
+    ///
+    /// let m = Measurement::try_new(1_234_567.0, "m").unwrap();
+    /// assert_eq!(m.to_scaled(ScalePolicy::engineering()).to_string(), "1.234567 Mm");
+    ///
+    /// let m = Measurement::try_new(1_234_567.0, "m").unwrap();
+    /// let policy = ScalePolicy::engineering().with_range(1.0, 10_000.0).unwrap();
+    /// assert_eq!(m.to_scaled(policy).unit().to_string(), "Mm");
+    ///
+    /// let m = Measurement::try_new(2500.0, "kg/[acr_us]").unwrap();
+    /// assert_eq!(m.to_scaled(ScalePolicy::engineering()).to_string(), "2.5 Mg/[acr_us]");
+    /// ```
+    ///
+    #[must_use]
+    pub fn to_scaled(&self, policy: ScalePolicy) -> Self {
+        if !self.value.is_normal() || policy.contains(self.value) {
+            return self.clone();
+        }
+
+        let index = match self
+            .unit
+            .terms()
+            .iter()
+            .position(Term::exponent_is_positive)
+        {
+            Some(index) => index,
+            None => return self.clone(),
+        };
+
+        let term = &self.unit.terms()[index];
+
+        if !is_scalable(term) {
+            return self.clone();
+        }
+
+        let atom = match term.atom {
+            Some(atom) => atom,
+            None => return self.clone(),
+        };
+
+        let current_prefix_value: f64 = term.prefix.map_or(1.0, |p| p.definition_value());
+        let exponent = term.exponent.unwrap_or(1);
+
+        let scaled_value = |prefix: Option<Prefix>| {
+            let prefix_value = prefix.map_or(1.0, |p| p.definition_value());
+
+            self.value * (current_prefix_value / prefix_value).powi(exponent)
+        };
+
+        // Ties go to the larger `Prefix`, which yields the smaller value.
+        let best = policy
+            .candidate_prefixes(atom)
+            .iter()
+            .copied()
+            .map(Some)
+            .chain(std::iter::once(None))
+            .map(|prefix| (prefix, scaled_value(prefix)))
+            .min_by(|(_, lhs), (_, rhs)| {
+                policy
+                    .distance(*lhs)
+                    .total_cmp(&policy.distance(*rhs))
+                    .then_with(|| lhs.abs().total_cmp(&rhs.abs()))
+            });
+
+        match best {
+            Some((prefix, value)) => {
+                let mut terms = self.unit.terms().to_vec();
+                terms[index].prefix = prefix;
+
+                Self::new(value, Unit::new(terms)).unwrap_or_else(|_| self.clone())
+            }
+            None => self.clone(),
+        }
+    }
+}
+
+/// Only `Term`s with a metric, non-special `Atom` can be scaled.
+///
+fn is_scalable(term: &Term) -> bool {
+    term.atom.is_some() && term.is_metric() && !term.is_special()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScalePolicy;
+    use crate::{field_eq::FieldEq, measurement::Measurement};
+    use approx::assert_relative_eq;
+
+    macro_rules! validate_to_scaled {
+        ($test_name:ident, $policy:expr, $value:expr, $unit:expr, $expected_value:expr, $expected_unit:expr) => {
+            #[test]
+            fn $test_name() {
+                let measurement = Measurement::try_new($value, $unit).unwrap();
+                let scaled = measurement.to_scaled($policy);
+
+                assert_relative_eq!(scaled.value(), $expected_value, max_relative = 1e-12);
+                assert_eq!(scaled.unit().expression(), $expected_unit);
+                assert_eq!(scaled, measurement);
+            }
+        };
+    }
+
+    validate_to_scaled!(
+        validate_gram_to_microgram,
+        ScalePolicy::new(),
+        0.0005,
+        "g",
+        500.0,
+        "ug"
+    );
+    validate_to_scaled!(
+        validate_meter_to_megameter,
+        ScalePolicy::new(),
+        1_234_567.0,
+        "m",
+        1.234_567,
+        "Mm"
+    );
+    validate_to_scaled!(validate_in_range, ScalePolicy::new(), 50.0, "m", 50.0, "m");
+    validate_to_scaled!(
+        validate_prefers_largest_prefix,
+        ScalePolicy::new(),
+        5000.0,
+        "m",
+        5.0,
+        "km"
+    );
+    validate_to_scaled!(
+        validate_kilogram_to_gram,
+        ScalePolicy::engineering(),
+        0.25,
+        "kg",
+        250.0,
+        "g"
+    );
+    validate_to_scaled!(
+        validate_negative_value,
+        ScalePolicy::new(),
+        -0.0005,
+        "g",
+        -500.0,
+        "ug"
+    );
+    validate_to_scaled!(
+        validate_exponent,
+        ScalePolicy::new(),
+        2_000_000.0,
+        "m2",
+        2.0,
+        "km2"
+    );
+    validate_to_scaled!(
+        validate_engineering,
+        ScalePolicy::engineering(),
+        0.05,
+        "m",
+        50.0,
+        "mm"
+    );
+    validate_to_scaled!(validate_decimal, ScalePolicy::new(), 0.05, "m", 5.0, "cm");
+    validate_to_scaled!(
+        validate_decimal_hecto,
+        ScalePolicy::new(),
+        0.25,
+        "kg",
+        2.5,
+        "hg"
+    );
+    validate_to_scaled!(
+        validate_leading_numerator_term,
+        ScalePolicy::engineering(),
+        2500.0,
+        "g/[acr_us]",
+        2.5,
+        "kg/[acr_us]"
+    );
+    validate_to_scaled!(
+        validate_skips_denominator_terms,
+        ScalePolicy::engineering(),
+        0.002,
+        "s-1.g",
+        2.0,
+        "mg/s"
+    );
+    validate_to_scaled!(
+        validate_keeps_annotation,
+        ScalePolicy::engineering(),
+        3000.0,
+        "g{seed}",
+        3.0,
+        "kg{seed}"
+    );
+    validate_to_scaled!(
+        validate_binary_bytes,
+        ScalePolicy::binary(),
+        3_145_728.0,
+        "By",
+        3.0,
+        "MiBy"
+    );
+    validate_to_scaled!(
+        validate_binary_non_binary_atom,
+        ScalePolicy::binary(),
+        3000.0,
+        "m",
+        3.0,
+        "km"
+    );
+    validate_to_scaled!(
+        validate_custom_range,
+        ScalePolicy::engineering()
+            .with_range(1.0, 10_000.0)
+            .unwrap(),
+        0.0012,
+        "km",
+        1.2,
+        "m"
+    );
+    validate_to_scaled!(
+        validate_out_of_prefix_range,
+        ScalePolicy::engineering(),
+        1.0e30,
+        "g",
+        1.0e6,
+        "Yg"
+    );
+
+    #[test]
+    fn validate_non_metric_is_unchanged() {
+        let measurement = Measurement::try_new(12_000.0, "[ft_i]").unwrap();
+        assert!(measurement
+            .to_scaled(ScalePolicy::new())
+            .field_eq(&measurement));
+
+        let measurement = Measurement::try_new(12_000.0, "[lb_av]/har").unwrap();
+        assert!(measurement
+            .to_scaled(ScalePolicy::new())
+            .field_eq(&measurement));
+
+        // Only the leading numerator term gets scaled, even if a later one could be.
+        let measurement = Measurement::try_new(12_000.0, "[ft_i].g").unwrap();
+        assert!(measurement
+            .to_scaled(ScalePolicy::new())
+            .field_eq(&measurement));
+    }
+
+    #[test]
+    fn validate_with_range() {
+        let policy = ScalePolicy::new();
+
+        assert!(policy.with_range(0.5, 500.0).is_some());
+        assert!(policy.with_range(1.0, f64::INFINITY).is_some());
+        assert!(policy.with_range(0.0, 1000.0).is_none());
+        assert!(policy.with_range(-1.0, 1000.0).is_none());
+        assert!(policy.with_range(f64::NAN, 1000.0).is_none());
+        assert!(policy.with_range(1.0, f64::NAN).is_none());
+        assert!(policy.with_range(1000.0, 1000.0).is_none());
+        assert!(policy.with_range(1000.0, 1.0).is_none());
+    }
+
+    #[test]
+    fn validate_special_is_unchanged() {
+        let measurement = Measurement::try_new(12_000.0, "Cel").unwrap();
+        assert!(measurement
+            .to_scaled(ScalePolicy::new())
+            .field_eq(&measurement));
+    }
+
+    #[test]
+    fn validate_zero_is_unchanged() {
+        let measurement = Measurement::try_new(0.0, "m").unwrap();
+        assert!(measurement
+            .to_scaled(ScalePolicy::new())
+            .field_eq(&measurement));
+    }
+}
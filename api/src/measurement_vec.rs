@@ -0,0 +1,624 @@
+//! A `MeasurementVec` is a column of values that all share one `Unit`, ex. the yields from a
+//! yield monitor. Unlike a `Vec<Measurement>`, the `Unit` is only stored once.
+//!
+use crate::{
+    is_compatible_with::IsCompatibleWith, measurement::finite, Converter, Convertible, Error,
+    Measurement, Unit,
+};
+use std::{
+    ops::{Add, Div, Mul, Sub},
+    slice::SliceIndex,
+    str::FromStr,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Values that all have the same `Unit`. Like a `Measurement`'s, every value is finite.
+///
+/// ```
+/// use wise_units::{Convertible, MeasurementVec};
+///
+/// let yields = MeasurementVec::try_new(vec![150.0, 175.5, 201.25], "[bu_us]/[acr_us]").unwrap();
+/// let converted = yields.convert_to("L/har").unwrap();
+///
+/// assert_eq!(converted.unit().expression(), "L/har");
+/// assert_eq!(converted.len(), 3);
+///
+/// let mean = yields.mean().unwrap();
+/// assert!((mean.value() - 175.583_333).abs() < 0.000_001);
+/// ```
+///
+/// With the `serde` feature, it's (de)serialized as `{ "unit": "...", "values": [...] }`.
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MeasurementVec {
+    unit: Unit,
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "deserialize_finite_values")
+    )]
+    values: Vec<f64>,
+}
+
+impl MeasurementVec {
+    /// # Errors
+    ///
+    /// Returns an `Error::NonFiniteValue` if any of `values` is NaN or infinite.
+    ///
+    pub fn new(values: Vec<f64>, unit: Unit) -> Result<Self, Error> {
+        for &value in &values {
+            let _ = finite(value)?;
+        }
+
+        Ok(Self { unit, values })
+    }
+
+    /// Creates a new `MeasurementVec` by parsing `expression` into a `Unit`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if `expression` isn't one that represents a valid `Unit`, or an
+    /// `Error::NonFiniteValue` if any of `values` is NaN or infinite.
+    ///
+    pub fn try_new(values: Vec<f64>, expression: &str) -> Result<Self, Error> {
+        let unit = Unit::from_str(expression)?;
+
+        Self::new(values, unit)
+    }
+
+    /// Collects `measurements` into a column of `unit`, converting each of them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::IncompatibleUnitTypes` if any of `measurements` can't be converted to
+    /// `unit`, or an `Error::NonFiniteValue` if a converted value isn't finite.
+    ///
+    pub fn from_measurements(measurements: &[Measurement], unit: Unit) -> Result<Self, Error> {
+        let values = measurements
+            .iter()
+            .map(|measurement| measurement.convert_to(&unit).map(|m| m.value()))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { unit, values })
+    }
+
+    #[must_use]
+    pub const fn unit(&self) -> &Unit {
+        &self.unit
+    }
+
+    #[must_use]
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The value at `index`, as a `Measurement`.
+    ///
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<Measurement> {
+        self.values.get(index).map(|&value| self.measurement(value))
+    }
+
+    /// A new `MeasurementVec` of the values in `range` (ex. `1..3`, `..10`), or `None` if it's
+    /// out of bounds.
+    ///
+    #[must_use]
+    pub fn slice<R>(&self, range: R) -> Option<Self>
+    where
+        R: SliceIndex<[f64], Output = [f64]>,
+    {
+        self.values.get(range).map(|values| Self {
+            unit: self.unit.clone(),
+            values: values.to_vec(),
+        })
+    }
+
+    /// Each value, as a `Measurement`.
+    ///
+    #[must_use]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = Measurement> + '_ {
+        self.values
+            .iter()
+            .map(move |&value| self.measurement(value))
+    }
+
+    /// Appends `measurement`, converted to `unit()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::IncompatibleUnitTypes` if `measurement` can't be converted to `unit()`,
+    /// or an `Error::NonFiniteValue` if the converted value isn't finite.
+    ///
+    pub fn push(&mut self, measurement: &Measurement) -> Result<(), Error> {
+        let converted = measurement.convert_to(&self.unit)?;
+        self.values.push(converted.value());
+
+        Ok(())
+    }
+
+    //-------------------------------------------------------------------------
+    // Aggregation
+    //-------------------------------------------------------------------------
+    /// The sum of all values; 0 if there aren't any.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::NonFiniteValue` if the sum overflows.
+    ///
+    pub fn sum(&self) -> Result<Measurement, Error> {
+        let sum = finite(self.values.iter().sum())?;
+
+        Ok(self.measurement(sum))
+    }
+
+    /// The mean of all values, or `None` if there aren't any. It's calculated as a running mean,
+    /// so it's finite even if the sum of the values wouldn't be.
+    ///
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn mean(&self) -> Option<Measurement> {
+        if self.values.is_empty() {
+            return None;
+        }
+
+        let mean = self
+            .values
+            .iter()
+            .enumerate()
+            .fold(0.0, |mean, (index, value)| {
+                let count = (index + 1) as f64;
+
+                // Dividing each term first keeps them (and so the mean) from overflowing.
+                mean - mean / count + value / count
+            });
+
+        Some(self.measurement(mean))
+    }
+
+    /// The smallest value, or `None` if there aren't any.
+    ///
+    #[must_use]
+    pub fn min(&self) -> Option<Measurement> {
+        self.values
+            .iter()
+            .copied()
+            .reduce(f64::min)
+            .map(|value| self.measurement(value))
+    }
+
+    /// The largest value, or `None` if there aren't any.
+    ///
+    #[must_use]
+    pub fn max(&self) -> Option<Measurement> {
+        self.values
+            .iter()
+            .copied()
+            .reduce(f64::max)
+            .map(|value| self.measurement(value))
+    }
+
+    /// Every value is finite, so there's no need to check it again.
+    ///
+    fn measurement(&self, value: f64) -> Measurement {
+        Measurement::new_unchecked(value, self.unit.clone())
+    }
+
+    fn with_values(&self, values: Vec<f64>, unit: Unit) -> Result<Self, Error> {
+        debug_assert_eq!(values.len(), self.values.len());
+
+        Self::new(values, unit)
+    }
+
+    fn check_len(&self, other: &Self) -> Result<(), Error> {
+        if self.values.len() == other.values.len() {
+            Ok(())
+        } else {
+            Err(Error::LengthMismatch {
+                lhs: self.values.len(),
+                rhs: other.values.len(),
+            })
+        }
+    }
+
+    /// `other`'s values, converted to `self`'s `Unit`.
+    ///
+    fn converted_values(&self, other: &Self) -> Result<Vec<f64>, Error> {
+        other
+            .convert_to(&self.unit)
+            .map(|converted| converted.values)
+    }
+}
+
+#[cfg(feature = "serde")]
+fn deserialize_finite_values<'de, D>(deserializer: D) -> Result<Vec<f64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let values = Vec::<f64>::deserialize(deserializer)?;
+
+    for &value in &values {
+        let _ = finite(value).map_err(serde::de::Error::custom)?;
+    }
+
+    Ok(values)
+}
+
+//-----------------------------------------------------------------------------
+// impl Convertible
+//-----------------------------------------------------------------------------
+/// Converts every value to `other_unit`, working out the conversion only once (see
+/// `Converter`).
+///
+impl<'a> Convertible<&'a Unit> for MeasurementVec {
+    type Output = Self;
+    type ConversionError = Error;
+
+    fn convert_to(&self, other_unit: &'a Unit) -> Result<Self, Self::ConversionError> {
+        let converter = Converter::new(&self.unit, other_unit)?;
+        let mut values = self.values.clone();
+        converter.convert_in_place(&mut values);
+
+        self.with_values(values, other_unit.clone())
+    }
+}
+
+impl<'a> Convertible<&'a str> for MeasurementVec {
+    type Output = Self;
+    type ConversionError = Error;
+
+    fn convert_to(&self, expression: &'a str) -> Result<Self, Self::ConversionError> {
+        let other_unit = Unit::from_str(expression)?;
+
+        self.convert_to(&other_unit)
+    }
+}
+
+//-----------------------------------------------------------------------------
+// impl Add, Sub, Mul, Div
+//-----------------------------------------------------------------------------
+/// Implements `$trait` for each combination of owned and borrowed `MeasurementVec`s, calling
+/// `$function`.
+///
+macro_rules! impl_column_op {
+    ($trait:ident, $method:ident, $function:ident) => {
+        impl $trait for MeasurementVec {
+            type Output = Result<Self, Error>;
+
+            #[inline]
+            fn $method(self, other: Self) -> Self::Output {
+                $function(&self, &other)
+            }
+        }
+
+        impl<'a> $trait<&'a Self> for MeasurementVec {
+            type Output = Result<Self, Error>;
+
+            #[inline]
+            fn $method(self, other: &'a Self) -> Self::Output {
+                $function(&self, other)
+            }
+        }
+
+        impl<'a> $trait for &'a MeasurementVec {
+            type Output = Result<MeasurementVec, Error>;
+
+            #[inline]
+            fn $method(self, other: &'a MeasurementVec) -> Self::Output {
+                $function(self, other)
+            }
+        }
+
+        impl<'a> $trait<MeasurementVec> for &'a MeasurementVec {
+            type Output = Result<MeasurementVec, Error>;
+
+            #[inline]
+            fn $method(self, other: MeasurementVec) -> Self::Output {
+                $function(self, &other)
+            }
+        }
+    };
+}
+
+/// Adds each of `rhs`'s values, converted to `lhs`'s `Unit`, to the same index of `lhs`.
+///
+fn add_columns(lhs: &MeasurementVec, rhs: &MeasurementVec) -> Result<MeasurementVec, Error> {
+    lhs.check_len(rhs)?;
+    let mut values = lhs.converted_values(rhs)?;
+
+    for (value, lhs_value) in values.iter_mut().zip(&lhs.values) {
+        *value += lhs_value;
+    }
+
+    lhs.with_values(values, lhs.unit.clone())
+}
+
+fn sub_columns(lhs: &MeasurementVec, rhs: &MeasurementVec) -> Result<MeasurementVec, Error> {
+    lhs.check_len(rhs)?;
+    let mut values = lhs.converted_values(rhs)?;
+
+    for (value, lhs_value) in values.iter_mut().zip(&lhs.values) {
+        *value = lhs_value - *value;
+    }
+
+    lhs.with_values(values, lhs.unit.clone())
+}
+
+/// Like multiplying `Measurement`s, `rhs` is converted to `lhs`'s `Unit` first if it can be.
+///
+fn mul_columns(lhs: &MeasurementVec, rhs: &MeasurementVec) -> Result<MeasurementVec, Error> {
+    lhs.check_len(rhs)?;
+
+    let (mut values, rhs_unit) = if lhs.unit.is_compatible_with(&rhs.unit) {
+        (lhs.converted_values(rhs)?, &lhs.unit)
+    } else {
+        (rhs.values.clone(), &rhs.unit)
+    };
+
+    for (value, lhs_value) in values.iter_mut().zip(&lhs.values) {
+        *value *= lhs_value;
+    }
+
+    lhs.with_values(values, lhs.unit.checked_mul(rhs_unit)?)
+}
+
+/// Like dividing `Measurement`s, `rhs` is converted to `lhs`'s `Unit` first if it can be.
+///
+fn div_columns(lhs: &MeasurementVec, rhs: &MeasurementVec) -> Result<MeasurementVec, Error> {
+    lhs.check_len(rhs)?;
+
+    let (mut values, rhs_unit) = if lhs.unit.is_compatible_with(&rhs.unit) {
+        (lhs.converted_values(rhs)?, &lhs.unit)
+    } else {
+        (rhs.values.clone(), &rhs.unit)
+    };
+
+    for (value, lhs_value) in values.iter_mut().zip(&lhs.values) {
+        if *value == 0.0 {
+            return Err(Error::DivideByZero);
+        }
+
+        *value = lhs_value / *value;
+    }
+
+    lhs.with_values(values, lhs.unit.checked_div(rhs_unit)?)
+}
+
+impl_column_op!(Add, add, add_columns);
+impl_column_op!(Sub, sub, sub_columns);
+impl_column_op!(Mul, mul, mul_columns);
+impl_column_op!(Div, div, div_columns);
+
+/// Multiplies each value by `other`.
+///
+impl Mul<f64> for MeasurementVec {
+    type Output = Result<Self, Error>;
+
+    #[inline]
+    fn mul(self, other: f64) -> Self::Output {
+        &self * other
+    }
+}
+
+impl<'a> Mul<f64> for &'a MeasurementVec {
+    type Output = Result<MeasurementVec, Error>;
+
+    fn mul(self, other: f64) -> Self::Output {
+        let values = self.values.iter().map(|value| value * other).collect();
+
+        self.with_values(values, self.unit.clone())
+    }
+}
+
+/// Divides each value by `other`.
+///
+impl Div<f64> for MeasurementVec {
+    type Output = Result<Self, Error>;
+
+    #[inline]
+    fn div(self, other: f64) -> Self::Output {
+        &self / other
+    }
+}
+
+impl<'a> Div<f64> for &'a MeasurementVec {
+    type Output = Result<MeasurementVec, Error>;
+
+    fn div(self, other: f64) -> Self::Output {
+        if other == 0.0 {
+            return Err(Error::DivideByZero);
+        }
+
+        let values = self.values.iter().map(|value| value / other).collect();
+
+        self.with_values(values, self.unit.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MeasurementVec;
+    use crate::{Convertible, Error, Measurement, Unit};
+    use approx::assert_relative_eq;
+    use std::str::FromStr;
+
+    fn column(values: &[f64], expression: &str) -> MeasurementVec {
+        MeasurementVec::try_new(values.to_vec(), expression).unwrap()
+    }
+
+    #[test]
+    fn validate_new() {
+        assert!(matches!(
+            MeasurementVec::try_new(vec![1.0, f64::INFINITY], "m"),
+            Err(Error::NonFiniteValue(_))
+        ));
+        assert!(MeasurementVec::try_new(vec![1.0], "not_a_unit").is_err());
+
+        let measurements = [
+            Measurement::try_new(1.0, "km").unwrap(),
+            Measurement::try_new(250.0, "m").unwrap(),
+        ];
+        let subject =
+            MeasurementVec::from_measurements(&measurements, Unit::from_str("m").unwrap()).unwrap();
+        assert_eq!(subject.values(), &[1000.0, 250.0]);
+
+        let mut subject = column(&[1.0], "m");
+        subject
+            .push(&Measurement::try_new(2.0, "cm").unwrap())
+            .unwrap();
+        assert_eq!(subject.values(), &[1.0, 0.02]);
+        assert!(subject
+            .push(&Measurement::try_new(1.0, "g").unwrap())
+            .is_err());
+    }
+
+    #[test]
+    fn validate_accessors() {
+        let subject = column(&[1.0, 2.0, 3.0, 4.0], "kg");
+
+        assert_eq!(subject.len(), 4);
+        assert!(!subject.is_empty());
+        assert_eq!(
+            subject.get(1),
+            Some(Measurement::try_new(2.0, "kg").unwrap())
+        );
+        assert_eq!(subject.get(4), None);
+
+        assert_eq!(subject.slice(1..3), Some(column(&[2.0, 3.0], "kg")));
+        assert_eq!(subject.slice(..1), Some(column(&[1.0], "kg")));
+        assert_eq!(subject.slice(3..5), None);
+
+        let measurements: Vec<Measurement> = subject.iter().collect();
+        assert_eq!(measurements.len(), 4);
+        assert_eq!(measurements[3], Measurement::try_new(4.0, "kg").unwrap());
+    }
+
+    #[test]
+    fn validate_convert_to() {
+        let subject = column(&[0.0, 100.0, -40.0], "Cel");
+        let converted = subject.convert_to("[degF]").unwrap();
+
+        assert_eq!(converted.unit().expression(), "[degF]");
+        assert_relative_eq!(converted.values()[0], 32.0, max_relative = 1e-12);
+        assert_relative_eq!(converted.values()[1], 212.0, max_relative = 1e-12);
+        assert_relative_eq!(converted.values()[2], -40.0, max_relative = 1e-12);
+
+        assert!(matches!(
+            subject.convert_to("m"),
+            Err(Error::IncompatibleUnitTypes { .. })
+        ));
+    }
+
+    #[test]
+    fn validate_column_ops() {
+        let lhs = column(&[1.0, 2.0], "m");
+        let rhs = column(&[50.0, 100.0], "cm");
+
+        let sum = (&lhs + &rhs).unwrap();
+        assert_eq!(sum, column(&[1.5, 3.0], "m"));
+
+        let difference = (&lhs - &rhs).unwrap();
+        assert_eq!(difference, column(&[0.5, 1.0], "m"));
+
+        let product = (&lhs * &rhs).unwrap();
+        assert_eq!(product, column(&[0.5, 2.0], "m2"));
+
+        let quotient = (&lhs / &column(&[2.0, 4.0], "s")).unwrap();
+        assert_eq!(quotient, column(&[0.5, 0.5], "m/s"));
+
+        assert!(matches!(
+            &lhs + &column(&[1.0], "m"),
+            Err(Error::LengthMismatch { lhs: 2, rhs: 1 })
+        ));
+        assert!(matches!(
+            &lhs + &column(&[1.0, 2.0], "g"),
+            Err(Error::IncompatibleUnitTypes { .. })
+        ));
+        assert_eq!(&lhs / &column(&[1.0, 0.0], "s"), Err(Error::DivideByZero));
+    }
+
+    #[test]
+    fn validate_scalar_ops() {
+        let subject = column(&[1.0, 2.0], "kg");
+
+        assert_eq!((&subject * 2.0).unwrap(), column(&[2.0, 4.0], "kg"));
+        assert_eq!((subject.clone() / 4.0).unwrap(), column(&[0.25, 0.5], "kg"));
+        assert_eq!(&subject / 0.0, Err(Error::DivideByZero));
+        assert!(matches!(subject * f64::MAX, Err(Error::NonFiniteValue(_))));
+    }
+
+    #[test]
+    fn validate_aggregation() {
+        let subject = column(&[3.0, -1.0, 4.0, 2.0], "L");
+
+        assert_eq!(
+            subject.sum().unwrap(),
+            Measurement::try_new(8.0, "L").unwrap()
+        );
+        assert_eq!(
+            subject.mean().unwrap(),
+            Measurement::try_new(2.0, "L").unwrap()
+        );
+        assert_eq!(
+            subject.min(),
+            Some(Measurement::try_new(-1.0, "L").unwrap())
+        );
+        assert_eq!(subject.max(), Some(Measurement::try_new(4.0, "L").unwrap()));
+
+        // The sum overflows, but the mean doesn't.
+        let large = column(&[f64::MAX, f64::MAX, f64::MAX / 2.0], "L");
+        assert!(matches!(large.sum(), Err(Error::NonFiniteValue(_))));
+        assert_relative_eq!(
+            large.mean().unwrap().value(),
+            f64::MAX / 6.0 * 5.0,
+            max_relative = 1e-12
+        );
+
+        let empty = column(&[], "L");
+        assert_eq!(
+            empty.sum().unwrap(),
+            Measurement::try_new(0.0, "L").unwrap()
+        );
+        assert_eq!(empty.mean(), None);
+        assert_eq!(empty.min(), None);
+        assert_eq!(empty.max(), None);
+    }
+
+    #[cfg(feature = "serde")]
+    mod serde {
+        use super::column;
+        use super::MeasurementVec;
+
+        #[test]
+        fn validate_serde_json() {
+            let subject = column(&[1.5, 2.0], "kg/har");
+            let json = serde_json::to_string(&subject).unwrap();
+            assert_eq!(json, r#"{"unit":"kg/har","values":[1.5,2.0]}"#);
+
+            let deserialized: MeasurementVec = serde_json::from_str(&json).unwrap();
+            assert_eq!(deserialized, subject);
+        }
+
+        #[test]
+        fn validate_deserialize_errors() {
+            let result: Result<MeasurementVec, _> =
+                serde_json::from_str(r#"{"unit":"not_a_unit","values":[1.0]}"#);
+            assert!(result.is_err());
+
+            let result: Result<MeasurementVec, _> =
+                serde_json::from_str(r#"{"unit":"m","values":[1e400]}"#);
+            assert!(result.is_err());
+        }
+    }
+}
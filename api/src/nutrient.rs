@@ -0,0 +1,260 @@
+//! Conversions between the forms a nutrient is reported in. Fertilizer labels give phosphorus as
+//! P2O5 and potassium as K2O, while agronomic models use elemental P and K; the form is carried
+//! as an annotation (ex. `kg{P2O5}/har`).
+//!
+use crate::{Convertible, Error, Measurement, Unit};
+
+/// Elemental mass fractions: one unit of the first form contains `factor` units of the second.
+///
+const DEFAULT_FACTORS: [(&str, &str, f64); 7] = [
+    ("P2O5", "P", 0.4364),
+    ("K2O", "K", 0.8301),
+    ("NO3", "N", 0.2259),
+    ("NH4", "N", 0.7765),
+    ("SO4", "S", 0.3338),
+    ("CaO", "Ca", 0.7147),
+    ("MgO", "Mg", 0.6031),
+];
+
+#[derive(Clone, Debug, PartialEq)]
+struct NutrientFactor {
+    from: String,
+    to: String,
+    factor: f64,
+}
+
+/// Holds the factors for converting between annotated nutrient forms. Each factor can be used in
+/// either direction.
+///
+/// ```
+/// use std::str::FromStr;
+/// use wise_units::{nutrient::NutrientTable, Measurement, Unit};
+///
+/// let table = NutrientTable::new();
+///
+/// let oxide = Measurement::try_new(100.0, "kg{P2O5}/har").unwrap();
+/// let elemental = table
+///     .convert(&oxide, &Unit::from_str("kg{P}/har").unwrap())
+///     .unwrap();
+///
+/// assert!((elemental.value() - 43.64).abs() < 0.000_001);
+/// assert_eq!(elemental.unit().expression(), "kg{P}/har");
+/// ```
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct NutrientTable {
+    factors: Vec<NutrientFactor>,
+}
+
+impl NutrientTable {
+    /// A table with the built-in factors: P2O5 → P, K2O → K, NO3 → N, NH4 → N, SO4 → S,
+    /// CaO → Ca, and MgO → Mg.
+    ///
+    /// # Panics
+    ///
+    /// Only if one of the built-in factors is invalid, which would be a bug.
+    ///
+    #[must_use]
+    pub fn new() -> Self {
+        let mut table = Self::empty();
+
+        for (from, to, factor) in &DEFAULT_FACTORS {
+            table
+                .register(from, to, *factor)
+                .expect("built-in factors are valid");
+        }
+
+        table
+    }
+
+    /// A table without any of the built-in factors.
+    ///
+    #[must_use]
+    pub const fn empty() -> Self {
+        Self {
+            factors: Vec::new(),
+        }
+    }
+
+    /// Adds a factor that says one unit of `from` contains `factor` units of `to`. This replaces
+    /// any factor already registered for `from` and `to`, in either direction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::InvalidFactor` (leaving `self` unchanged) if `factor` isn't finite and
+    /// greater than 0.
+    ///
+    pub fn register(&mut self, from: &str, to: &str, factor: f64) -> Result<(), Error> {
+        if !(factor.is_finite() && factor > 0.0) {
+            return Err(Error::InvalidFactor(factor));
+        }
+
+        self.factors.retain(|existing| {
+            !((existing.from == from && existing.to == to)
+                || (existing.from == to && existing.to == from))
+        });
+
+        self.factors.push(NutrientFactor {
+            from: from.to_string(),
+            to: to.to_string(),
+            factor,
+        });
+
+        Ok(())
+    }
+
+    /// What a value in the `from` form gets multiplied by to get the value in the `to` form.
+    ///
+    #[must_use]
+    pub fn factor(&self, from: &str, to: &str) -> Option<f64> {
+        if from == to {
+            return Some(1.0);
+        }
+
+        self.factors.iter().find_map(|existing| {
+            if existing.from == from && existing.to == to {
+                Some(existing.factor)
+            } else if existing.from == to && existing.to == from {
+                Some(1.0 / existing.factor)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Converts `measurement` to `unit`. If both have an annotation and they differ, the
+    /// annotations are treated as nutrient forms and the registered factor is applied; otherwise
+    /// this is the same as `Measurement::convert_to()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::IncompatibleUnitTypes` if no factor is registered for the annotations,
+    /// or if the `Unit`s aren't compatible once the annotations are removed.
+    ///
+    pub fn convert(&self, measurement: &Measurement, unit: &Unit) -> Result<Measurement, Error> {
+        let (from, to) = match (
+            measurement.unit().first_annotation(),
+            unit.first_annotation(),
+        ) {
+            (Some(from), Some(to)) if from != to => (from, to),
+            _ => return measurement.convert_to(unit),
+        };
+
+        let factor = self
+            .factor(from, to)
+            .ok_or_else(|| Error::IncompatibleUnitTypes {
+                lhs: measurement.unit().expression(),
+                rhs: unit.expression(),
+            })?;
+
+        let source = Measurement::new(
+            measurement.value(),
+            measurement.unit().without_annotation(from),
+        )?;
+        let converted = source.convert_to(&unit.without_annotation(to))?;
+
+        Measurement::new(converted.value() * factor, unit.clone())
+    }
+}
+
+impl Default for NutrientTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NutrientTable;
+    use crate::{Error, Measurement, Unit};
+    use approx::assert_relative_eq;
+    use std::str::FromStr;
+
+    fn convert(value: f64, from: &str, to: &str) -> Result<Measurement, Error> {
+        let measurement = Measurement::try_new(value, from).unwrap();
+        NutrientTable::new().convert(&measurement, &Unit::from_str(to).unwrap())
+    }
+
+    #[test]
+    fn validate_oxide_to_elemental() {
+        let converted = convert(100.0, "kg{P2O5}/har", "kg{P}/har").unwrap();
+        assert_relative_eq!(converted.value(), 43.64, max_relative = 1e-12);
+        assert_eq!(converted.unit().expression(), "kg{P}/har");
+
+        let converted = convert(100.0, "[lb_av]{K2O}", "[lb_av]{K}").unwrap();
+        assert_relative_eq!(converted.value(), 83.01, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn validate_elemental_to_oxide() {
+        let converted = convert(43.64, "kg{P}/har", "kg{P2O5}/har").unwrap();
+        assert_relative_eq!(converted.value(), 100.0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn validate_with_unit_conversion() {
+        let converted = convert(100.0, "kg{P2O5}/har", "[lb_av]{P}/[acr_us]").unwrap();
+        assert_relative_eq!(
+            converted.value(),
+            43.64 * 4_046.872_609_874_252 / 10_000.0 / 0.453_592_37,
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn validate_same_annotation() {
+        let converted = convert(1.0, "kg{P2O5}", "g{P2O5}").unwrap();
+        assert_relative_eq!(converted.value(), 1000.0);
+    }
+
+    #[test]
+    fn validate_unregistered() {
+        assert_eq!(
+            convert(1.0, "kg{P2O5}", "kg{K}").unwrap_err(),
+            Error::IncompatibleUnitTypes {
+                lhs: "kg{P2O5}".to_string(),
+                rhs: "kg{K}".to_string()
+            }
+        );
+        assert!(convert(1.0, "kg{P2O5}", "kg").is_err());
+        assert!(convert(1.0, "kg{P2O5}", "L{P}").is_err());
+    }
+
+    #[test]
+    fn validate_register() {
+        let mut table = NutrientTable::empty();
+        assert_eq!(table.factor("P2O5", "P"), None);
+        assert_eq!(table.factor("P", "P"), Some(1.0));
+
+        table.register("B2O3", "B", 0.3106).unwrap();
+        assert_eq!(table.factor("B2O3", "B"), Some(0.3106));
+        assert_relative_eq!(table.factor("B", "B2O3").unwrap(), 1.0 / 0.3106);
+
+        table.register("B", "B2O3", 3.0).unwrap();
+        assert_eq!(table.factor("B", "B2O3"), Some(3.0));
+        assert_relative_eq!(table.factor("B2O3", "B").unwrap(), 1.0 / 3.0);
+    }
+
+    #[test]
+    fn validate_register_invalid() {
+        let mut table = NutrientTable::new();
+
+        for factor in &[0.0, -0.4364, f64::NAN, f64::INFINITY] {
+            let error = table.register("P2O5", "P", *factor).unwrap_err();
+            assert!(matches!(error, Error::InvalidFactor(_)), "{}", factor);
+        }
+
+        // The existing factor is left alone.
+        assert_eq!(table.factor("P2O5", "P"), Some(0.4364));
+    }
+
+    #[test]
+    fn validate_defaults() {
+        let table = NutrientTable::new();
+
+        assert_eq!(table.factor("P2O5", "P"), Some(0.4364));
+        assert_eq!(table.factor("K2O", "K"), Some(0.8301));
+        assert_eq!(table.factor("NO3", "N"), Some(0.2259));
+        assert_eq!(table.factor("SO4", "S"), Some(0.3338));
+    }
+}
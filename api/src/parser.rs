@@ -21,10 +21,12 @@ mod annotation_composition;
 mod atom_test;
 mod composable;
 mod composition;
+mod constant_profile;
 mod definition;
 mod dimension;
 mod error;
 mod function_set;
+mod parse_limits;
 mod prefix;
 mod term;
 mod terms;
@@ -32,10 +34,14 @@ mod ucum_symbol;
 
 pub use self::{
     annotation_composition::AnnotationComposition, atom::Atom, classification::Classification,
-    composable::Composable, composition::Composition, dimension::Dimension, error::Error,
-    prefix::Prefix, property::Property, term::Term, ucum_symbol::UcumSymbol,
+    composable::Composable, composition::Composition, constant_profile::ConstantProfile,
+    dimension::Dimension, error::Error, parse_limits::ParseLimits, prefix::Prefix,
+    property::Property, term::Term, ucum_symbol::UcumSymbol,
 };
 
+pub(crate) use self::annotation_composition::AnnotationComposable;
+pub(crate) use self::term::checked_composition;
+
 use self::{
     symbols::symbol_parser::Rule as SymbolRule,
     terms::term_parser::{Rule as TermRule, TermParser},
@@ -44,10 +50,27 @@ use pest::{iterators::Pair, Parser};
 
 #[inline]
 pub(crate) fn parse(expression: &str) -> Result<Vec<Term>, Error> {
-    match TermParser::parse(TermRule::main_term, expression) {
-        Ok(pairs) => Ok(terms::mapper::map(pairs)?),
-        Err(_) => Err(Error::UnknownUnitString(expression.to_string())),
-    }
+    parse_with_limits(expression, ParseLimits::new())
+}
+
+pub(crate) fn parse_with_limits(expression: &str, limits: ParseLimits) -> Result<Vec<Term>, Error> {
+    limits.check_expression(expression)?;
+
+    let terms = match TermParser::parse(TermRule::main_term, expression) {
+        Ok(pairs) => terms::mapper::map(pairs).map_err(|error| match error {
+            // The mapper only knows about the range of an `i32`.
+            Error::ExponentOutOfRange { exponent, .. } => Error::ExponentOutOfRange {
+                exponent,
+                max: limits.max_exponent(),
+            },
+            other => other,
+        })?,
+        Err(_) => return Err(Error::UnknownUnitString(expression.to_string())),
+    };
+
+    limits.check_terms(expression, &terms)?;
+
+    Ok(terms)
 }
 
 trait Visit<R> {
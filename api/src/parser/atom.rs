@@ -6,8 +6,8 @@
 use crate::{
     is_compatible_with::{DefaultCompatibility, IsCompatibleWith},
     parser::{
-        definition::Definition, function_set::FunctionSet, Classification, Composable, Composition,
-        Dimension, Property, UcumSymbol,
+        constant_profile::ConstantProfile, definition::Definition, Classification, Composable,
+        Composition, Property, UcumSymbol,
     },
     reducible::Reducible,
     UcumUnit, Unit,
@@ -329,797 +329,368 @@ pub enum Atom {
 }
 
 impl Atom {
-    pub(crate) fn definition(self) -> Definition {
-        let result = match self {
-            Self::Meter => Ok(Definition::default()),
-            Self::Second => Ok(Definition::default()),
-            Self::Gram => Ok(Definition::default()),
-            Self::Radian => Ok(Definition::default()),
-            Self::Kelvin => Ok(Definition::default()),
-            Self::Coulomb => Ok(Definition::default()),
-            Self::Candela => Ok(Definition::default()),
-            Self::TheNumberTenForArbitraryPowersStar => Definition::new(10.0, "1", None),
-            Self::TheNumberTenForArbitraryPowersCaret => Definition::new(10.0, "1", None),
-            Self::TheNumberPi => Definition::new(::std::f64::consts::PI, "1", None),
-            Self::Percent => Definition::new(1.0, "10*-2", None),
-            Self::PartsPerThousand => Definition::new(1.0, "10*-3", None),
-            Self::PartsPerMillion => Definition::new(1.0, "10*-6", None),
-            Self::PartsPerBillion => Definition::new(1.0, "10*-9", None),
-            Self::PartsPerTrillion => Definition::new(1.0, "10*-12", None),
-            Self::Mole => Definition::new(6.0221367, "10*23", None),
-            Self::Steradian => Definition::new(1.0, "rad2", None),
-            Self::Hertz => Definition::new(1.0, "s-1", None),
-            Self::Newton => Definition::new(1.0, "kg.m/s2", None),
-            Self::Pascal => Definition::new(1.0, "N/m2", None),
-            Self::Joule => Definition::new(1.0, "N.m", None),
-            Self::Watt => Definition::new(1.0, "J/s", None),
-            Self::Ampere => Definition::new(1.0, "C/s", None),
-            Self::Volt => Definition::new(1.0, "J/C", None),
-            Self::Farad => Definition::new(1.0, "C/V", None),
-            Self::Ohm => Definition::new(1.0, "V/A", None),
-            Self::Siemens => Definition::new(1.0, "Ohm-1", None),
-            Self::Weber => Definition::new(1.0, "V.s", None),
-            Self::DegreeCelsius => Definition::new(
-                1.0,
-                "K",
-                Some(FunctionSet {
-                    convert_from: |value: f64| value - 273.15,
-                    convert_to: |value: f64| value + 273.15,
-                }),
-            ),
-            Self::Tesla => Definition::new(1.0, "Wb/m2", None),
-            Self::Henry => Definition::new(1.0, "Wb/A", None),
-            Self::Lumen => Definition::new(1.0, "cd.sr", None),
-            Self::Lux => Definition::new(1.0, "lm/m2", None),
-            Self::Becquerel => Definition::new(1.0, "s-1", None),
-            Self::Gray => Definition::new(1.0, "J/kg", None),
-            Self::Sievert => Definition::new(1.0, "J/kg", None),
-            Self::Gon => Definition::new(0.9, "deg", None),
-            Self::Degree => Definition::new(2.0, "[pi].rad/360", None),
-            Self::MinuteAngle => Definition::new(1.0, "deg/60", None),
-            Self::SecondAngle => Definition::new(1.0, "'/60", None),
-            Self::Liter => Definition::new(1.0, "dm3", None),
-            Self::LiterSecondary => Definition::new(1.0, "l", None),
-            Self::Are => Definition::new(100.0, "m2", None),
-            Self::Minute => Definition::new(60.0, "s", None),
-            Self::Hour => Definition::new(60.0, "min", None),
-            Self::Day => Definition::new(24.0, "h", None),
-            Self::TropicalYear => Definition::new(365.24219, "d", None),
-            Self::MeanJulianYear => Definition::new(365.25, "d", None),
-            Self::MeanGregorianYear => Definition::new(365.2425, "d", None),
-            Self::Year => Definition::new(1.0, "a_j", None),
-            Self::Week => Definition::new(7.0, "d", None),
-            Self::SynodalMonth => Definition::new(29.53059, "d", None),
-            Self::MeanJulianMonth => Definition::new(1.0, "a_j/12", None),
-            Self::MeanGregorianMonth => Definition::new(1.0, "a_g/12", None),
-            Self::Month => Definition::new(1.0, "mo_j", None),
-            Self::Tonne => Definition::new(1000.0, "kg", None),
-            Self::Bar => Definition::new(100000.0, "Pa", None),
-            Self::UnifiedAtomicMassUnit => {
-                Definition::new(0.0000000000000000000000016605402, "g", None)
-            }
-            Self::Electronvolt => Definition::new(1.0, "[e].V", None),
-            Self::AstronomicUnit => Definition::new(149597.870691, "Mm", None),
-            Self::Parsec => Definition::new(30856780000000000.0, "m", None),
-            Self::VelocityOfLight => Definition::new(299792458.0, "m/s", None),
-            Self::PlanckConstant => {
-                Definition::new(0.00000000000000000000000000000000066260755, "J.s", None)
+    pub(crate) fn definition(self) -> &'static Definition {
+        if let Some(definition) = ConstantProfile::ACTIVE.definition(self) {
+            return definition;
+        }
+
+        match self {
+            Self::Meter => &definitions::METER,
+            Self::Second => &definitions::SECOND,
+            Self::Gram => &definitions::GRAM,
+            Self::Radian => &definitions::RADIAN,
+            Self::Kelvin => &definitions::KELVIN,
+            Self::Coulomb => &definitions::COULOMB,
+            Self::Candela => &definitions::CANDELA,
+            Self::TheNumberTenForArbitraryPowersStar => {
+                &definitions::THE_NUMBER_TEN_FOR_ARBITRARY_POWERS_STAR
             }
-            Self::BoltzmannConstant => {
-                Definition::new(0.00000000000000000000001380658, "J/K", None)
+            Self::TheNumberTenForArbitraryPowersCaret => {
+                &definitions::THE_NUMBER_TEN_FOR_ARBITRARY_POWERS_CARET
             }
-            Self::PermittivityOfVacuum => Definition::new(0.000000000008854187817, "F/m", None),
-            Self::PermeabilityOfVacuum => Definition::new(1.0, "4.[pi].10*-7.N/A2", None),
-            Self::ElementaryCharge => Definition::new(0.000000000000000000160217733, "C", None),
-            Self::ElectronMass => Definition::new(0.00000000000000000000000000091093897, "g", None),
-            Self::ProtonMass => Definition::new(0.0000000000000000000000016726231, "g", None),
-            Self::NewtonianConstantOfGravitation => {
-                Definition::new(0.0000000000667259, "m3.kg-1.s-2", None)
+            Self::TheNumberPi => &definitions::THE_NUMBER_PI,
+            Self::Percent => &definitions::PERCENT,
+            Self::PartsPerThousand => &definitions::PARTS_PER_THOUSAND,
+            Self::PartsPerMillion => &definitions::PARTS_PER_MILLION,
+            Self::PartsPerBillion => &definitions::PARTS_PER_BILLION,
+            Self::PartsPerTrillion => &definitions::PARTS_PER_TRILLION,
+            Self::Mole => &definitions::MOLE,
+            Self::Steradian => &definitions::STERADIAN,
+            Self::Hertz => &definitions::HERTZ,
+            Self::Newton => &definitions::NEWTON,
+            Self::Pascal => &definitions::PASCAL,
+            Self::Joule => &definitions::JOULE,
+            Self::Watt => &definitions::WATT,
+            Self::Ampere => &definitions::AMPERE,
+            Self::Volt => &definitions::VOLT,
+            Self::Farad => &definitions::FARAD,
+            Self::Ohm => &definitions::OHM,
+            Self::Siemens => &definitions::SIEMENS,
+            Self::Weber => &definitions::WEBER,
+            Self::DegreeCelsius => &definitions::DEGREE_CELSIUS,
+            Self::Tesla => &definitions::TESLA,
+            Self::Henry => &definitions::HENRY,
+            Self::Lumen => &definitions::LUMEN,
+            Self::Lux => &definitions::LUX,
+            Self::Becquerel => &definitions::BECQUEREL,
+            Self::Gray => &definitions::GRAY,
+            Self::Sievert => &definitions::SIEVERT,
+            Self::Gon => &definitions::GON,
+            Self::Degree => &definitions::DEGREE,
+            Self::MinuteAngle => &definitions::MINUTE_ANGLE,
+            Self::SecondAngle => &definitions::SECOND_ANGLE,
+            Self::Liter => &definitions::LITER,
+            Self::LiterSecondary => &definitions::LITER_SECONDARY,
+            Self::Are => &definitions::ARE,
+            Self::Minute => &definitions::MINUTE,
+            Self::Hour => &definitions::HOUR,
+            Self::Day => &definitions::DAY,
+            Self::TropicalYear => &definitions::TROPICAL_YEAR,
+            Self::MeanJulianYear => &definitions::MEAN_JULIAN_YEAR,
+            Self::MeanGregorianYear => &definitions::MEAN_GREGORIAN_YEAR,
+            Self::Year => &definitions::YEAR,
+            Self::Week => &definitions::WEEK,
+            Self::SynodalMonth => &definitions::SYNODAL_MONTH,
+            Self::MeanJulianMonth => &definitions::MEAN_JULIAN_MONTH,
+            Self::MeanGregorianMonth => &definitions::MEAN_GREGORIAN_MONTH,
+            Self::Month => &definitions::MONTH,
+            Self::Tonne => &definitions::TONNE,
+            Self::Bar => &definitions::BAR,
+            Self::UnifiedAtomicMassUnit => &definitions::UNIFIED_ATOMIC_MASS_UNIT,
+            Self::Electronvolt => &definitions::ELECTRONVOLT,
+            Self::AstronomicUnit => &definitions::ASTRONOMIC_UNIT,
+            Self::Parsec => &definitions::PARSEC,
+            Self::VelocityOfLight => &definitions::VELOCITY_OF_LIGHT,
+            Self::PlanckConstant => &definitions::PLANCK_CONSTANT,
+            Self::BoltzmannConstant => &definitions::BOLTZMANN_CONSTANT,
+            Self::PermittivityOfVacuum => &definitions::PERMITTIVITY_OF_VACUUM,
+            Self::PermeabilityOfVacuum => &definitions::PERMEABILITY_OF_VACUUM,
+            Self::ElementaryCharge => &definitions::ELEMENTARY_CHARGE,
+            Self::ElectronMass => &definitions::ELECTRON_MASS,
+            Self::ProtonMass => &definitions::PROTON_MASS,
+            Self::NewtonianConstantOfGravitation => &definitions::NEWTONIAN_CONSTANT_OF_GRAVITATION,
+            Self::StandardAccelerationOfFreeFall => {
+                &definitions::STANDARD_ACCELERATION_OF_FREE_FALL
             }
-            Self::StandardAccelerationOfFreeFall => Definition::new(9.80665, "m/s2", None),
-            Self::StandardAtmosphere => Definition::new(101325.0, "Pa", None),
-            Self::LightYear => Definition::new(1.0, "[c].a_j", None),
-            Self::GramForce => Definition::new(1.0, "g.[g]", None),
-            Self::PoundForceAvoirdupois => Definition::new(1.0, "[lb_av].[g]", None),
-            Self::Kayser => Definition::new(1.0, "cm-1", None),
-            Self::Gal => Definition::new(1.0, "cm/s2", None),
-            Self::Dyne => Definition::new(1.0, "g.cm/s2", None),
-            Self::Erg => Definition::new(1.0, "dyn.cm", None),
-            Self::Poise => Definition::new(1.0, "dyn.s/cm2", None),
-            Self::Biot => Definition::new(10.0, "A", None),
-            Self::Stokes => Definition::new(1.0, "cm2/s", None),
-            Self::Maxwell => Definition::new(0.00000001, "Wb", None),
-            Self::Gauss => Definition::new(0.0001, "T", None),
-            Self::Oersted => Definition::new(250.0, "/[pi].A/m", None),
-            Self::Gilbert => Definition::new(1.0, "Oe.cm", None),
-            Self::Stilb => Definition::new(1.0, "cd/cm2", None),
-            Self::Lambert => Definition::new(1.0, "cd/cm2/[pi]", None),
-            Self::Phot => Definition::new(0.0001, "lx", None),
-            Self::Curie => Definition::new(37000000000.0, "Bq", None),
-            Self::Roentgen => Definition::new(0.000258, "C/kg", None),
-            Self::RadiationAbsorbedDose => Definition::new(100.0, "erg/g", None),
-            Self::RadiationEquivalentMan => Definition::new(1.0, "RAD", None),
-            Self::InchInternational => Definition::new(2.54, "cm", None),
-            Self::FootInternational => Definition::new(12.0, "[in_i]", None),
-            Self::YardInternational => Definition::new(3.0, "[ft_i]", None),
-            Self::MileInternational => Definition::new(5280.0, "[ft_i]", None),
-            Self::FathomInternational => Definition::new(6.0, "[ft_i]", None),
-            Self::NauticalMileInternational => Definition::new(1852.0, "m", None),
-            Self::KnotInternational => Definition::new(1.0, "[nmi_i]/h", None),
-            Self::SquareInchInternational => Definition::new(1.0, "[in_i]2", None),
-            Self::SquareFootInternational => Definition::new(1.0, "[ft_i]2", None),
-            Self::SquareYardInternational => Definition::new(1.0, "[yd_i]2", None),
-            Self::CubicInchInternational => Definition::new(1.0, "[in_i]3", None),
-            Self::CubicFootInternational => Definition::new(1.0, "[ft_i]3", None),
-            Self::CubicYardInternational => Definition::new(1.0, "[yd_i]3", None),
-            Self::BoardFootInternational => Definition::new(144.0, "[in_i]3", None),
-            Self::CordInternational => Definition::new(128.0, "[ft_i]3", None),
-            Self::MilInternational => Definition::new(0.001, "[in_i]", None),
-            Self::CircularMilInternational => Definition::new(1.0, "[pi]/4.[mil_i]2", None),
-            Self::HandInternational => Definition::new(4.0, "[in_i]", None),
-            Self::FootUS => Definition::new(1200.0, "m/3937", None),
-            Self::YardUS => Definition::new(3.0, "[ft_us]", None),
-            Self::InchUS => Definition::new(1.0, "[ft_us]/12", None),
-            Self::RodUS => Definition::new(16.5, "[ft_us]", None),
-            Self::GuntersChainUS => Definition::new(4.0, "[rd_us]", None),
-            Self::LinkForGuntersChainUS => Definition::new(1.0, "[ch_us]/100", None),
-            Self::RamdensChainUS => Definition::new(100.0, "[ft_us]", None),
-            Self::LinkForRamdensChainUS => Definition::new(1.0, "[rch_us]/100", None),
-            Self::FathomUS => Definition::new(6.0, "[ft_us]", None),
-            Self::FurlongUS => Definition::new(40.0, "[rd_us]", None),
-            Self::MileUS => Definition::new(8.0, "[fur_us]", None),
-            Self::AcreUS => Definition::new(160.0, "[rd_us]2", None),
-            Self::SquareRodUS => Definition::new(1.0, "[rd_us]2", None),
-            Self::SquareMileUS => Definition::new(1.0, "[mi_us]2", None),
-            Self::Section => Definition::new(1.0, "[mi_us]2", None),
-            Self::Township => Definition::new(36.0, "[sct]", None),
-            Self::MilUS => Definition::new(0.001, "[in_us]", None),
-            Self::InchBritish => Definition::new(2.539998, "cm", None),
-            Self::FootBritish => Definition::new(12.0, "[in_br]", None),
-            Self::RodBritish => Definition::new(16.5, "[ft_br]", None),
-            Self::GuntersChainBritish => Definition::new(4.0, "[rd_br]", None),
-            Self::LinkForGuntersChainBritish => Definition::new(1.0, "[ch_br]/100", None),
-            Self::FathomBritish => Definition::new(6.0, "[ft_br]", None),
-            Self::PaceBritish => Definition::new(2.5, "[ft_br]", None),
-            Self::YardBritish => Definition::new(3.0, "[ft_br]", None),
-            Self::MileBritish => Definition::new(5280.0, "[ft_br]", None),
-            Self::NauticalMileBritish => Definition::new(6080.0, "[ft_br]", None),
-            Self::KnotBritish => Definition::new(1.0, "[nmi_br]/h", None),
-            Self::AcreBritish => Definition::new(4840.0, "[yd_br]2", None),
-            Self::QueenAnnesWineGallonUS => Definition::new(231.0, "[in_i]3", None),
-            Self::BarrelUS => Definition::new(42.0, "[gal_us]", None),
-            Self::QuartUS => Definition::new(1.0, "[gal_us]/4", None),
-            Self::PintUS => Definition::new(1.0, "[qt_us]/2", None),
-            Self::GillUS => Definition::new(1.0, "[pt_us]/4", None),
-            Self::FluidOunceUS => Definition::new(1.0, "[gil_us]/4", None),
-            Self::FluidDramUS => Definition::new(1.0, "[foz_us]/8", None),
-            Self::MinimUS => Definition::new(1.0, "[fdr_us]/60", None),
-            Self::CordUS => Definition::new(128.0, "[ft_i]3", None),
-            Self::BushelUS => Definition::new(2150.42, "[in_i]3", None),
-            Self::HistoricalWinchesterGallon => Definition::new(1.0, "[bu_us]/8", None),
-            Self::PeckUS => Definition::new(1.0, "[bu_us]/4", None),
-            Self::DryQuartUS => Definition::new(1.0, "[pk_us]/8", None),
-            Self::DryPintUS => Definition::new(1.0, "[dqt_us]/2", None),
-            Self::TablespoonUS => Definition::new(1.0, "[foz_us]/2", None),
-            Self::TeaspoonUS => Definition::new(1.0, "[tbs_us]/3", None),
-            Self::CupUS => Definition::new(16.0, "[tbs_us]", None),
-            Self::MetricFluidOunce => Definition::new(30.0, "mL", None),
-            Self::MetricCup => Definition::new(240.0, "mL", None),
-            Self::MetricTeaspoon => Definition::new(5.0, "mL", None),
-            Self::MetricTablespoon => Definition::new(15.0, "mL", None),
-            Self::GallonBritish => Definition::new(4.54609, "l", None),
-            Self::PeckBritish => Definition::new(2.0, "[gal_br]", None),
-            Self::BushelBritish => Definition::new(4.0, "[pk_br]", None),
-            Self::QuartBritish => Definition::new(1.0, "[gal_br]/4", None),
-            Self::PintBritish => Definition::new(1.0, "[qt_br]/2", None),
-            Self::GillBritish => Definition::new(1.0, "[pt_br]/4", None),
-            Self::FluidOunceBritish => Definition::new(1.0, "[gil_br]/5", None),
-            Self::FluidDramBritish => Definition::new(1.0, "[foz_br]/8", None),
-            Self::MinimBritish => Definition::new(1.0, "[fdr_br]/60", None),
-            Self::Grain => Definition::new(64.79891, "mg", None),
-            Self::PoundAvoirdupois => Definition::new(7000.0, "[gr]", None),
-            Self::OunceAvoirdupois => Definition::new(1.0, "[lb_av]/16", None),
-            Self::DramAvoirdupois => Definition::new(1.0, "[oz_av]/16", None),
-            Self::ShortHundredweightAvoirdupois => Definition::new(100.0, "[lb_av]", None),
-            Self::LongHunderdweightAvoirdupois => Definition::new(112.0, "[lb_av]", None),
-            Self::ShortTonAvoirdupois => Definition::new(20.0, "[scwt_av]", None),
-            Self::LongTonAvoirdupois => Definition::new(20.0, "[lcwt_av]", None),
-            Self::StoneAvoirdupois => Definition::new(14.0, "[lb_av]", None),
-            Self::PennyweightTroy => Definition::new(24.0, "[gr]", None),
-            Self::OunceTroy => Definition::new(20.0, "[pwt_tr]", None),
-            Self::PoundTroy => Definition::new(12.0, "[oz_tr]", None),
-            Self::ScrupleApothecaries => Definition::new(20.0, "[gr]", None),
-            Self::DramApothecaries => Definition::new(3.0, "[sc_ap]", None),
-            Self::OunceApothecaries => Definition::new(8.0, "[dr_ap]", None),
-            Self::PoundApothecaries => Definition::new(12.0, "[oz_ap]", None),
-            Self::MetricOunce => Definition::new(28.0, "g", None),
-            Self::Line => Definition::new(1.0, "[in_i]/12", None),
-            Self::Point => Definition::new(1.0, "[lne]/6", None),
-            Self::Pica => Definition::new(12.0, "[pnt]", None),
-            Self::PrintersPoint => Definition::new(0.013837, "[in_i]", None),
-            Self::PrintersPica => Definition::new(12.0, "[pnt_pr]", None),
-            Self::Pied => Definition::new(32.48, "cm", None),
-            Self::Pouce => Definition::new(1.0, "[pied]/12", None),
-            Self::Ligne => Definition::new(1.0, "[pouce]/12", None),
-            Self::Didot => Definition::new(1.0, "[ligne]/6", None),
-            Self::Cicero => Definition::new(12.0, "[didot]", None),
-            Self::DegreeFahrenheit => Definition::new(
-                5.0,
-                "K/9",
-                Some(FunctionSet {
-                    convert_from: |value: f64| 9.0 * value / 5.0 - 459.67,
-                    convert_to: |value: f64| 5.0 / 9.0 * (value + 459.67),
-                }),
-            ),
-            Self::DegreeRankine => Definition::new(5.0, "K/9", None),
-            Self::DegreeReaumur => Definition::new(
-                5.0,
-                "K/4",
-                Some(FunctionSet {
-                    convert_from: |value: f64| (value - 273.15) * 0.8,
-                    convert_to: |value: f64| (value / 0.8) + 273.15,
-                }),
-            ),
-            Self::CalorieAt15C => Definition::new(4.1858, "J", None),
-            Self::CalorieAt20C => Definition::new(4.1819, "J", None),
-            Self::MeanCalorie => Definition::new(4.19002, "J", None),
-            Self::InternationalTableCalorie => Definition::new(4.1868, "J", None),
-            Self::ThermochemicalCalorie => Definition::new(4.184, "J", None),
-            Self::Calorie => Definition::new(1.0, "cal_th", None),
-            Self::NutritionLabelCalories => Definition::new(1.0, "kcal_th", None),
-            Self::BritishThermalUnitAt39F => Definition::new(1.05967, "kJ", None),
-            Self::BritishThermalUnitAt59F => Definition::new(1.0548, "kJ", None),
-            Self::BritishThermalUnitAt60F => Definition::new(1.05468, "kJ", None),
-            Self::MeanBritishThermalUnit => Definition::new(1.05587, "kJ", None),
+            Self::StandardAtmosphere => &definitions::STANDARD_ATMOSPHERE,
+            Self::LightYear => &definitions::LIGHT_YEAR,
+            Self::GramForce => &definitions::GRAM_FORCE,
+            Self::PoundForceAvoirdupois => &definitions::POUND_FORCE_AVOIRDUPOIS,
+            Self::Kayser => &definitions::KAYSER,
+            Self::Gal => &definitions::GAL,
+            Self::Dyne => &definitions::DYNE,
+            Self::Erg => &definitions::ERG,
+            Self::Poise => &definitions::POISE,
+            Self::Biot => &definitions::BIOT,
+            Self::Stokes => &definitions::STOKES,
+            Self::Maxwell => &definitions::MAXWELL,
+            Self::Gauss => &definitions::GAUSS,
+            Self::Oersted => &definitions::OERSTED,
+            Self::Gilbert => &definitions::GILBERT,
+            Self::Stilb => &definitions::STILB,
+            Self::Lambert => &definitions::LAMBERT,
+            Self::Phot => &definitions::PHOT,
+            Self::Curie => &definitions::CURIE,
+            Self::Roentgen => &definitions::ROENTGEN,
+            Self::RadiationAbsorbedDose => &definitions::RADIATION_ABSORBED_DOSE,
+            Self::RadiationEquivalentMan => &definitions::RADIATION_EQUIVALENT_MAN,
+            Self::InchInternational => &definitions::INCH_INTERNATIONAL,
+            Self::FootInternational => &definitions::FOOT_INTERNATIONAL,
+            Self::YardInternational => &definitions::YARD_INTERNATIONAL,
+            Self::MileInternational => &definitions::MILE_INTERNATIONAL,
+            Self::FathomInternational => &definitions::FATHOM_INTERNATIONAL,
+            Self::NauticalMileInternational => &definitions::NAUTICAL_MILE_INTERNATIONAL,
+            Self::KnotInternational => &definitions::KNOT_INTERNATIONAL,
+            Self::SquareInchInternational => &definitions::SQUARE_INCH_INTERNATIONAL,
+            Self::SquareFootInternational => &definitions::SQUARE_FOOT_INTERNATIONAL,
+            Self::SquareYardInternational => &definitions::SQUARE_YARD_INTERNATIONAL,
+            Self::CubicInchInternational => &definitions::CUBIC_INCH_INTERNATIONAL,
+            Self::CubicFootInternational => &definitions::CUBIC_FOOT_INTERNATIONAL,
+            Self::CubicYardInternational => &definitions::CUBIC_YARD_INTERNATIONAL,
+            Self::BoardFootInternational => &definitions::BOARD_FOOT_INTERNATIONAL,
+            Self::CordInternational => &definitions::CORD_INTERNATIONAL,
+            Self::MilInternational => &definitions::MIL_INTERNATIONAL,
+            Self::CircularMilInternational => &definitions::CIRCULAR_MIL_INTERNATIONAL,
+            Self::HandInternational => &definitions::HAND_INTERNATIONAL,
+            Self::FootUS => &definitions::FOOT_US,
+            Self::YardUS => &definitions::YARD_US,
+            Self::InchUS => &definitions::INCH_US,
+            Self::RodUS => &definitions::ROD_US,
+            Self::GuntersChainUS => &definitions::GUNTERS_CHAIN_US,
+            Self::LinkForGuntersChainUS => &definitions::LINK_FOR_GUNTERS_CHAIN_US,
+            Self::RamdensChainUS => &definitions::RAMDENS_CHAIN_US,
+            Self::LinkForRamdensChainUS => &definitions::LINK_FOR_RAMDENS_CHAIN_US,
+            Self::FathomUS => &definitions::FATHOM_US,
+            Self::FurlongUS => &definitions::FURLONG_US,
+            Self::MileUS => &definitions::MILE_US,
+            Self::AcreUS => &definitions::ACRE_US,
+            Self::SquareRodUS => &definitions::SQUARE_ROD_US,
+            Self::SquareMileUS => &definitions::SQUARE_MILE_US,
+            Self::Section => &definitions::SECTION,
+            Self::Township => &definitions::TOWNSHIP,
+            Self::MilUS => &definitions::MIL_US,
+            Self::InchBritish => &definitions::INCH_BRITISH,
+            Self::FootBritish => &definitions::FOOT_BRITISH,
+            Self::RodBritish => &definitions::ROD_BRITISH,
+            Self::GuntersChainBritish => &definitions::GUNTERS_CHAIN_BRITISH,
+            Self::LinkForGuntersChainBritish => &definitions::LINK_FOR_GUNTERS_CHAIN_BRITISH,
+            Self::FathomBritish => &definitions::FATHOM_BRITISH,
+            Self::PaceBritish => &definitions::PACE_BRITISH,
+            Self::YardBritish => &definitions::YARD_BRITISH,
+            Self::MileBritish => &definitions::MILE_BRITISH,
+            Self::NauticalMileBritish => &definitions::NAUTICAL_MILE_BRITISH,
+            Self::KnotBritish => &definitions::KNOT_BRITISH,
+            Self::AcreBritish => &definitions::ACRE_BRITISH,
+            Self::QueenAnnesWineGallonUS => &definitions::QUEEN_ANNES_WINE_GALLON_US,
+            Self::BarrelUS => &definitions::BARREL_US,
+            Self::QuartUS => &definitions::QUART_US,
+            Self::PintUS => &definitions::PINT_US,
+            Self::GillUS => &definitions::GILL_US,
+            Self::FluidOunceUS => &definitions::FLUID_OUNCE_US,
+            Self::FluidDramUS => &definitions::FLUID_DRAM_US,
+            Self::MinimUS => &definitions::MINIM_US,
+            Self::CordUS => &definitions::CORD_US,
+            Self::BushelUS => &definitions::BUSHEL_US,
+            Self::HistoricalWinchesterGallon => &definitions::HISTORICAL_WINCHESTER_GALLON,
+            Self::PeckUS => &definitions::PECK_US,
+            Self::DryQuartUS => &definitions::DRY_QUART_US,
+            Self::DryPintUS => &definitions::DRY_PINT_US,
+            Self::TablespoonUS => &definitions::TABLESPOON_US,
+            Self::TeaspoonUS => &definitions::TEASPOON_US,
+            Self::CupUS => &definitions::CUP_US,
+            Self::MetricFluidOunce => &definitions::METRIC_FLUID_OUNCE,
+            Self::MetricCup => &definitions::METRIC_CUP,
+            Self::MetricTeaspoon => &definitions::METRIC_TEASPOON,
+            Self::MetricTablespoon => &definitions::METRIC_TABLESPOON,
+            Self::GallonBritish => &definitions::GALLON_BRITISH,
+            Self::PeckBritish => &definitions::PECK_BRITISH,
+            Self::BushelBritish => &definitions::BUSHEL_BRITISH,
+            Self::QuartBritish => &definitions::QUART_BRITISH,
+            Self::PintBritish => &definitions::PINT_BRITISH,
+            Self::GillBritish => &definitions::GILL_BRITISH,
+            Self::FluidOunceBritish => &definitions::FLUID_OUNCE_BRITISH,
+            Self::FluidDramBritish => &definitions::FLUID_DRAM_BRITISH,
+            Self::MinimBritish => &definitions::MINIM_BRITISH,
+            Self::Grain => &definitions::GRAIN,
+            Self::PoundAvoirdupois => &definitions::POUND_AVOIRDUPOIS,
+            Self::OunceAvoirdupois => &definitions::OUNCE_AVOIRDUPOIS,
+            Self::DramAvoirdupois => &definitions::DRAM_AVOIRDUPOIS,
+            Self::ShortHundredweightAvoirdupois => &definitions::SHORT_HUNDREDWEIGHT_AVOIRDUPOIS,
+            Self::LongHunderdweightAvoirdupois => &definitions::LONG_HUNDERDWEIGHT_AVOIRDUPOIS,
+            Self::ShortTonAvoirdupois => &definitions::SHORT_TON_AVOIRDUPOIS,
+            Self::LongTonAvoirdupois => &definitions::LONG_TON_AVOIRDUPOIS,
+            Self::StoneAvoirdupois => &definitions::STONE_AVOIRDUPOIS,
+            Self::PennyweightTroy => &definitions::PENNYWEIGHT_TROY,
+            Self::OunceTroy => &definitions::OUNCE_TROY,
+            Self::PoundTroy => &definitions::POUND_TROY,
+            Self::ScrupleApothecaries => &definitions::SCRUPLE_APOTHECARIES,
+            Self::DramApothecaries => &definitions::DRAM_APOTHECARIES,
+            Self::OunceApothecaries => &definitions::OUNCE_APOTHECARIES,
+            Self::PoundApothecaries => &definitions::POUND_APOTHECARIES,
+            Self::MetricOunce => &definitions::METRIC_OUNCE,
+            Self::Line => &definitions::LINE,
+            Self::Point => &definitions::POINT,
+            Self::Pica => &definitions::PICA,
+            Self::PrintersPoint => &definitions::PRINTERS_POINT,
+            Self::PrintersPica => &definitions::PRINTERS_PICA,
+            Self::Pied => &definitions::PIED,
+            Self::Pouce => &definitions::POUCE,
+            Self::Ligne => &definitions::LIGNE,
+            Self::Didot => &definitions::DIDOT,
+            Self::Cicero => &definitions::CICERO,
+            Self::DegreeFahrenheit => &definitions::DEGREE_FAHRENHEIT,
+            Self::DegreeRankine => &definitions::DEGREE_RANKINE,
+            Self::DegreeReaumur => &definitions::DEGREE_REAUMUR,
+            Self::CalorieAt15C => &definitions::CALORIE_AT15_C,
+            Self::CalorieAt20C => &definitions::CALORIE_AT20_C,
+            Self::MeanCalorie => &definitions::MEAN_CALORIE,
+            Self::InternationalTableCalorie => &definitions::INTERNATIONAL_TABLE_CALORIE,
+            Self::ThermochemicalCalorie => &definitions::THERMOCHEMICAL_CALORIE,
+            Self::Calorie => &definitions::CALORIE,
+            Self::NutritionLabelCalories => &definitions::NUTRITION_LABEL_CALORIES,
+            Self::BritishThermalUnitAt39F => &definitions::BRITISH_THERMAL_UNIT_AT39_F,
+            Self::BritishThermalUnitAt59F => &definitions::BRITISH_THERMAL_UNIT_AT59_F,
+            Self::BritishThermalUnitAt60F => &definitions::BRITISH_THERMAL_UNIT_AT60_F,
+            Self::MeanBritishThermalUnit => &definitions::MEAN_BRITISH_THERMAL_UNIT,
             Self::InternationalTableBritishThermalUnit => {
-                Definition::new(1.05505585262, "kJ", None)
+                &definitions::INTERNATIONAL_TABLE_BRITISH_THERMAL_UNIT
+            }
+            Self::ThermochemicalBritishThermalUnit => {
+                &definitions::THERMOCHEMICAL_BRITISH_THERMAL_UNIT
+            }
+            Self::BritishThermalUnit => &definitions::BRITISH_THERMAL_UNIT,
+            Self::Horsepower => &definitions::HORSEPOWER,
+            Self::Tex => &definitions::TEX,
+            Self::Denier => &definitions::DENIER,
+            Self::MeterOfWaterColumn => &definitions::METER_OF_WATER_COLUMN,
+            Self::MeterOfMercuryColumn => &definitions::METER_OF_MERCURY_COLUMN,
+            Self::InchOfWaterColumn => &definitions::INCH_OF_WATER_COLUMN,
+            Self::InchOfMercuryColumn => &definitions::INCH_OF_MERCURY_COLUMN,
+            Self::PeripheralVascularResistanceUnit => {
+                &definitions::PERIPHERAL_VASCULAR_RESISTANCE_UNIT
+            }
+            Self::WoodUnit => &definitions::WOOD_UNIT,
+            Self::Diopter => &definitions::DIOPTER,
+            Self::PrismDiopter => &definitions::PRISM_DIOPTER,
+            Self::PercentOfSlope => &definitions::PERCENT_OF_SLOPE,
+            Self::MeshInternational => &definitions::MESH_INTERNATIONAL,
+            Self::Charriere => &definitions::CHARRIERE,
+            Self::Drop => &definitions::DROP,
+            Self::HounsfieldUnit => &definitions::HOUNSFIELD_UNIT,
+            Self::MetabolicEquivalent => &definitions::METABOLIC_EQUIVALENT,
+            Self::HomeopathicPotencyOfDecimalSeriesRetired => {
+                &definitions::HOMEOPATHIC_POTENCY_OF_DECIMAL_SERIES_RETIRED
+            }
+            Self::HomeopathicPotencyOfCentesimalSeriesRetired => {
+                &definitions::HOMEOPATHIC_POTENCY_OF_CENTESIMAL_SERIES_RETIRED
+            }
+            Self::HomeopathicPotencyOfMillesimalSeriesRetired => {
+                &definitions::HOMEOPATHIC_POTENCY_OF_MILLESIMAL_SERIES_RETIRED
+            }
+            Self::HomeopathicPotencyOfQuintamillesimalSeriesRetired => {
+                &definitions::HOMEOPATHIC_POTENCY_OF_QUINTAMILLESIMAL_SERIES_RETIRED
+            }
+            Self::HomeopathicPotencyOfDecimalHahnemannianSeries => {
+                &definitions::HOMEOPATHIC_POTENCY_OF_DECIMAL_HAHNEMANNIAN_SERIES
+            }
+            Self::HomeopathicPotencyOfCentesimalHahnemannianSeries => {
+                &definitions::HOMEOPATHIC_POTENCY_OF_CENTESIMAL_HAHNEMANNIAN_SERIES
+            }
+            Self::HomeopathicPotencyOfMillesimalHahnemannianSeries => {
+                &definitions::HOMEOPATHIC_POTENCY_OF_MILLESIMAL_HAHNEMANNIAN_SERIES
             }
-            Self::ThermochemicalBritishThermalUnit => Definition::new(1.05435, "kJ", None),
-            Self::BritishThermalUnit => Definition::new(1.0, "[Btu_th]", None),
-            Self::Horsepower => Definition::new(550.0, "[ft_i].[lbf_av]/s", None),
-            Self::Tex => Definition::new(1.0, "g/km", None),
-            Self::Denier => Definition::new(1.0, "g/9/km", None),
-            Self::MeterOfWaterColumn => Definition::new(9.80665, "kPa", None),
-            Self::MeterOfMercuryColumn => Definition::new(133.322, "kPa", None),
-            Self::InchOfWaterColumn => Definition::new(1.0, "m[H2O].[in_i]/m", None),
-            Self::InchOfMercuryColumn => Definition::new(1.0, "m[Hg].[in_i]/m", None),
-            Self::PeripheralVascularResistanceUnit => Definition::new(1.0, "mm[Hg].s/ml", None),
-            Self::WoodUnit => Definition::new(1.0, "mm[Hg].min/L", None),
-            Self::Diopter => Definition::new(1.0, "/m", None),
-            Self::PrismDiopter => Definition::new(
-                1.0,
-                "rad",
-                Some(FunctionSet {
-                    convert_from: |value: f64| (value / 100.0).atan(),
-                    convert_to: |value: f64| value.tan() * 100.0,
-                }),
-            ),
-            Self::PercentOfSlope => Definition::new(
-                1.0,
-                "deg",
-                Some(FunctionSet {
-                    convert_from: |value: f64| (value / 100.0).atan(),
-                    convert_to: |value: f64| value.tan() * 100.0,
-                }),
-            ),
-            Self::MeshInternational => Definition::new(1.0, "/[in_i]", None),
-            Self::Charriere => Definition::new(1.0, "mm/3", None),
-            Self::Drop => Definition::new(1.0, "ml/20", None),
-            Self::HounsfieldUnit => Ok(Definition::default()),
-            Self::MetabolicEquivalent => Definition::new(3.5, "mL/min/kg", None),
-            Self::HomeopathicPotencyOfDecimalSeriesRetired => Definition::new(
-                1.0,
-                "1",
-                Some(FunctionSet {
-                    convert_from: |value: f64| -value.log10(),
-                    convert_to: |value: f64| 10_f64.powf(-value),
-                }),
-            ),
-            Self::HomeopathicPotencyOfCentesimalSeriesRetired => Definition::new(
-                1.0,
-                "1",
-                Some(FunctionSet {
-                    convert_from: |value: f64| -value.ln() / 100_f64.ln(),
-                    convert_to: |value: f64| 100_f64.powf(-value),
-                }),
-            ),
-            Self::HomeopathicPotencyOfMillesimalSeriesRetired => Definition::new(
-                1.0,
-                "1",
-                Some(FunctionSet {
-                    convert_from: |value: f64| -value.ln() / 1_000_f64.ln(),
-                    convert_to: |value: f64| 1_000_f64.powf(-value),
-                }),
-            ),
-            Self::HomeopathicPotencyOfQuintamillesimalSeriesRetired => Definition::new(
-                1.0,
-                "1",
-                Some(FunctionSet {
-                    convert_from: |value: f64| -value.ln() / 50_000_f64.ln(),
-                    convert_to: |value: f64| 50_000_f64.powf(-value),
-                }),
-            ),
-            Self::HomeopathicPotencyOfDecimalHahnemannianSeries => Ok(Definition::default()),
-            Self::HomeopathicPotencyOfCentesimalHahnemannianSeries => Ok(Definition::default()),
-            Self::HomeopathicPotencyOfMillesimalHahnemannianSeries => Ok(Definition::default()),
             Self::HomeopathicPotencyOfQuintamillesimalHahnemannianSeries => {
-                Ok(Definition::default())
+                &definitions::HOMEOPATHIC_POTENCY_OF_QUINTAMILLESIMAL_HAHNEMANNIAN_SERIES
+            }
+            Self::HomeopathicPotencyOfDecimalKorsakovianSeries => {
+                &definitions::HOMEOPATHIC_POTENCY_OF_DECIMAL_KORSAKOVIAN_SERIES
+            }
+            Self::HomeopathicPotencyOfCentesimalKorsakovianSeries => {
+                &definitions::HOMEOPATHIC_POTENCY_OF_CENTESIMAL_KORSAKOVIAN_SERIES
+            }
+            Self::HomeopathicPotencyOfMillesimalKorsakovianSeries => {
+                &definitions::HOMEOPATHIC_POTENCY_OF_MILLESIMAL_KORSAKOVIAN_SERIES
             }
-            Self::HomeopathicPotencyOfDecimalKorsakovianSeries => Ok(Definition::default()),
-            Self::HomeopathicPotencyOfCentesimalKorsakovianSeries => Ok(Definition::default()),
-            Self::HomeopathicPotencyOfMillesimalKorsakovianSeries => Ok(Definition::default()),
             Self::HomeopathicPotencyOfQuintamillesimalKorsakovianSeries => {
-                Ok(Definition::default())
+                &definitions::HOMEOPATHIC_POTENCY_OF_QUINTAMILLESIMAL_KORSAKOVIAN_SERIES
+            }
+            Self::Equivalents => &definitions::EQUIVALENTS,
+            Self::Osmole => &definitions::OSMOLE,
+            Self::PH => &definitions::PH,
+            Self::GramPercent => &definitions::GRAM_PERCENT,
+            Self::SvedbergUnit => &definitions::SVEDBERG_UNIT,
+            Self::HighPowerField => &definitions::HIGH_POWER_FIELD,
+            Self::LowPowerField => &definitions::LOW_POWER_FIELD,
+            Self::Katal => &definitions::KATAL,
+            Self::Unit => &definitions::UNIT,
+            Self::InternationalUnit => &definitions::INTERNATIONAL_UNIT,
+            Self::InternationalUnitSecondary => &definitions::INTERNATIONAL_UNIT_SECONDARY,
+            Self::ArbitraryUnit => &definitions::ARBITRARY_UNIT,
+            Self::UnitedStatesPharmacopeiaUnit => &definitions::UNITED_STATES_PHARMACOPEIA_UNIT,
+            Self::GplUnit => &definitions::GPL_UNIT,
+            Self::MplUnit => &definitions::MPL_UNIT,
+            Self::AplUnit => &definitions::APL_UNIT,
+            Self::BethesdaUnit => &definitions::BETHESDA_UNIT,
+            Self::AntiFactorXaUnit => &definitions::ANTI_FACTOR_XA_UNIT,
+            Self::ToddUnit => &definitions::TODD_UNIT,
+            Self::DyeUnit => &definitions::DYE_UNIT,
+            Self::SomogyiUnit => &definitions::SOMOGYI_UNIT,
+            Self::BodanskyUnit => &definitions::BODANSKY_UNIT,
+            Self::KingArmstrongUnit => &definitions::KING_ARMSTRONG_UNIT,
+            Self::KunkelUnit => &definitions::KUNKEL_UNIT,
+            Self::MacLaganUnit => &definitions::MAC_LAGAN_UNIT,
+            Self::TuberculinUnit => &definitions::TUBERCULIN_UNIT,
+            Self::CellCultureInfectiousDose => &definitions::CELL_CULTURE_INFECTIOUS_DOSE,
+            Self::TissueCultureInfectiousDose => &definitions::TISSUE_CULTURE_INFECTIOUS_DOSE,
+            Self::EmbryoInfectiousDose => &definitions::EMBRYO_INFECTIOUS_DOSE,
+            Self::PlaqueFormingUnits => &definitions::PLAQUE_FORMING_UNITS,
+            Self::FocusFormingUnits => &definitions::FOCUS_FORMING_UNITS,
+            Self::ColonyFormingUnits => &definitions::COLONY_FORMING_UNITS,
+            Self::IndexOfReactivity => &definitions::INDEX_OF_REACTIVITY,
+            Self::BioequivalentAllergenUnit => &definitions::BIOEQUIVALENT_ALLERGEN_UNIT,
+            Self::AllergenUnit => &definitions::ALLERGEN_UNIT,
+            Self::AllergenUnitForAmbrosiaArtemisiifolia => {
+                &definitions::ALLERGEN_UNIT_FOR_AMBROSIA_ARTEMISIIFOLIA
+            }
+            Self::ProteinNitrogenUnit => &definitions::PROTEIN_NITROGEN_UNIT,
+            Self::LimitOfFlocculation => &definitions::LIMIT_OF_FLOCCULATION,
+            Self::DAntigenUnit => &definitions::D_ANTIGEN_UNIT,
+            Self::FibrinogenEquivalentUnit => &definitions::FIBRINOGEN_EQUIVALENT_UNIT,
+            Self::ElisaUnit => &definitions::ELISA_UNIT,
+            Self::EhrlichUnit => &definitions::EHRLICH_UNIT,
+            Self::Neper => &definitions::NEPER,
+            Self::Bel => &definitions::BEL,
+            Self::BelSoundPressure => &definitions::BEL_SOUND_PRESSURE,
+            Self::BelVolt => &definitions::BEL_VOLT,
+            Self::BelMillivolt => &definitions::BEL_MILLIVOLT,
+            Self::BelMicrovolt => &definitions::BEL_MICROVOLT,
+            Self::Bel10Nanovolt => &definitions::BEL10_NANOVOLT,
+            Self::BelWatt => &definitions::BEL_WATT,
+            Self::BelKilowatt => &definitions::BEL_KILOWATT,
+            Self::Stere => &definitions::STERE,
+            Self::Angstrom => &definitions::ANGSTROM,
+            Self::Barn => &definitions::BARN,
+            Self::TechnicalAtmosphere => &definitions::TECHNICAL_ATMOSPHERE,
+            Self::Mho => &definitions::MHO,
+            Self::PoundPerSqareInch => &definitions::POUND_PER_SQARE_INCH,
+            Self::Circle => &definitions::CIRCLE,
+            Self::Spere => &definitions::SPERE,
+            Self::MetricCarat => &definitions::METRIC_CARAT,
+            Self::CaratOfGoldAlloys => &definitions::CARAT_OF_GOLD_ALLOYS,
+            Self::Smoot => &definitions::SMOOT,
+            Self::MeterPerSquareSecondsPerSquareRootOfHertz => {
+                &definitions::METER_PER_SQUARE_SECONDS_PER_SQUARE_ROOT_OF_HERTZ
             }
-            Self::Equivalents => Definition::new(1.0, "mol", None),
-            Self::Osmole => Definition::new(1.0, "mol", None),
-            Self::PH => Definition::new(
-                1.0,
-                "mol/l",
-                Some(FunctionSet {
-                    convert_from: |value: f64| 10.0_f64.powf(-value),
-                    convert_to: |value: f64| -value.log10(),
-                }),
-            ),
-            Self::GramPercent => Definition::new(1.0, "g/dl", None),
-            Self::SvedbergUnit => Definition::new(1.0, "10*-13.s", None),
-            Self::HighPowerField => Ok(Definition::default()),
-            Self::LowPowerField => Definition::new(100.0, "1", None),
-            Self::Katal => Definition::new(1.0, "mol/s", None),
-            Self::Unit => Definition::new(1.0, "umol/min", None),
-            Self::InternationalUnit => Ok(Definition::default()),
-            Self::InternationalUnitSecondary => Definition::new(1.0, "[iU]", None),
-            Self::ArbitraryUnit => Ok(Definition::default()),
-            Self::UnitedStatesPharmacopeiaUnit => Ok(Definition::default()),
-            Self::GplUnit => Ok(Definition::default()),
-            Self::MplUnit => Ok(Definition::default()),
-            Self::AplUnit => Ok(Definition::default()),
-            Self::BethesdaUnit => Ok(Definition::default()),
-            Self::AntiFactorXaUnit => Ok(Definition::default()),
-            Self::ToddUnit => Ok(Definition::default()),
-            Self::DyeUnit => Ok(Definition::default()),
-            Self::SomogyiUnit => Ok(Definition::default()),
-            Self::BodanskyUnit => Ok(Definition::default()),
-            Self::KingArmstrongUnit => Ok(Definition::default()),
-            Self::KunkelUnit => Ok(Definition::default()),
-            Self::MacLaganUnit => Ok(Definition::default()),
-            Self::TuberculinUnit => Ok(Definition::default()),
-            Self::CellCultureInfectiousDose => Ok(Definition::default()),
-            Self::TissueCultureInfectiousDose => Ok(Definition::default()),
-            Self::EmbryoInfectiousDose => Ok(Definition::default()),
-            Self::PlaqueFormingUnits => Ok(Definition::default()),
-            Self::FocusFormingUnits => Ok(Definition::default()),
-            Self::ColonyFormingUnits => Ok(Definition::default()),
-            Self::IndexOfReactivity => Ok(Definition::default()),
-            Self::BioequivalentAllergenUnit => Ok(Definition::default()),
-            Self::AllergenUnit => Ok(Definition::default()),
-            Self::AllergenUnitForAmbrosiaArtemisiifolia => Ok(Definition::default()),
-            Self::ProteinNitrogenUnit => Ok(Definition::default()),
-            Self::LimitOfFlocculation => Ok(Definition::default()),
-            Self::DAntigenUnit => Ok(Definition::default()),
-            Self::FibrinogenEquivalentUnit => Ok(Definition::default()),
-            Self::ElisaUnit => Ok(Definition::default()),
-            Self::EhrlichUnit => Ok(Definition::default()),
-            Self::Neper => Definition::new(
-                1.0,
-                "1",
-                Some(FunctionSet {
-                    convert_from: |value: f64| value.ln(),
-                    convert_to: |value: f64| value.exp(),
-                }),
-            ),
-            Self::Bel => Definition::new(
-                1.0,
-                "1",
-                Some(FunctionSet {
-                    convert_from: |value: f64| value.log10(),
-                    convert_to: |value: f64| 10_f64.powf(value),
-                }),
-            ),
-            Self::BelSoundPressure => Definition::new(
-                2.0,
-                "10*-5.Pa",
-                Some(FunctionSet {
-                    convert_from: |value: f64| 2.0 * value.log10(),
-                    convert_to: |value: f64| 10_f64.powf(value / 2.0),
-                }),
-            ),
-            Self::BelVolt => Definition::new(
-                1.0,
-                "V",
-                Some(FunctionSet {
-                    convert_from: |value: f64| 2.0 * value.log10(),
-                    convert_to: |value: f64| 10_f64.powf(value / 2.0),
-                }),
-            ),
-            Self::BelMillivolt => Definition::new(
-                1.0,
-                "mV",
-                Some(FunctionSet {
-                    convert_from: |value: f64| 2.0 * value.log10(),
-                    convert_to: |value: f64| 10_f64.powf(value / 2.0),
-                }),
-            ),
-            Self::BelMicrovolt => Definition::new(
-                1.0,
-                "uV",
-                Some(FunctionSet {
-                    convert_from: |value: f64| 2.0 * value.log10(),
-                    convert_to: |value: f64| 10_f64.powf(value / 2.0),
-                }),
-            ),
-            Self::Bel10Nanovolt => Definition::new(
-                10.0,
-                "nV",
-                Some(FunctionSet {
-                    convert_from: |value: f64| 2.0 * value.log10(),
-                    convert_to: |value: f64| 10_f64.powf(value / 2.0),
-                }),
-            ),
-            Self::BelWatt => Definition::new(
-                1.0,
-                "W",
-                Some(FunctionSet {
-                    convert_from: |value: f64| value.log10(),
-                    convert_to: |value: f64| 10_f64.powf(value),
-                }),
-            ),
-            Self::BelKilowatt => Definition::new(
-                1.0,
-                "kW",
-                Some(FunctionSet {
-                    convert_from: |value: f64| value.log10(),
-                    convert_to: |value: f64| 10_f64.powf(value),
-                }),
-            ),
-            Self::Stere => Definition::new(1.0, "m3", None),
-            Self::Angstrom => Definition::new(0.1, "nm", None),
-            Self::Barn => Definition::new(100.0, "fm2", None),
-            Self::TechnicalAtmosphere => Definition::new(1.0, "kgf/cm2", None),
-            Self::Mho => Definition::new(1.0, "S", None),
-            Self::PoundPerSqareInch => Definition::new(1.0, "[lbf_av]/[in_i]2", None),
-            Self::Circle => Definition::new(2.0, "[pi].rad", None),
-            Self::Spere => Definition::new(4.0, "[pi].sr", None),
-            Self::MetricCarat => Definition::new(0.2, "g", None),
-            Self::CaratOfGoldAlloys => Definition::new(1.0, "/24", None),
-            Self::Smoot => Definition::new(67.0, "[in_i]", None),
-            Self::MeterPerSquareSecondsPerSquareRootOfHertz => Definition::new(
-                1.0,
-                "m2/s4/Hz",
-                Some(FunctionSet {
-                    convert_from: |value: f64| value.sqrt(),
-                    convert_to: |value: f64| value * value,
-                }),
-            ),
-            Self::BitLogarithmusDualis => Definition::new(
-                1.0,
-                "1",
-                Some(FunctionSet {
-                    convert_from: |value: f64| value.log2(),
-                    convert_to: |value: f64| value.exp2(),
-                }),
-            ),
-            Self::Bit => Ok(Definition::default()),
-            Self::Byte => Definition::new(8.0, "bit", None),
-            Self::Baud => Definition::new(1.0, "/s", None),
-        };
-
-        result.expect("BUG! Bad Atom -> Definition mapping!")
+            Self::BitLogarithmusDualis => &definitions::BIT_LOGARITHMUS_DUALIS,
+            Self::Bit => &definitions::BIT,
+            Self::Byte => &definitions::BYTE,
+            Self::Baud => &definitions::BAUD,
+        }
     }
 
     #[must_use]
     pub const fn property(self) -> Property {
-        match self {
-            Self::Meter => Property::Length,
-            Self::Second => Property::Time,
-            Self::Gram => Property::Mass,
-            Self::Radian => Property::PlaneAngle,
-            Self::Kelvin => Property::Temperature,
-            Self::Coulomb => Property::ElectricCharge,
-            Self::Candela => Property::LuminousIntensity,
-            Self::TheNumberTenForArbitraryPowersStar => Property::Number,
-            Self::TheNumberTenForArbitraryPowersCaret => Property::Number,
-            Self::TheNumberPi => Property::Number,
-            Self::Percent => Property::Fraction,
-            Self::PartsPerThousand => Property::Fraction,
-            Self::PartsPerMillion => Property::Fraction,
-            Self::PartsPerBillion => Property::Fraction,
-            Self::PartsPerTrillion => Property::Fraction,
-            Self::Mole => Property::AmountOfSubstance,
-            Self::Steradian => Property::SolidAngle,
-            Self::Hertz => Property::Frequency,
-            Self::Newton => Property::Force,
-            Self::Pascal => Property::Pressure,
-            Self::Joule => Property::Energy,
-            Self::Watt => Property::Power,
-            Self::Ampere => Property::ElectricCurrent,
-            Self::Volt => Property::ElectricPotential,
-            Self::Farad => Property::ElectricCapacitance,
-            Self::Ohm => Property::ElectricResistance,
-            Self::Siemens => Property::ElectricConductance,
-            Self::Weber => Property::MagneticFlux,
-            Self::DegreeCelsius => Property::Temperature,
-            Self::Tesla => Property::MagneticFluxDensity,
-            Self::Henry => Property::Inductance,
-            Self::Lumen => Property::LuminousFlux,
-            Self::Lux => Property::Illuminance,
-            Self::Becquerel => Property::Radioactivity,
-            Self::Gray => Property::EnergyDose,
-            Self::Sievert => Property::DoseEquivalent,
-            Self::Gon => Property::PlaneAngle,
-            Self::Degree => Property::PlaneAngle,
-            Self::MinuteAngle => Property::PlaneAngle,
-            Self::SecondAngle => Property::PlaneAngle,
-            Self::Liter => Property::Volume,
-            Self::LiterSecondary => Property::Volume,
-            Self::Are => Property::Area,
-            Self::Minute => Property::Time,
-            Self::Hour => Property::Time,
-            Self::Day => Property::Time,
-            Self::TropicalYear => Property::Time,
-            Self::MeanJulianYear => Property::Time,
-            Self::MeanGregorianYear => Property::Time,
-            Self::Year => Property::Time,
-            Self::Week => Property::Time,
-            Self::SynodalMonth => Property::Time,
-            Self::MeanJulianMonth => Property::Time,
-            Self::MeanGregorianMonth => Property::Time,
-            Self::Month => Property::Time,
-            Self::Tonne => Property::Mass,
-            Self::Bar => Property::Pressure,
-            Self::UnifiedAtomicMassUnit => Property::Mass,
-            Self::Electronvolt => Property::Energy,
-            Self::AstronomicUnit => Property::Length,
-            Self::Parsec => Property::Length,
-            Self::VelocityOfLight => Property::Velocity,
-            Self::PlanckConstant => Property::Action,
-            Self::BoltzmannConstant => Property::Unclassified,
-            Self::PermittivityOfVacuum => Property::ElectricPermittivity,
-            Self::PermeabilityOfVacuum => Property::MagneticPermeability,
-            Self::ElementaryCharge => Property::ElectricCharge,
-            Self::ElectronMass => Property::Mass,
-            Self::ProtonMass => Property::Mass,
-            Self::NewtonianConstantOfGravitation => Property::Unclassified,
-            Self::StandardAccelerationOfFreeFall => Property::Acceleration,
-            Self::StandardAtmosphere => Property::Pressure,
-            Self::LightYear => Property::Length,
-            Self::GramForce => Property::Force,
-            Self::PoundForceAvoirdupois => Property::Force,
-            Self::Kayser => Property::LineicNumber,
-            Self::Gal => Property::Acceleration,
-            Self::Dyne => Property::Force,
-            Self::Erg => Property::Energy,
-            Self::Poise => Property::DynamicViscosity,
-            Self::Biot => Property::ElectricCurrent,
-            Self::Stokes => Property::KinematicViscosity,
-            Self::Maxwell => Property::FluxOfMagneticInduction,
-            Self::Gauss => Property::MagneticFluxDensity,
-            Self::Oersted => Property::MagneticFieldIntensity,
-            Self::Gilbert => Property::MagneticTension,
-            Self::Stilb => Property::LumIntensityDensity,
-            Self::Lambert => Property::Brightness,
-            Self::Phot => Property::Illuminance,
-            Self::Curie => Property::Radioactivity,
-            Self::Roentgen => Property::IonDose,
-            Self::RadiationAbsorbedDose => Property::EnergyDose,
-            Self::RadiationEquivalentMan => Property::DoseEquivalent,
-            Self::InchInternational => Property::Length,
-            Self::FootInternational => Property::Length,
-            Self::YardInternational => Property::Length,
-            Self::MileInternational => Property::Length,
-            Self::FathomInternational => Property::DepthOfWater,
-            Self::NauticalMileInternational => Property::Length,
-            Self::KnotInternational => Property::Velocity,
-            Self::SquareInchInternational => Property::Area,
-            Self::SquareFootInternational => Property::Area,
-            Self::SquareYardInternational => Property::Area,
-            Self::CubicInchInternational => Property::Volume,
-            Self::CubicFootInternational => Property::Volume,
-            Self::CubicYardInternational => Property::Volume,
-            Self::BoardFootInternational => Property::Volume,
-            Self::CordInternational => Property::Volume,
-            Self::MilInternational => Property::Length,
-            Self::CircularMilInternational => Property::Area,
-            Self::HandInternational => Property::HeightOfHorses,
-            Self::FootUS => Property::Length,
-            Self::YardUS => Property::Length,
-            Self::InchUS => Property::Length,
-            Self::RodUS => Property::Length,
-            Self::GuntersChainUS => Property::Length,
-            Self::LinkForGuntersChainUS => Property::Length,
-            Self::RamdensChainUS => Property::Length,
-            Self::LinkForRamdensChainUS => Property::Length,
-            Self::FathomUS => Property::Length,
-            Self::FurlongUS => Property::Length,
-            Self::MileUS => Property::Length,
-            Self::AcreUS => Property::Area,
-            Self::SquareRodUS => Property::Area,
-            Self::SquareMileUS => Property::Area,
-            Self::Section => Property::Area,
-            Self::Township => Property::Area,
-            Self::MilUS => Property::Length,
-            Self::InchBritish => Property::Length,
-            Self::FootBritish => Property::Length,
-            Self::RodBritish => Property::Length,
-            Self::GuntersChainBritish => Property::Length,
-            Self::LinkForGuntersChainBritish => Property::Length,
-            Self::FathomBritish => Property::Length,
-            Self::PaceBritish => Property::Length,
-            Self::YardBritish => Property::Length,
-            Self::MileBritish => Property::Length,
-            Self::NauticalMileBritish => Property::Length,
-            Self::KnotBritish => Property::Velocity,
-            Self::AcreBritish => Property::Area,
-            Self::QueenAnnesWineGallonUS => Property::FluidVolume,
-            Self::BarrelUS => Property::FluidVolume,
-            Self::QuartUS => Property::FluidVolume,
-            Self::PintUS => Property::FluidVolume,
-            Self::GillUS => Property::FluidVolume,
-            Self::FluidOunceUS => Property::FluidVolume,
-            Self::FluidDramUS => Property::FluidVolume,
-            Self::MinimUS => Property::FluidVolume,
-            Self::CordUS => Property::FluidVolume,
-            Self::BushelUS => Property::DryVolume,
-            Self::HistoricalWinchesterGallon => Property::DryVolume,
-            Self::PeckUS => Property::DryVolume,
-            Self::DryQuartUS => Property::DryVolume,
-            Self::DryPintUS => Property::DryVolume,
-            Self::TablespoonUS => Property::Volume,
-            Self::TeaspoonUS => Property::Volume,
-            Self::CupUS => Property::Volume,
-            Self::MetricFluidOunce => Property::FluidVolume,
-            Self::MetricCup => Property::Volume,
-            Self::MetricTeaspoon => Property::Volume,
-            Self::MetricTablespoon => Property::Volume,
-            Self::GallonBritish => Property::Volume,
-            Self::PeckBritish => Property::Volume,
-            Self::BushelBritish => Property::Volume,
-            Self::QuartBritish => Property::Volume,
-            Self::PintBritish => Property::Volume,
-            Self::GillBritish => Property::Volume,
-            Self::FluidOunceBritish => Property::Volume,
-            Self::FluidDramBritish => Property::Volume,
-            Self::MinimBritish => Property::Volume,
-            Self::Grain => Property::Mass,
-            Self::PoundAvoirdupois => Property::Mass,
-            Self::OunceAvoirdupois => Property::Mass,
-            Self::DramAvoirdupois => Property::Mass,
-            Self::ShortHundredweightAvoirdupois => Property::Mass,
-            Self::LongHunderdweightAvoirdupois => Property::Mass,
-            Self::ShortTonAvoirdupois => Property::Mass,
-            Self::LongTonAvoirdupois => Property::Mass,
-            Self::StoneAvoirdupois => Property::Mass,
-            Self::PennyweightTroy => Property::Mass,
-            Self::OunceTroy => Property::Mass,
-            Self::PoundTroy => Property::Mass,
-            Self::ScrupleApothecaries => Property::Mass,
-            Self::DramApothecaries => Property::Mass,
-            Self::OunceApothecaries => Property::Mass,
-            Self::PoundApothecaries => Property::Mass,
-            Self::MetricOunce => Property::Mass,
-            Self::Line => Property::Length,
-            Self::Point => Property::Length,
-            Self::Pica => Property::Length,
-            Self::PrintersPoint => Property::Length,
-            Self::PrintersPica => Property::Length,
-            Self::Pied => Property::Length,
-            Self::Pouce => Property::Length,
-            Self::Ligne => Property::Length,
-            Self::Didot => Property::Length,
-            Self::Cicero => Property::Length,
-            Self::DegreeFahrenheit => Property::Temperature,
-            Self::DegreeRankine => Property::Temperature,
-            Self::DegreeReaumur => Property::Temperature,
-            Self::CalorieAt15C => Property::Energy,
-            Self::CalorieAt20C => Property::Energy,
-            Self::MeanCalorie => Property::Energy,
-            Self::InternationalTableCalorie => Property::Energy,
-            Self::ThermochemicalCalorie => Property::Energy,
-            Self::Calorie => Property::Energy,
-            Self::NutritionLabelCalories => Property::Energy,
-            Self::BritishThermalUnitAt39F => Property::Energy,
-            Self::BritishThermalUnitAt59F => Property::Energy,
-            Self::BritishThermalUnitAt60F => Property::Energy,
-            Self::MeanBritishThermalUnit => Property::Energy,
-            Self::InternationalTableBritishThermalUnit => Property::Energy,
-            Self::ThermochemicalBritishThermalUnit => Property::Energy,
-            Self::BritishThermalUnit => Property::Energy,
-            Self::Horsepower => Property::Power,
-            Self::Tex => Property::LinearMassDensityOfTextileThread,
-            Self::Denier => Property::LinearMassDensityOfTextileThread,
-            Self::MeterOfWaterColumn => Property::Pressure,
-            Self::MeterOfMercuryColumn => Property::Pressure,
-            Self::InchOfWaterColumn => Property::Pressure,
-            Self::InchOfMercuryColumn => Property::Pressure,
-            Self::PeripheralVascularResistanceUnit => Property::FluidResistance,
-            Self::WoodUnit => Property::FluidResistance,
-            Self::Diopter => Property::RefractionOfALens,
-            Self::PrismDiopter => Property::RefractionOfAPrism,
-            Self::PercentOfSlope => Property::Slope,
-            Self::MeshInternational => Property::LineicNumber,
-            Self::Charriere => Property::GaugeOfCatheters,
-            Self::Drop => Property::Volume,
-            Self::HounsfieldUnit => Property::XRayAttenuation,
-            Self::MetabolicEquivalent => Property::MetabolicCostOfPhysicalActivity,
-            Self::HomeopathicPotencyOfDecimalSeriesRetired => Property::HomeopathicPotencyRetired,
-            Self::HomeopathicPotencyOfCentesimalSeriesRetired => Property::HomeopathicPotencyRetired,
-            Self::HomeopathicPotencyOfMillesimalSeriesRetired => Property::HomeopathicPotencyRetired,
-            Self::HomeopathicPotencyOfQuintamillesimalSeriesRetired => Property::HomeopathicPotencyRetired,
-            Self::HomeopathicPotencyOfDecimalHahnemannianSeries => Property::HomeopathicPotencyHahnemann,
-            Self::HomeopathicPotencyOfCentesimalHahnemannianSeries => Property::HomeopathicPotencyHahnemann,
-            Self::HomeopathicPotencyOfMillesimalHahnemannianSeries => Property::HomeopathicPotencyHahnemann,
-            Self::HomeopathicPotencyOfQuintamillesimalHahnemannianSeries => Property::HomeopathicPotencyHahnemann,
-            Self::HomeopathicPotencyOfDecimalKorsakovianSeries => Property::HomeopathicPotencyKorsakov,
-            Self::HomeopathicPotencyOfCentesimalKorsakovianSeries => Property::HomeopathicPotencyKorsakov,
-            Self::HomeopathicPotencyOfMillesimalKorsakovianSeries => Property::HomeopathicPotencyKorsakov,
-            Self::HomeopathicPotencyOfQuintamillesimalKorsakovianSeries => Property::HomeopathicPotencyKorsakov,
-            Self::Equivalents => Property::AmountOfSubstance,
-            Self::Osmole => Property::AmountOfSubstanceDissolvedParticles,
-            Self::PH => Property::Acidity,
-            Self::GramPercent => Property::MassConcentration,
-            Self::SvedbergUnit => Property::SedimentationCoefficient,
-            Self::HighPowerField => Property::ViewAreaInMicroscope,
-            Self::LowPowerField => Property::ViewAreaInMicroscope,
-            Self::Katal => Property::CatalyticActivity,
-            Self::Unit => Property::CatalyticActivity,
-            Self::InternationalUnit => Property::Arbitrary,
-            Self::InternationalUnitSecondary => Property::Arbitrary,
-            Self::ArbitraryUnit => Property::Arbitrary,
-            Self::UnitedStatesPharmacopeiaUnit => Property::Arbitrary,
-            Self::GplUnit => Property::BiologicActivityOfAnticardiolipinIgG,
-            Self::MplUnit => Property::BiologicActivityOfAnticardiolipinIgM,
-            Self::AplUnit => Property::BiologicActivityOfAnticardiolipinIgA,
-            Self::BethesdaUnit => Property::BiologicActivityOfFactorViiiInhibitor,
-            Self::AntiFactorXaUnit => Property::BiologicActivityOfFactorXaInhibitorHeparin,
-            Self::ToddUnit => Property::BiologicActivityAntistreptolysinO,
-            Self::DyeUnit => Property::BiologicActivityOfAmylase,
-            Self::SomogyiUnit => Property::BiologicActivityOfAmylase,
-            Self::BodanskyUnit => Property::BiologicActivityOfPhosphatase,
-            Self::KingArmstrongUnit => Property::BiologicActivityOfPhosphatase,
-            Self::KunkelUnit => Property::ArbitraryBiologicActivity,
-            Self::MacLaganUnit => Property::ArbitraryBiologicActivity,
-            Self::TuberculinUnit => Property::BiologicActivityOfTuberculin,
-            Self::CellCultureInfectiousDose => Property::BiologicActivityInfectivityOfAnInfectiousAgentPreparation,
-            Self::TissueCultureInfectiousDose => Property::BiologicActivityInfectivityOfAnInfectiousAgentPreparation,
-            Self::EmbryoInfectiousDose => Property::BiologicActivityInfectivityOfAnInfectiousAgentPreparation,
-            Self::PlaqueFormingUnits => Property::AmountOfAnInfectiousAgent,
-            Self::FocusFormingUnits => Property::AmountOfAnInfectiousAgent,
-            Self::ColonyFormingUnits => Property::AmountOfAProliferatingOrganism,
-            Self::IndexOfReactivity => Property::AmountOfAnAllergenCallibratedThroughInVivoTestingUsingTheStallergenesMethod,
-            Self::BioequivalentAllergenUnit => Property::AmountOfAnAllergenCallibratedThroughInVivoTestingBasedOnTheId50ealMethodOfIntradermalDilutionFor50mmSumOfErythemaDiameters,
-            Self::AllergenUnit => Property::ProcedureDefinedAmountOfAnAllergenUsingSomeReferenceStandard,
-            Self::AllergenUnitForAmbrosiaArtemisiifolia => Property::ProcedureDefinedAmountOfTheMajorAllergenOfRagweed,
-            Self::ProteinNitrogenUnit => Property::ProcedureDefinedAmountOfAProteinSubstance,
-            Self::LimitOfFlocculation => Property::ProcedureDefinedAmountOfAnAntigenSubstance,
-            Self::DAntigenUnit => Property::ProcedureDefinedAmountOfAPoliomyelitisDAntigenSubstance,
-            Self::FibrinogenEquivalentUnit => Property::AmountOfFibrinogenBrokenDownIntoTheMeasuredDDimers,
-            Self::ElisaUnit => Property::ArbitraryElisaUnit,
-            Self::EhrlichUnit => Property::EhrlichUnit,
-            Self::Neper => Property::Level,
-            Self::Bel => Property::Level,
-            Self::BelSoundPressure => Property::PressureLevel,
-            Self::BelVolt => Property::ElectricPotentialLevel,
-            Self::BelMillivolt => Property::ElectricPotentialLevel,
-            Self::BelMicrovolt => Property::ElectricPotentialLevel,
-            Self::Bel10Nanovolt => Property::ElectricPotentialLevel,
-            Self::BelWatt => Property::PowerLevel,
-            Self::BelKilowatt => Property::PowerLevel,
-            Self::Stere => Property::Volume,
-            Self::Angstrom => Property::Length,
-            Self::Barn => Property::ActionArea,
-            Self::TechnicalAtmosphere => Property::Pressure,
-            Self::Mho => Property::ElectricConductance,
-            Self::PoundPerSqareInch => Property::Pressure,
-            Self::Circle => Property::PlaneAngle,
-            Self::Spere => Property::SolidAngle,
-            Self::MetricCarat => Property::Mass,
-            Self::CaratOfGoldAlloys => Property::MassFraction,
-            Self::Smoot => Property::Length,
-            Self::MeterPerSquareSecondsPerSquareRootOfHertz => Property::AmplitudeSpectralDensity,
-            Self::BitLogarithmusDualis => Property::AmountOfInformation,
-            Self::Bit => Property::AmountOfInformation,
-            Self::Byte => Property::AmountOfInformation,
-            Self::Baud => Property::SignalTransmissionRate,
+        match self {            Self::Meter => Property::Length,            Self::Second => Property::Time,            Self::Gram => Property::Mass,            Self::Radian => Property::PlaneAngle,            Self::Kelvin => Property::Temperature,            Self::Coulomb => Property::ElectricCharge,            Self::Candela => Property::LuminousIntensity,            Self::TheNumberTenForArbitraryPowersStar => Property::Number,            Self::TheNumberTenForArbitraryPowersCaret => Property::Number,            Self::TheNumberPi => Property::Number,            Self::Percent => Property::Fraction,            Self::PartsPerThousand => Property::Fraction,            Self::PartsPerMillion => Property::Fraction,            Self::PartsPerBillion => Property::Fraction,            Self::PartsPerTrillion => Property::Fraction,            Self::Mole => Property::AmountOfSubstance,            Self::Steradian => Property::SolidAngle,            Self::Hertz => Property::Frequency,            Self::Newton => Property::Force,            Self::Pascal => Property::Pressure,            Self::Joule => Property::Energy,            Self::Watt => Property::Power,            Self::Ampere => Property::ElectricCurrent,            Self::Volt => Property::ElectricPotential,            Self::Farad => Property::ElectricCapacitance,            Self::Ohm => Property::ElectricResistance,            Self::Siemens => Property::ElectricConductance,            Self::Weber => Property::MagneticFlux,            Self::DegreeCelsius => Property::Temperature,            Self::Tesla => Property::MagneticFluxDensity,            Self::Henry => Property::Inductance,            Self::Lumen => Property::LuminousFlux,            Self::Lux => Property::Illuminance,            Self::Becquerel => Property::Radioactivity,            Self::Gray => Property::EnergyDose,            Self::Sievert => Property::DoseEquivalent,            Self::Gon => Property::PlaneAngle,            Self::Degree => Property::PlaneAngle,            Self::MinuteAngle => Property::PlaneAngle,            Self::SecondAngle => Property::PlaneAngle,            Self::Liter => Property::Volume,            Self::LiterSecondary => Property::Volume,            Self::Are => Property::Area,            Self::Minute => Property::Time,            Self::Hour => Property::Time,            Self::Day => Property::Time,            Self::TropicalYear => Property::Time,            Self::MeanJulianYear => Property::Time,            Self::MeanGregorianYear => Property::Time,            Self::Year => Property::Time,            Self::Week => Property::Time,            Self::SynodalMonth => Property::Time,            Self::MeanJulianMonth => Property::Time,            Self::MeanGregorianMonth => Property::Time,            Self::Month => Property::Time,            Self::Tonne => Property::Mass,            Self::Bar => Property::Pressure,            Self::UnifiedAtomicMassUnit => Property::Mass,            Self::Electronvolt => Property::Energy,            Self::AstronomicUnit => Property::Length,            Self::Parsec => Property::Length,            Self::VelocityOfLight => Property::Velocity,            Self::PlanckConstant => Property::Action,            Self::BoltzmannConstant => Property::Unclassified,            Self::PermittivityOfVacuum => Property::ElectricPermittivity,            Self::PermeabilityOfVacuum => Property::MagneticPermeability,            Self::ElementaryCharge => Property::ElectricCharge,            Self::ElectronMass => Property::Mass,            Self::ProtonMass => Property::Mass,            Self::NewtonianConstantOfGravitation => Property::Unclassified,            Self::StandardAccelerationOfFreeFall => Property::Acceleration,            Self::StandardAtmosphere => Property::Pressure,            Self::LightYear => Property::Length,            Self::GramForce => Property::Force,            Self::PoundForceAvoirdupois => Property::Force,            Self::Kayser => Property::LineicNumber,            Self::Gal => Property::Acceleration,            Self::Dyne => Property::Force,            Self::Erg => Property::Energy,            Self::Poise => Property::DynamicViscosity,            Self::Biot => Property::ElectricCurrent,            Self::Stokes => Property::KinematicViscosity,            Self::Maxwell => Property::FluxOfMagneticInduction,            Self::Gauss => Property::MagneticFluxDensity,            Self::Oersted => Property::MagneticFieldIntensity,            Self::Gilbert => Property::MagneticTension,            Self::Stilb => Property::LumIntensityDensity,            Self::Lambert => Property::Brightness,            Self::Phot => Property::Illuminance,            Self::Curie => Property::Radioactivity,            Self::Roentgen => Property::IonDose,            Self::RadiationAbsorbedDose => Property::EnergyDose,            Self::RadiationEquivalentMan => Property::DoseEquivalent,            Self::InchInternational => Property::Length,            Self::FootInternational => Property::Length,            Self::YardInternational => Property::Length,            Self::MileInternational => Property::Length,            Self::FathomInternational => Property::DepthOfWater,            Self::NauticalMileInternational => Property::Length,            Self::KnotInternational => Property::Velocity,            Self::SquareInchInternational => Property::Area,            Self::SquareFootInternational => Property::Area,            Self::SquareYardInternational => Property::Area,            Self::CubicInchInternational => Property::Volume,            Self::CubicFootInternational => Property::Volume,            Self::CubicYardInternational => Property::Volume,            Self::BoardFootInternational => Property::Volume,            Self::CordInternational => Property::Volume,            Self::MilInternational => Property::Length,            Self::CircularMilInternational => Property::Area,            Self::HandInternational => Property::HeightOfHorses,            Self::FootUS => Property::Length,            Self::YardUS => Property::Length,            Self::InchUS => Property::Length,            Self::RodUS => Property::Length,            Self::GuntersChainUS => Property::Length,            Self::LinkForGuntersChainUS => Property::Length,            Self::RamdensChainUS => Property::Length,            Self::LinkForRamdensChainUS => Property::Length,            Self::FathomUS => Property::Length,            Self::FurlongUS => Property::Length,            Self::MileUS => Property::Length,            Self::AcreUS => Property::Area,            Self::SquareRodUS => Property::Area,            Self::SquareMileUS => Property::Area,            Self::Section => Property::Area,            Self::Township => Property::Area,            Self::MilUS => Property::Length,            Self::InchBritish => Property::Length,            Self::FootBritish => Property::Length,            Self::RodBritish => Property::Length,            Self::GuntersChainBritish => Property::Length,            Self::LinkForGuntersChainBritish => Property::Length,            Self::FathomBritish => Property::Length,            Self::PaceBritish => Property::Length,            Self::YardBritish => Property::Length,            Self::MileBritish => Property::Length,            Self::NauticalMileBritish => Property::Length,            Self::KnotBritish => Property::Velocity,            Self::AcreBritish => Property::Area,            Self::QueenAnnesWineGallonUS => Property::FluidVolume,            Self::BarrelUS => Property::FluidVolume,            Self::QuartUS => Property::FluidVolume,            Self::PintUS => Property::FluidVolume,            Self::GillUS => Property::FluidVolume,            Self::FluidOunceUS => Property::FluidVolume,            Self::FluidDramUS => Property::FluidVolume,            Self::MinimUS => Property::FluidVolume,            Self::CordUS => Property::FluidVolume,            Self::BushelUS => Property::DryVolume,            Self::HistoricalWinchesterGallon => Property::DryVolume,            Self::PeckUS => Property::DryVolume,            Self::DryQuartUS => Property::DryVolume,            Self::DryPintUS => Property::DryVolume,            Self::TablespoonUS => Property::Volume,            Self::TeaspoonUS => Property::Volume,            Self::CupUS => Property::Volume,            Self::MetricFluidOunce => Property::FluidVolume,            Self::MetricCup => Property::Volume,            Self::MetricTeaspoon => Property::Volume,            Self::MetricTablespoon => Property::Volume,            Self::GallonBritish => Property::Volume,            Self::PeckBritish => Property::Volume,            Self::BushelBritish => Property::Volume,            Self::QuartBritish => Property::Volume,            Self::PintBritish => Property::Volume,            Self::GillBritish => Property::Volume,            Self::FluidOunceBritish => Property::Volume,            Self::FluidDramBritish => Property::Volume,            Self::MinimBritish => Property::Volume,            Self::Grain => Property::Mass,            Self::PoundAvoirdupois => Property::Mass,            Self::OunceAvoirdupois => Property::Mass,            Self::DramAvoirdupois => Property::Mass,            Self::ShortHundredweightAvoirdupois => Property::Mass,            Self::LongHunderdweightAvoirdupois => Property::Mass,            Self::ShortTonAvoirdupois => Property::Mass,            Self::LongTonAvoirdupois => Property::Mass,            Self::StoneAvoirdupois => Property::Mass,            Self::PennyweightTroy => Property::Mass,            Self::OunceTroy => Property::Mass,            Self::PoundTroy => Property::Mass,            Self::ScrupleApothecaries => Property::Mass,            Self::DramApothecaries => Property::Mass,            Self::OunceApothecaries => Property::Mass,            Self::PoundApothecaries => Property::Mass,            Self::MetricOunce => Property::Mass,            Self::Line => Property::Length,            Self::Point => Property::Length,            Self::Pica => Property::Length,            Self::PrintersPoint => Property::Length,            Self::PrintersPica => Property::Length,            Self::Pied => Property::Length,            Self::Pouce => Property::Length,            Self::Ligne => Property::Length,            Self::Didot => Property::Length,            Self::Cicero => Property::Length,            Self::DegreeFahrenheit => Property::Temperature,            Self::DegreeRankine => Property::Temperature,            Self::DegreeReaumur => Property::Temperature,            Self::CalorieAt15C => Property::Energy,            Self::CalorieAt20C => Property::Energy,            Self::MeanCalorie => Property::Energy,            Self::InternationalTableCalorie => Property::Energy,            Self::ThermochemicalCalorie => Property::Energy,            Self::Calorie => Property::Energy,            Self::NutritionLabelCalories => Property::Energy,            Self::BritishThermalUnitAt39F => Property::Energy,            Self::BritishThermalUnitAt59F => Property::Energy,            Self::BritishThermalUnitAt60F => Property::Energy,            Self::MeanBritishThermalUnit => Property::Energy,            Self::InternationalTableBritishThermalUnit => Property::Energy,            Self::ThermochemicalBritishThermalUnit => Property::Energy,            Self::BritishThermalUnit => Property::Energy,            Self::Horsepower => Property::Power,            Self::Tex => Property::LinearMassDensityOfTextileThread,            Self::Denier => Property::LinearMassDensityOfTextileThread,            Self::MeterOfWaterColumn => Property::Pressure,            Self::MeterOfMercuryColumn => Property::Pressure,            Self::InchOfWaterColumn => Property::Pressure,            Self::InchOfMercuryColumn => Property::Pressure,            Self::PeripheralVascularResistanceUnit => Property::FluidResistance,            Self::WoodUnit => Property::FluidResistance,            Self::Diopter => Property::RefractionOfALens,            Self::PrismDiopter => Property::RefractionOfAPrism,            Self::PercentOfSlope => Property::Slope,            Self::MeshInternational => Property::LineicNumber,            Self::Charriere => Property::GaugeOfCatheters,            Self::Drop => Property::Volume,            Self::HounsfieldUnit => Property::XRayAttenuation,            Self::MetabolicEquivalent => Property::MetabolicCostOfPhysicalActivity,            Self::HomeopathicPotencyOfDecimalSeriesRetired => Property::HomeopathicPotencyRetired,            Self::HomeopathicPotencyOfCentesimalSeriesRetired => Property::HomeopathicPotencyRetired,            Self::HomeopathicPotencyOfMillesimalSeriesRetired => Property::HomeopathicPotencyRetired,            Self::HomeopathicPotencyOfQuintamillesimalSeriesRetired => Property::HomeopathicPotencyRetired,            Self::HomeopathicPotencyOfDecimalHahnemannianSeries => Property::HomeopathicPotencyHahnemann,            Self::HomeopathicPotencyOfCentesimalHahnemannianSeries => Property::HomeopathicPotencyHahnemann,            Self::HomeopathicPotencyOfMillesimalHahnemannianSeries => Property::HomeopathicPotencyHahnemann,            Self::HomeopathicPotencyOfQuintamillesimalHahnemannianSeries => Property::HomeopathicPotencyHahnemann,            Self::HomeopathicPotencyOfDecimalKorsakovianSeries => Property::HomeopathicPotencyKorsakov,            Self::HomeopathicPotencyOfCentesimalKorsakovianSeries => Property::HomeopathicPotencyKorsakov,            Self::HomeopathicPotencyOfMillesimalKorsakovianSeries => Property::HomeopathicPotencyKorsakov,            Self::HomeopathicPotencyOfQuintamillesimalKorsakovianSeries => Property::HomeopathicPotencyKorsakov,            Self::Equivalents => Property::AmountOfSubstance,            Self::Osmole => Property::AmountOfSubstanceDissolvedParticles,            Self::PH => Property::Acidity,            Self::GramPercent => Property::MassConcentration,            Self::SvedbergUnit => Property::SedimentationCoefficient,            Self::HighPowerField => Property::ViewAreaInMicroscope,            Self::LowPowerField => Property::ViewAreaInMicroscope,            Self::Katal => Property::CatalyticActivity,            Self::Unit => Property::CatalyticActivity,            Self::InternationalUnit => Property::Arbitrary,            Self::InternationalUnitSecondary => Property::Arbitrary,            Self::ArbitraryUnit => Property::Arbitrary,            Self::UnitedStatesPharmacopeiaUnit => Property::Arbitrary,            Self::GplUnit => Property::BiologicActivityOfAnticardiolipinIgG,            Self::MplUnit => Property::BiologicActivityOfAnticardiolipinIgM,            Self::AplUnit => Property::BiologicActivityOfAnticardiolipinIgA,            Self::BethesdaUnit => Property::BiologicActivityOfFactorViiiInhibitor,            Self::AntiFactorXaUnit => Property::BiologicActivityOfFactorXaInhibitorHeparin,            Self::ToddUnit => Property::BiologicActivityAntistreptolysinO,            Self::DyeUnit => Property::BiologicActivityOfAmylase,            Self::SomogyiUnit => Property::BiologicActivityOfAmylase,            Self::BodanskyUnit => Property::BiologicActivityOfPhosphatase,            Self::KingArmstrongUnit => Property::BiologicActivityOfPhosphatase,            Self::KunkelUnit => Property::ArbitraryBiologicActivity,            Self::MacLaganUnit => Property::ArbitraryBiologicActivity,            Self::TuberculinUnit => Property::BiologicActivityOfTuberculin,            Self::CellCultureInfectiousDose => Property::BiologicActivityInfectivityOfAnInfectiousAgentPreparation,            Self::TissueCultureInfectiousDose => Property::BiologicActivityInfectivityOfAnInfectiousAgentPreparation,            Self::EmbryoInfectiousDose => Property::BiologicActivityInfectivityOfAnInfectiousAgentPreparation,            Self::PlaqueFormingUnits => Property::AmountOfAnInfectiousAgent,            Self::FocusFormingUnits => Property::AmountOfAnInfectiousAgent,            Self::ColonyFormingUnits => Property::AmountOfAProliferatingOrganism,            Self::IndexOfReactivity => Property::AmountOfAnAllergenCallibratedThroughInVivoTestingUsingTheStallergenesMethod,            Self::BioequivalentAllergenUnit => Property::AmountOfAnAllergenCallibratedThroughInVivoTestingBasedOnTheId50ealMethodOfIntradermalDilutionFor50mmSumOfErythemaDiameters,            Self::AllergenUnit => Property::ProcedureDefinedAmountOfAnAllergenUsingSomeReferenceStandard,            Self::AllergenUnitForAmbrosiaArtemisiifolia => Property::ProcedureDefinedAmountOfTheMajorAllergenOfRagweed,            Self::ProteinNitrogenUnit => Property::ProcedureDefinedAmountOfAProteinSubstance,            Self::LimitOfFlocculation => Property::ProcedureDefinedAmountOfAnAntigenSubstance,            Self::DAntigenUnit => Property::ProcedureDefinedAmountOfAPoliomyelitisDAntigenSubstance,            Self::FibrinogenEquivalentUnit => Property::AmountOfFibrinogenBrokenDownIntoTheMeasuredDDimers,            Self::ElisaUnit => Property::ArbitraryElisaUnit,            Self::EhrlichUnit => Property::EhrlichUnit,            Self::Neper => Property::Level,            Self::Bel => Property::Level,            Self::BelSoundPressure => Property::PressureLevel,            Self::BelVolt => Property::ElectricPotentialLevel,            Self::BelMillivolt => Property::ElectricPotentialLevel,            Self::BelMicrovolt => Property::ElectricPotentialLevel,            Self::Bel10Nanovolt => Property::ElectricPotentialLevel,            Self::BelWatt => Property::PowerLevel,            Self::BelKilowatt => Property::PowerLevel,            Self::Stere => Property::Volume,            Self::Angstrom => Property::Length,            Self::Barn => Property::ActionArea,            Self::TechnicalAtmosphere => Property::Pressure,            Self::Mho => Property::ElectricConductance,            Self::PoundPerSqareInch => Property::Pressure,            Self::Circle => Property::PlaneAngle,            Self::Spere => Property::SolidAngle,            Self::MetricCarat => Property::Mass,            Self::CaratOfGoldAlloys => Property::MassFraction,            Self::Smoot => Property::Length,            Self::MeterPerSquareSecondsPerSquareRootOfHertz => Property::AmplitudeSpectralDensity,            Self::BitLogarithmusDualis => Property::AmountOfInformation,            Self::Bit => Property::AmountOfInformation,            Self::Byte => Property::AmountOfInformation,            Self::Baud => Property::SignalTransmissionRate,
         }
     }
 }
@@ -2635,7 +2206,7 @@ impl UcumSymbol for Atom {
     }
 
     fn definition_unit(&self) -> Unit {
-        Unit::new(self.definition().terms().clone())
+        Unit::new_static(self.definition().terms())
     }
 }
 
@@ -2843,16 +2414,7 @@ impl DefaultCompatibility for Atom {}
 
 impl Composable for Atom {
     fn composition(&self) -> Composition {
-        match self {
-            Self::Candela => Composition::new(Dimension::LuminousIntensity, 1),
-            Self::Coulomb => Composition::new(Dimension::ElectricCharge, 1),
-            Self::Gram => Composition::new(Dimension::Mass, 1),
-            Self::Kelvin => Composition::new(Dimension::Temperature, 1),
-            Self::Meter => Composition::new(Dimension::Length, 1),
-            Self::Radian => Composition::new(Dimension::PlaneAngle, 1),
-            Self::Second => Composition::new(Dimension::Time, 1),
-            _ => self.definition().terms().composition(),
-        }
+        self.definition().composition()
     }
 }
 
@@ -2871,3 +2433,5173 @@ impl fmt::Display for Atom {
         write!(f, "{}", self.primary_code())
     }
 }
+
+/// Each `Atom`'s `Definition`, precomputed from its UCUM definition.
+///
+// The scalars are calculated, so they aren't written to be read, and may be close to constants.
+#[allow(clippy::approx_constant, clippy::unreadable_literal)]
+mod definitions {
+    use crate::parser::{
+        definition::Definition, function_set::FunctionSet, Atom, Composition, Dimension, Prefix,
+        Term,
+    };
+    pub(super) static METER: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(1.0),
+        )
+    };
+
+    pub(super) static SECOND: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Time, 1),
+            Some(1.0),
+        )
+    };
+
+    pub(super) static GRAM: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Mass, 1),
+            Some(1.0),
+        )
+    };
+
+    pub(super) static RADIAN: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::PlaneAngle, 1),
+            Some(1.0),
+        )
+    };
+
+    pub(super) static KELVIN: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Temperature, 1),
+            Some(1.0),
+        )
+    };
+
+    pub(super) static COULOMB: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::ElectricCharge, 1),
+            Some(1.0),
+        )
+    };
+
+    pub(super) static CANDELA: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::LuminousIntensity, 1),
+            Some(1.0),
+        )
+    };
+
+    pub(super) static THE_NUMBER_TEN_FOR_ARBITRARY_POWERS_STAR: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(10.0, TERMS, None, Composition::EMPTY, Some(10.0))
+    };
+
+    pub(super) static THE_NUMBER_TEN_FOR_ARBITRARY_POWERS_CARET: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(10.0, TERMS, None, Composition::EMPTY, Some(10.0))
+    };
+
+    pub(super) static THE_NUMBER_PI: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(
+            ::std::f64::consts::PI,
+            TERMS,
+            None,
+            Composition::EMPTY,
+            Some(3.141592653589793),
+        )
+    };
+
+    pub(super) static PERCENT: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::TheNumberTenForArbitraryPowersStar),
+            prefix: None,
+            factor: None,
+            exponent: Some(-2),
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(0.01))
+    };
+
+    pub(super) static PARTS_PER_THOUSAND: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::TheNumberTenForArbitraryPowersStar),
+            prefix: None,
+            factor: None,
+            exponent: Some(-3),
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(0.001))
+    };
+
+    pub(super) static PARTS_PER_MILLION: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::TheNumberTenForArbitraryPowersStar),
+            prefix: None,
+            factor: None,
+            exponent: Some(-6),
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1e-6))
+    };
+
+    pub(super) static PARTS_PER_BILLION: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::TheNumberTenForArbitraryPowersStar),
+            prefix: None,
+            factor: None,
+            exponent: Some(-9),
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1e-9))
+    };
+
+    pub(super) static PARTS_PER_TRILLION: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::TheNumberTenForArbitraryPowersStar),
+            prefix: None,
+            factor: None,
+            exponent: Some(-12),
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1e-12))
+    };
+
+    pub(super) static MOLE: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::TheNumberTenForArbitraryPowersStar),
+            prefix: None,
+            factor: None,
+            exponent: Some(23),
+            annotation: None,
+        }];
+
+        Definition::new(
+            6.0221367,
+            TERMS,
+            None,
+            Composition::EMPTY,
+            Some(6.0221367e23),
+        )
+    };
+
+    pub(super) static STERADIAN: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::Radian),
+            prefix: None,
+            factor: None,
+            exponent: Some(2),
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::PlaneAngle, 2),
+            Some(1.0),
+        )
+    };
+
+    pub(super) static HERTZ: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::Second),
+            prefix: None,
+            factor: None,
+            exponent: Some(-1),
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Time, -1),
+            Some(1.0),
+        )
+    };
+
+    pub(super) static NEWTON: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(Some(Prefix::Kilo), Some(Atom::Gram)),
+            Term::new(None, Some(Atom::Meter)),
+            Term {
+                atom: Some(Atom::Second),
+                prefix: None,
+                factor: None,
+                exponent: Some(-2),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 1)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            Some(1000.0),
+        )
+    };
+
+    pub(super) static PASCAL: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Newton)),
+            Term {
+                atom: Some(Atom::Meter),
+                prefix: None,
+                factor: None,
+                exponent: Some(-2),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, -1)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            Some(1000.0),
+        )
+    };
+
+    pub(super) static JOULE: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Newton)),
+            Term::new(None, Some(Atom::Meter)),
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            Some(1000.0),
+        )
+    };
+
+    pub(super) static WATT: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Joule)),
+            Term {
+                atom: Some(Atom::Second),
+                prefix: None,
+                factor: None,
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -3),
+            Some(1000.0),
+        )
+    };
+
+    pub(super) static AMPERE: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Coulomb)),
+            Term {
+                atom: Some(Atom::Second),
+                prefix: None,
+                factor: None,
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::ElectricCharge, 1)
+                .with(Dimension::Time, -1),
+            Some(1.0),
+        )
+    };
+
+    pub(super) static VOLT: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Joule)),
+            Term {
+                atom: Some(Atom::Coulomb),
+                prefix: None,
+                factor: None,
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::ElectricCharge, -1)
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            Some(1000.0),
+        )
+    };
+
+    pub(super) static FARAD: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Coulomb)),
+            Term {
+                atom: Some(Atom::Volt),
+                prefix: None,
+                factor: None,
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::ElectricCharge, 2)
+                .with(Dimension::Length, -2)
+                .with(Dimension::Mass, -1)
+                .with(Dimension::Time, 2),
+            Some(0.001),
+        )
+    };
+
+    pub(super) static OHM: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Volt)),
+            Term {
+                atom: Some(Atom::Ampere),
+                prefix: None,
+                factor: None,
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::ElectricCharge, -2)
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -1),
+            Some(1000.0),
+        )
+    };
+
+    pub(super) static SIEMENS: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::Ohm),
+            prefix: None,
+            factor: None,
+            exponent: Some(-1),
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::ElectricCharge, 2)
+                .with(Dimension::Length, -2)
+                .with(Dimension::Mass, -1)
+                .with(Dimension::Time, 1),
+            Some(0.001),
+        )
+    };
+
+    pub(super) static WEBER: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Volt)),
+            Term::new(None, Some(Atom::Second)),
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::ElectricCharge, -1)
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -1),
+            Some(1000.0),
+        )
+    };
+
+    pub(super) static DEGREE_CELSIUS: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Kelvin))];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            Some(FunctionSet {
+                convert_from: |value: f64| value - 273.15,
+                convert_to: |value: f64| value + 273.15,
+            }),
+            Composition::EMPTY.with(Dimension::Temperature, 1),
+            None,
+        )
+    };
+
+    pub(super) static TESLA: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Weber)),
+            Term {
+                atom: Some(Atom::Meter),
+                prefix: None,
+                factor: None,
+                exponent: Some(-2),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::ElectricCharge, -1)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -1),
+            Some(1000.0),
+        )
+    };
+
+    pub(super) static HENRY: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Weber)),
+            Term {
+                atom: Some(Atom::Ampere),
+                prefix: None,
+                factor: None,
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::ElectricCharge, -2)
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1),
+            Some(1000.0),
+        )
+    };
+
+    pub(super) static LUMEN: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Candela)),
+            Term::new(None, Some(Atom::Steradian)),
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::LuminousIntensity, 1)
+                .with(Dimension::PlaneAngle, 2),
+            Some(1.0),
+        )
+    };
+
+    pub(super) static LUX: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Lumen)),
+            Term {
+                atom: Some(Atom::Meter),
+                prefix: None,
+                factor: None,
+                exponent: Some(-2),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, -2)
+                .with(Dimension::LuminousIntensity, 1)
+                .with(Dimension::PlaneAngle, 2),
+            Some(1.0),
+        )
+    };
+
+    pub(super) static BECQUEREL: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::Second),
+            prefix: None,
+            factor: None,
+            exponent: Some(-1),
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Time, -1),
+            Some(1.0),
+        )
+    };
+
+    pub(super) static GRAY: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Joule)),
+            Term {
+                atom: Some(Atom::Gram),
+                prefix: Some(Prefix::Kilo),
+                factor: None,
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 2)
+                .with(Dimension::Time, -2),
+            Some(1.0),
+        )
+    };
+
+    pub(super) static SIEVERT: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Joule)),
+            Term {
+                atom: Some(Atom::Gram),
+                prefix: Some(Prefix::Kilo),
+                factor: None,
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 2)
+                .with(Dimension::Time, -2),
+            Some(1.0),
+        )
+    };
+
+    pub(super) static GON: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Degree))];
+
+        Definition::new(
+            0.9,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::PlaneAngle, 1),
+            Some(0.015707963267948967),
+        )
+    };
+
+    pub(super) static DEGREE: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::TheNumberPi)),
+            Term::new(None, Some(Atom::Radian)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(360),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            2.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::PlaneAngle, 1),
+            Some(0.017453292519943295),
+        )
+    };
+
+    pub(super) static MINUTE_ANGLE: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Degree)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(60),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::PlaneAngle, 1),
+            Some(0.0002908882086657216),
+        )
+    };
+
+    pub(super) static SECOND_ANGLE: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::MinuteAngle)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(60),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::PlaneAngle, 1),
+            Some(4.84813681109536e-6),
+        )
+    };
+
+    pub(super) static LITER: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::Meter),
+            prefix: Some(Prefix::Deci),
+            factor: None,
+            exponent: Some(3),
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(0.0010000000000000002),
+        )
+    };
+
+    pub(super) static LITER_SECONDARY: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Liter))];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(0.0010000000000000002),
+        )
+    };
+
+    pub(super) static ARE: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::Meter),
+            prefix: None,
+            factor: None,
+            exponent: Some(2),
+            annotation: None,
+        }];
+
+        Definition::new(
+            100.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 2),
+            Some(100.0),
+        )
+    };
+
+    pub(super) static MINUTE: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Second))];
+
+        Definition::new(
+            60.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Time, 1),
+            Some(60.0),
+        )
+    };
+
+    pub(super) static HOUR: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Minute))];
+
+        Definition::new(
+            60.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Time, 1),
+            Some(3600.0),
+        )
+    };
+
+    pub(super) static DAY: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Hour))];
+
+        Definition::new(
+            24.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Time, 1),
+            Some(86400.0),
+        )
+    };
+
+    pub(super) static TROPICAL_YEAR: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Day))];
+
+        Definition::new(
+            365.24219,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Time, 1),
+            Some(31556925.216),
+        )
+    };
+
+    pub(super) static MEAN_JULIAN_YEAR: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Day))];
+
+        Definition::new(
+            365.25,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Time, 1),
+            Some(31557600.0),
+        )
+    };
+
+    pub(super) static MEAN_GREGORIAN_YEAR: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Day))];
+
+        Definition::new(
+            365.2425,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Time, 1),
+            Some(31556952.0),
+        )
+    };
+
+    pub(super) static YEAR: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::MeanJulianYear))];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Time, 1),
+            Some(31557600.0),
+        )
+    };
+
+    pub(super) static WEEK: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Day))];
+
+        Definition::new(
+            7.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Time, 1),
+            Some(604800.0),
+        )
+    };
+
+    pub(super) static SYNODAL_MONTH: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Day))];
+
+        Definition::new(
+            29.53059,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Time, 1),
+            Some(2551442.976),
+        )
+    };
+
+    pub(super) static MEAN_JULIAN_MONTH: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::MeanJulianYear)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(12),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Time, 1),
+            Some(2629800.0),
+        )
+    };
+
+    pub(super) static MEAN_GREGORIAN_MONTH: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::MeanGregorianYear)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(12),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Time, 1),
+            Some(2629746.0),
+        )
+    };
+
+    pub(super) static MONTH: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::MeanJulianMonth))];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Time, 1),
+            Some(2629800.0),
+        )
+    };
+
+    pub(super) static TONNE: Definition = {
+        const TERMS: &[Term] = &[Term::new(Some(Prefix::Kilo), Some(Atom::Gram))];
+
+        Definition::new(
+            1000.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Mass, 1),
+            Some(1000000.0),
+        )
+    };
+
+    pub(super) static BAR: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Pascal))];
+
+        Definition::new(
+            100000.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, -1)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            Some(100000000.0),
+        )
+    };
+
+    pub(super) static UNIFIED_ATOMIC_MASS_UNIT: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Gram))];
+
+        Definition::new(
+            1.6605402e-24,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Mass, 1),
+            Some(1.6605402e-24),
+        )
+    };
+
+    pub(super) static ELECTRONVOLT: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::ElementaryCharge)),
+            Term::new(None, Some(Atom::Volt)),
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            Some(1.60217733e-16),
+        )
+    };
+
+    pub(super) static ASTRONOMIC_UNIT: Definition = {
+        const TERMS: &[Term] = &[Term::new(Some(Prefix::Mega), Some(Atom::Meter))];
+
+        Definition::new(
+            149597.870691,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(149597870691.0),
+        )
+    };
+
+    pub(super) static PARSEC: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Meter))];
+
+        Definition::new(
+            3.085678e16,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(3.085678e16),
+        )
+    };
+
+    pub(super) static VELOCITY_OF_LIGHT: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Meter)),
+            Term {
+                atom: Some(Atom::Second),
+                prefix: None,
+                factor: None,
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            299792458.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 1)
+                .with(Dimension::Time, -1),
+            Some(299792458.0),
+        )
+    };
+
+    pub(super) static PLANCK_CONSTANT: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Joule)),
+            Term::new(None, Some(Atom::Second)),
+        ];
+
+        Definition::new(
+            6.6260755e-34,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -1),
+            Some(6.6260755e-31),
+        )
+    };
+
+    pub(super) static BOLTZMANN_CONSTANT: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Joule)),
+            Term {
+                atom: Some(Atom::Kelvin),
+                prefix: None,
+                factor: None,
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.380658e-23,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Temperature, -1)
+                .with(Dimension::Time, -2),
+            Some(1.380658e-20),
+        )
+    };
+
+    pub(super) static PERMITTIVITY_OF_VACUUM: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Farad)),
+            Term {
+                atom: Some(Atom::Meter),
+                prefix: None,
+                factor: None,
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            8.854187817e-12,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::ElectricCharge, 2)
+                .with(Dimension::Length, -3)
+                .with(Dimension::Mass, -1)
+                .with(Dimension::Time, 2),
+            Some(8.854187817000001e-15),
+        )
+    };
+
+    pub(super) static PERMEABILITY_OF_VACUUM: Definition = {
+        const TERMS: &[Term] = &[
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(4),
+                exponent: None,
+                annotation: None,
+            },
+            Term::new(None, Some(Atom::TheNumberPi)),
+            Term {
+                atom: Some(Atom::TheNumberTenForArbitraryPowersStar),
+                prefix: None,
+                factor: None,
+                exponent: Some(-7),
+                annotation: None,
+            },
+            Term::new(None, Some(Atom::Newton)),
+            Term {
+                atom: Some(Atom::Ampere),
+                prefix: None,
+                factor: None,
+                exponent: Some(-2),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::ElectricCharge, -2)
+                .with(Dimension::Length, 1)
+                .with(Dimension::Mass, 1),
+            Some(0.0012566370614359172),
+        )
+    };
+
+    pub(super) static ELEMENTARY_CHARGE: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Coulomb))];
+
+        Definition::new(
+            1.60217733e-19,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::ElectricCharge, 1),
+            Some(1.60217733e-19),
+        )
+    };
+
+    pub(super) static ELECTRON_MASS: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Gram))];
+
+        Definition::new(
+            9.1093897e-28,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Mass, 1),
+            Some(9.1093897e-28),
+        )
+    };
+
+    pub(super) static PROTON_MASS: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Gram))];
+
+        Definition::new(
+            1.6726231e-24,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Mass, 1),
+            Some(1.6726231e-24),
+        )
+    };
+
+    pub(super) static NEWTONIAN_CONSTANT_OF_GRAVITATION: Definition = {
+        const TERMS: &[Term] = &[
+            Term {
+                atom: Some(Atom::Meter),
+                prefix: None,
+                factor: None,
+                exponent: Some(3),
+                annotation: None,
+            },
+            Term {
+                atom: Some(Atom::Gram),
+                prefix: Some(Prefix::Kilo),
+                factor: None,
+                exponent: Some(-1),
+                annotation: None,
+            },
+            Term {
+                atom: Some(Atom::Second),
+                prefix: None,
+                factor: None,
+                exponent: Some(-2),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            6.67259e-11,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 3)
+                .with(Dimension::Mass, -1)
+                .with(Dimension::Time, -2),
+            Some(6.67259e-14),
+        )
+    };
+
+    pub(super) static STANDARD_ACCELERATION_OF_FREE_FALL: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Meter)),
+            Term {
+                atom: Some(Atom::Second),
+                prefix: None,
+                factor: None,
+                exponent: Some(-2),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            9.80665,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 1)
+                .with(Dimension::Time, -2),
+            Some(9.80665),
+        )
+    };
+
+    pub(super) static STANDARD_ATMOSPHERE: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Pascal))];
+
+        Definition::new(
+            101325.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, -1)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            Some(101325000.0),
+        )
+    };
+
+    pub(super) static LIGHT_YEAR: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::VelocityOfLight)),
+            Term::new(None, Some(Atom::MeanJulianYear)),
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(9460730472580800.0),
+        )
+    };
+
+    pub(super) static GRAM_FORCE: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Gram)),
+            Term::new(None, Some(Atom::StandardAccelerationOfFreeFall)),
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 1)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            Some(9.80665),
+        )
+    };
+
+    pub(super) static POUND_FORCE_AVOIRDUPOIS: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::PoundAvoirdupois)),
+            Term::new(None, Some(Atom::StandardAccelerationOfFreeFall)),
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 1)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            Some(4448.2216152605),
+        )
+    };
+
+    pub(super) static KAYSER: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::Meter),
+            prefix: Some(Prefix::Centi),
+            factor: None,
+            exponent: Some(-1),
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, -1),
+            Some(100.0),
+        )
+    };
+
+    pub(super) static GAL: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(Some(Prefix::Centi), Some(Atom::Meter)),
+            Term {
+                atom: Some(Atom::Second),
+                prefix: None,
+                factor: None,
+                exponent: Some(-2),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 1)
+                .with(Dimension::Time, -2),
+            Some(0.01),
+        )
+    };
+
+    pub(super) static DYNE: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Gram)),
+            Term::new(Some(Prefix::Centi), Some(Atom::Meter)),
+            Term {
+                atom: Some(Atom::Second),
+                prefix: None,
+                factor: None,
+                exponent: Some(-2),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 1)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            Some(0.01),
+        )
+    };
+
+    pub(super) static ERG: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Dyne)),
+            Term::new(Some(Prefix::Centi), Some(Atom::Meter)),
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            Some(0.0001),
+        )
+    };
+
+    pub(super) static POISE: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Dyne)),
+            Term::new(None, Some(Atom::Second)),
+            Term {
+                atom: Some(Atom::Meter),
+                prefix: Some(Prefix::Centi),
+                factor: None,
+                exponent: Some(-2),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, -1)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -1),
+            Some(100.0),
+        )
+    };
+
+    pub(super) static BIOT: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Ampere))];
+
+        Definition::new(
+            10.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::ElectricCharge, 1)
+                .with(Dimension::Time, -1),
+            Some(10.0),
+        )
+    };
+
+    pub(super) static STOKES: Definition = {
+        const TERMS: &[Term] = &[
+            Term {
+                atom: Some(Atom::Meter),
+                prefix: Some(Prefix::Centi),
+                factor: None,
+                exponent: Some(2),
+                annotation: None,
+            },
+            Term {
+                atom: Some(Atom::Second),
+                prefix: None,
+                factor: None,
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 2)
+                .with(Dimension::Time, -1),
+            Some(0.0001),
+        )
+    };
+
+    pub(super) static MAXWELL: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Weber))];
+
+        Definition::new(
+            1e-8,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::ElectricCharge, -1)
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -1),
+            Some(1e-5),
+        )
+    };
+
+    pub(super) static GAUSS: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Tesla))];
+
+        Definition::new(
+            0.0001,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::ElectricCharge, -1)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -1),
+            Some(0.1),
+        )
+    };
+
+    pub(super) static OERSTED: Definition = {
+        const TERMS: &[Term] = &[
+            Term {
+                atom: Some(Atom::TheNumberPi),
+                prefix: None,
+                factor: None,
+                exponent: Some(-1),
+                annotation: None,
+            },
+            Term {
+                atom: Some(Atom::Ampere),
+                prefix: None,
+                factor: None,
+                exponent: Some(-1),
+                annotation: None,
+            },
+            Term::new(None, Some(Atom::Meter)),
+        ];
+
+        Definition::new(
+            250.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::ElectricCharge, -1)
+                .with(Dimension::Length, 1)
+                .with(Dimension::Time, 1),
+            Some(79.57747154594767),
+        )
+    };
+
+    pub(super) static GILBERT: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Oersted)),
+            Term::new(Some(Prefix::Centi), Some(Atom::Meter)),
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::ElectricCharge, -1)
+                .with(Dimension::Length, 2)
+                .with(Dimension::Time, 1),
+            Some(0.7957747154594768),
+        )
+    };
+
+    pub(super) static STILB: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Candela)),
+            Term {
+                atom: Some(Atom::Meter),
+                prefix: Some(Prefix::Centi),
+                factor: None,
+                exponent: Some(-2),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, -2)
+                .with(Dimension::LuminousIntensity, 1),
+            Some(10000.0),
+        )
+    };
+
+    pub(super) static LAMBERT: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Candela)),
+            Term {
+                atom: Some(Atom::Meter),
+                prefix: Some(Prefix::Centi),
+                factor: None,
+                exponent: Some(-2),
+                annotation: None,
+            },
+            Term::new(None, Some(Atom::TheNumberPi)),
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, -2)
+                .with(Dimension::LuminousIntensity, 1),
+            Some(31415.926535897932),
+        )
+    };
+
+    pub(super) static PHOT: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Lux))];
+
+        Definition::new(
+            0.0001,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, -2)
+                .with(Dimension::LuminousIntensity, 1)
+                .with(Dimension::PlaneAngle, 2),
+            Some(0.0001),
+        )
+    };
+
+    pub(super) static CURIE: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Becquerel))];
+
+        Definition::new(
+            37000000000.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Time, -1),
+            Some(37000000000.0),
+        )
+    };
+
+    pub(super) static ROENTGEN: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Coulomb)),
+            Term {
+                atom: Some(Atom::Gram),
+                prefix: Some(Prefix::Kilo),
+                factor: None,
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            0.000258,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::ElectricCharge, 1)
+                .with(Dimension::Mass, -1),
+            Some(2.58e-7),
+        )
+    };
+
+    pub(super) static RADIATION_ABSORBED_DOSE: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Erg)),
+            Term {
+                atom: Some(Atom::Gram),
+                prefix: None,
+                factor: None,
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            100.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 2)
+                .with(Dimension::Time, -2),
+            Some(0.01),
+        )
+    };
+
+    pub(super) static RADIATION_EQUIVALENT_MAN: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::RadiationAbsorbedDose))];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 2)
+                .with(Dimension::Time, -2),
+            Some(0.01),
+        )
+    };
+
+    pub(super) static INCH_INTERNATIONAL: Definition = {
+        const TERMS: &[Term] = &[Term::new(Some(Prefix::Centi), Some(Atom::Meter))];
+
+        Definition::new(
+            2.54,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(0.025400000000000002),
+        )
+    };
+
+    pub(super) static FOOT_INTERNATIONAL: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::InchInternational))];
+
+        Definition::new(
+            12.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(0.3048),
+        )
+    };
+
+    pub(super) static YARD_INTERNATIONAL: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::FootInternational))];
+
+        Definition::new(
+            3.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(0.9144000000000001),
+        )
+    };
+
+    pub(super) static MILE_INTERNATIONAL: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::FootInternational))];
+
+        Definition::new(
+            5280.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(1609.344),
+        )
+    };
+
+    pub(super) static FATHOM_INTERNATIONAL: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::FootInternational))];
+
+        Definition::new(
+            6.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(1.8288000000000002),
+        )
+    };
+
+    pub(super) static NAUTICAL_MILE_INTERNATIONAL: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Meter))];
+
+        Definition::new(
+            1852.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(1852.0),
+        )
+    };
+
+    pub(super) static KNOT_INTERNATIONAL: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::NauticalMileInternational)),
+            Term {
+                atom: Some(Atom::Hour),
+                prefix: None,
+                factor: None,
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 1)
+                .with(Dimension::Time, -1),
+            Some(0.5144444444444445),
+        )
+    };
+
+    pub(super) static SQUARE_INCH_INTERNATIONAL: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::InchInternational),
+            prefix: None,
+            factor: None,
+            exponent: Some(2),
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 2),
+            Some(0.0006451600000000001),
+        )
+    };
+
+    pub(super) static SQUARE_FOOT_INTERNATIONAL: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::FootInternational),
+            prefix: None,
+            factor: None,
+            exponent: Some(2),
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 2),
+            Some(0.09290304),
+        )
+    };
+
+    pub(super) static SQUARE_YARD_INTERNATIONAL: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::YardInternational),
+            prefix: None,
+            factor: None,
+            exponent: Some(2),
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 2),
+            Some(0.8361273600000002),
+        )
+    };
+
+    pub(super) static CUBIC_INCH_INTERNATIONAL: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::InchInternational),
+            prefix: None,
+            factor: None,
+            exponent: Some(3),
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(1.6387064000000003e-5),
+        )
+    };
+
+    pub(super) static CUBIC_FOOT_INTERNATIONAL: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::FootInternational),
+            prefix: None,
+            factor: None,
+            exponent: Some(3),
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(0.028316846592000004),
+        )
+    };
+
+    pub(super) static CUBIC_YARD_INTERNATIONAL: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::YardInternational),
+            prefix: None,
+            factor: None,
+            exponent: Some(3),
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(0.7645548579840002),
+        )
+    };
+
+    pub(super) static BOARD_FOOT_INTERNATIONAL: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::InchInternational),
+            prefix: None,
+            factor: None,
+            exponent: Some(3),
+            annotation: None,
+        }];
+
+        Definition::new(
+            144.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(0.002359737216),
+        )
+    };
+
+    pub(super) static CORD_INTERNATIONAL: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::FootInternational),
+            prefix: None,
+            factor: None,
+            exponent: Some(3),
+            annotation: None,
+        }];
+
+        Definition::new(
+            128.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(3.6245563637760005),
+        )
+    };
+
+    pub(super) static MIL_INTERNATIONAL: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::InchInternational))];
+
+        Definition::new(
+            0.001,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(2.5400000000000004e-5),
+        )
+    };
+
+    pub(super) static CIRCULAR_MIL_INTERNATIONAL: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::TheNumberPi)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(4),
+                exponent: Some(-1),
+                annotation: None,
+            },
+            Term {
+                atom: Some(Atom::MilInternational),
+                prefix: None,
+                factor: None,
+                exponent: Some(-2),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, -2),
+            Some(1217369588.0052204),
+        )
+    };
+
+    pub(super) static HAND_INTERNATIONAL: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::InchInternational))];
+
+        Definition::new(
+            4.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(0.10160000000000001),
+        )
+    };
+
+    pub(super) static FOOT_US: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Meter)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(3937),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1200.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(0.3048006096012192),
+        )
+    };
+
+    pub(super) static YARD_US: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::FootUS))];
+
+        Definition::new(
+            3.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(0.9144018288036575),
+        )
+    };
+
+    pub(super) static INCH_US: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::FootUS)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(12),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(0.025400050800101596),
+        )
+    };
+
+    pub(super) static ROD_US: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::FootUS))];
+
+        Definition::new(
+            16.5,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(5.029210058420117),
+        )
+    };
+
+    pub(super) static GUNTERS_CHAIN_US: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::RodUS))];
+
+        Definition::new(
+            4.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(20.116840233680467),
+        )
+    };
+
+    pub(super) static LINK_FOR_GUNTERS_CHAIN_US: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::GuntersChainUS)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(100),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(0.20116840233680466),
+        )
+    };
+
+    pub(super) static RAMDENS_CHAIN_US: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::FootUS))];
+
+        Definition::new(
+            100.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(30.480060960121918),
+        )
+    };
+
+    pub(super) static LINK_FOR_RAMDENS_CHAIN_US: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::RamdensChainUS)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(100),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(0.3048006096012192),
+        )
+    };
+
+    pub(super) static FATHOM_US: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::FootUS))];
+
+        Definition::new(
+            6.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(1.828803657607315),
+        )
+    };
+
+    pub(super) static FURLONG_US: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::RodUS))];
+
+        Definition::new(
+            40.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(201.16840233680466),
+        )
+    };
+
+    pub(super) static MILE_US: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::FurlongUS))];
+
+        Definition::new(
+            8.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(1609.3472186944373),
+        )
+    };
+
+    pub(super) static ACRE_US: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::RodUS),
+            prefix: None,
+            factor: None,
+            exponent: Some(2),
+            annotation: None,
+        }];
+
+        Definition::new(
+            160.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 2),
+            Some(4046.872609874252),
+        )
+    };
+
+    pub(super) static SQUARE_ROD_US: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::RodUS),
+            prefix: None,
+            factor: None,
+            exponent: Some(2),
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 2),
+            Some(25.292953811714074),
+        )
+    };
+
+    pub(super) static SQUARE_MILE_US: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::MileUS),
+            prefix: None,
+            factor: None,
+            exponent: Some(2),
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 2),
+            Some(2589998.470319521),
+        )
+    };
+
+    pub(super) static SECTION: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::MileUS),
+            prefix: None,
+            factor: None,
+            exponent: Some(2),
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 2),
+            Some(2589998.470319521),
+        )
+    };
+
+    pub(super) static TOWNSHIP: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Section))];
+
+        Definition::new(
+            36.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 2),
+            Some(93239944.93150276),
+        )
+    };
+
+    pub(super) static MIL_US: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::InchUS))];
+
+        Definition::new(
+            0.001,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(2.5400050800101597e-5),
+        )
+    };
+
+    pub(super) static INCH_BRITISH: Definition = {
+        const TERMS: &[Term] = &[Term::new(Some(Prefix::Centi), Some(Atom::Meter))];
+
+        Definition::new(
+            2.539998,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(0.025399980000000003),
+        )
+    };
+
+    pub(super) static FOOT_BRITISH: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::InchBritish))];
+
+        Definition::new(
+            12.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(0.30479976000000003),
+        )
+    };
+
+    pub(super) static ROD_BRITISH: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::FootBritish))];
+
+        Definition::new(
+            16.5,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(5.02919604),
+        )
+    };
+
+    pub(super) static GUNTERS_CHAIN_BRITISH: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::RodBritish))];
+
+        Definition::new(
+            4.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(20.11678416),
+        )
+    };
+
+    pub(super) static LINK_FOR_GUNTERS_CHAIN_BRITISH: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::GuntersChainBritish)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(100),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(0.2011678416),
+        )
+    };
+
+    pub(super) static FATHOM_BRITISH: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::FootBritish))];
+
+        Definition::new(
+            6.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(1.82879856),
+        )
+    };
+
+    pub(super) static PACE_BRITISH: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::FootBritish))];
+
+        Definition::new(
+            2.5,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(0.7619994000000001),
+        )
+    };
+
+    pub(super) static YARD_BRITISH: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::FootBritish))];
+
+        Definition::new(
+            3.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(0.91439928),
+        )
+    };
+
+    pub(super) static MILE_BRITISH: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::FootBritish))];
+
+        Definition::new(
+            5280.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(1609.3427328000002),
+        )
+    };
+
+    pub(super) static NAUTICAL_MILE_BRITISH: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::FootBritish))];
+
+        Definition::new(
+            6080.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(1853.1825408000002),
+        )
+    };
+
+    pub(super) static KNOT_BRITISH: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::NauticalMileBritish)),
+            Term {
+                atom: Some(Atom::Hour),
+                prefix: None,
+                factor: None,
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 1)
+                .with(Dimension::Time, -1),
+            Some(0.5147729280000001),
+        )
+    };
+
+    pub(super) static ACRE_BRITISH: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::YardBritish),
+            prefix: None,
+            factor: None,
+            exponent: Some(2),
+            annotation: None,
+        }];
+
+        Definition::new(
+            4840.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 2),
+            Some(4046.850049400269),
+        )
+    };
+
+    pub(super) static QUEEN_ANNES_WINE_GALLON_US: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::InchInternational),
+            prefix: None,
+            factor: None,
+            exponent: Some(3),
+            annotation: None,
+        }];
+
+        Definition::new(
+            231.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(0.0037854117840000006),
+        )
+    };
+
+    pub(super) static BARREL_US: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::QueenAnnesWineGallonUS))];
+
+        Definition::new(
+            42.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(0.158987294928),
+        )
+    };
+
+    pub(super) static QUART_US: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::QueenAnnesWineGallonUS)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(4),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(0.0009463529460000001),
+        )
+    };
+
+    pub(super) static PINT_US: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::QuartUS)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(2),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(0.00047317647300000007),
+        )
+    };
+
+    pub(super) static GILL_US: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::PintUS)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(4),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(0.00011829411825000002),
+        )
+    };
+
+    pub(super) static FLUID_OUNCE_US: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::GillUS)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(4),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(2.9573529562500005e-5),
+        )
+    };
+
+    pub(super) static FLUID_DRAM_US: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::FluidOunceUS)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(8),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(3.6966911953125006e-6),
+        )
+    };
+
+    pub(super) static MINIM_US: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::FluidDramUS)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(60),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(6.1611519921875e-8),
+        )
+    };
+
+    pub(super) static CORD_US: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::FootInternational),
+            prefix: None,
+            factor: None,
+            exponent: Some(3),
+            annotation: None,
+        }];
+
+        Definition::new(
+            128.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(3.6245563637760005),
+        )
+    };
+
+    pub(super) static BUSHEL_US: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::InchInternational),
+            prefix: None,
+            factor: None,
+            exponent: Some(3),
+            annotation: None,
+        }];
+
+        Definition::new(
+            2150.42,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(0.03523907016688001),
+        )
+    };
+
+    pub(super) static HISTORICAL_WINCHESTER_GALLON: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::BushelUS)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(8),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(0.004404883770860001),
+        )
+    };
+
+    pub(super) static PECK_US: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::BushelUS)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(4),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(0.008809767541720002),
+        )
+    };
+
+    pub(super) static DRY_QUART_US: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::PeckUS)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(8),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(0.0011012209427150002),
+        )
+    };
+
+    pub(super) static DRY_PINT_US: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::DryQuartUS)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(2),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(0.0005506104713575001),
+        )
+    };
+
+    pub(super) static TABLESPOON_US: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::FluidOunceUS)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(2),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(1.4786764781250002e-5),
+        )
+    };
+
+    pub(super) static TEASPOON_US: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::TablespoonUS)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(3),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(4.9289215937500005e-6),
+        )
+    };
+
+    pub(super) static CUP_US: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::TablespoonUS))];
+
+        Definition::new(
+            16.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(0.00023658823650000004),
+        )
+    };
+
+    pub(super) static METRIC_FLUID_OUNCE: Definition = {
+        const TERMS: &[Term] = &[Term::new(Some(Prefix::Milli), Some(Atom::LiterSecondary))];
+
+        Definition::new(
+            30.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(3.0000000000000004e-5),
+        )
+    };
+
+    pub(super) static METRIC_CUP: Definition = {
+        const TERMS: &[Term] = &[Term::new(Some(Prefix::Milli), Some(Atom::LiterSecondary))];
+
+        Definition::new(
+            240.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(0.00024000000000000003),
+        )
+    };
+
+    pub(super) static METRIC_TEASPOON: Definition = {
+        const TERMS: &[Term] = &[Term::new(Some(Prefix::Milli), Some(Atom::LiterSecondary))];
+
+        Definition::new(
+            5.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(5.000000000000001e-6),
+        )
+    };
+
+    pub(super) static METRIC_TABLESPOON: Definition = {
+        const TERMS: &[Term] = &[Term::new(Some(Prefix::Milli), Some(Atom::LiterSecondary))];
+
+        Definition::new(
+            15.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(1.5000000000000002e-5),
+        )
+    };
+
+    pub(super) static GALLON_BRITISH: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Liter))];
+
+        Definition::new(
+            4.54609,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(0.004546090000000002),
+        )
+    };
+
+    pub(super) static PECK_BRITISH: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::GallonBritish))];
+
+        Definition::new(
+            2.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(0.009092180000000004),
+        )
+    };
+
+    pub(super) static BUSHEL_BRITISH: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::PeckBritish))];
+
+        Definition::new(
+            4.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(0.036368720000000014),
+        )
+    };
+
+    pub(super) static QUART_BRITISH: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::GallonBritish)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(4),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(0.0011365225000000004),
+        )
+    };
+
+    pub(super) static PINT_BRITISH: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::QuartBritish)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(2),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(0.0005682612500000002),
+        )
+    };
+
+    pub(super) static GILL_BRITISH: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::PintBritish)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(4),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(0.00014206531250000006),
+        )
+    };
+
+    pub(super) static FLUID_OUNCE_BRITISH: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::GillBritish)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(5),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(2.841306250000001e-5),
+        )
+    };
+
+    pub(super) static FLUID_DRAM_BRITISH: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::FluidOunceBritish)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(8),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(3.5516328125000015e-6),
+        )
+    };
+
+    pub(super) static MINIM_BRITISH: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::FluidDramBritish)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(60),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(5.9193880208333354e-8),
+        )
+    };
+
+    pub(super) static GRAIN: Definition = {
+        const TERMS: &[Term] = &[Term::new(Some(Prefix::Milli), Some(Atom::Gram))];
+
+        Definition::new(
+            64.79891,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Mass, 1),
+            Some(0.06479891),
+        )
+    };
+
+    pub(super) static POUND_AVOIRDUPOIS: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Grain))];
+
+        Definition::new(
+            7000.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Mass, 1),
+            Some(453.59237),
+        )
+    };
+
+    pub(super) static OUNCE_AVOIRDUPOIS: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::PoundAvoirdupois)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(16),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Mass, 1),
+            Some(28.349523125),
+        )
+    };
+
+    pub(super) static DRAM_AVOIRDUPOIS: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::OunceAvoirdupois)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(16),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Mass, 1),
+            Some(1.7718451953125),
+        )
+    };
+
+    pub(super) static SHORT_HUNDREDWEIGHT_AVOIRDUPOIS: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::PoundAvoirdupois))];
+
+        Definition::new(
+            100.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Mass, 1),
+            Some(45359.237),
+        )
+    };
+
+    pub(super) static LONG_HUNDERDWEIGHT_AVOIRDUPOIS: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::PoundAvoirdupois))];
+
+        Definition::new(
+            112.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Mass, 1),
+            Some(50802.345440000005),
+        )
+    };
+
+    pub(super) static SHORT_TON_AVOIRDUPOIS: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::ShortHundredweightAvoirdupois))];
+
+        Definition::new(
+            20.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Mass, 1),
+            Some(907184.74),
+        )
+    };
+
+    pub(super) static LONG_TON_AVOIRDUPOIS: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::LongHunderdweightAvoirdupois))];
+
+        Definition::new(
+            20.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Mass, 1),
+            Some(1016046.9088000001),
+        )
+    };
+
+    pub(super) static STONE_AVOIRDUPOIS: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::PoundAvoirdupois))];
+
+        Definition::new(
+            14.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Mass, 1),
+            Some(6350.293180000001),
+        )
+    };
+
+    pub(super) static PENNYWEIGHT_TROY: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Grain))];
+
+        Definition::new(
+            24.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Mass, 1),
+            Some(1.5551738400000001),
+        )
+    };
+
+    pub(super) static OUNCE_TROY: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::PennyweightTroy))];
+
+        Definition::new(
+            20.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Mass, 1),
+            Some(31.103476800000003),
+        )
+    };
+
+    pub(super) static POUND_TROY: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::OunceTroy))];
+
+        Definition::new(
+            12.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Mass, 1),
+            Some(373.2417216),
+        )
+    };
+
+    pub(super) static SCRUPLE_APOTHECARIES: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Grain))];
+
+        Definition::new(
+            20.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Mass, 1),
+            Some(1.2959782),
+        )
+    };
+
+    pub(super) static DRAM_APOTHECARIES: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::ScrupleApothecaries))];
+
+        Definition::new(
+            3.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Mass, 1),
+            Some(3.8879346),
+        )
+    };
+
+    pub(super) static OUNCE_APOTHECARIES: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::DramApothecaries))];
+
+        Definition::new(
+            8.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Mass, 1),
+            Some(31.1034768),
+        )
+    };
+
+    pub(super) static POUND_APOTHECARIES: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::OunceApothecaries))];
+
+        Definition::new(
+            12.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Mass, 1),
+            Some(373.2417216),
+        )
+    };
+
+    pub(super) static METRIC_OUNCE: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Gram))];
+
+        Definition::new(
+            28.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Mass, 1),
+            Some(28.0),
+        )
+    };
+
+    pub(super) static LINE: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::InchInternational)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(12),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(0.002116666666666667),
+        )
+    };
+
+    pub(super) static POINT: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Line)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(6),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(0.0003527777777777778),
+        )
+    };
+
+    pub(super) static PICA: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Point))];
+
+        Definition::new(
+            12.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(0.004233333333333334),
+        )
+    };
+
+    pub(super) static PRINTERS_POINT: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::InchInternational))];
+
+        Definition::new(
+            0.013837,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(0.00035145980000000004),
+        )
+    };
+
+    pub(super) static PRINTERS_PICA: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::PrintersPoint))];
+
+        Definition::new(
+            12.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(0.004217517600000001),
+        )
+    };
+
+    pub(super) static PIED: Definition = {
+        const TERMS: &[Term] = &[Term::new(Some(Prefix::Centi), Some(Atom::Meter))];
+
+        Definition::new(
+            32.48,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(0.3248),
+        )
+    };
+
+    pub(super) static POUCE: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Pied)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(12),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(0.027066666666666662),
+        )
+    };
+
+    pub(super) static LIGNE: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Pouce)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(12),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(0.002255555555555555),
+        )
+    };
+
+    pub(super) static DIDOT: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Ligne)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(6),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(0.0003759259259259258),
+        )
+    };
+
+    pub(super) static CICERO: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Didot))];
+
+        Definition::new(
+            12.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(0.00451111111111111),
+        )
+    };
+
+    pub(super) static DEGREE_FAHRENHEIT: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Kelvin)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(9),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            5.0,
+            TERMS,
+            Some(FunctionSet {
+                convert_from: |value: f64| 9.0 * value / 5.0 - 459.67,
+                convert_to: |value: f64| 5.0 / 9.0 * (value + 459.67),
+            }),
+            Composition::EMPTY.with(Dimension::Temperature, 1),
+            None,
+        )
+    };
+
+    pub(super) static DEGREE_RANKINE: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Kelvin)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(9),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            5.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Temperature, 1),
+            Some(0.5555555555555556),
+        )
+    };
+
+    pub(super) static DEGREE_REAUMUR: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Kelvin)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(4),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            5.0,
+            TERMS,
+            Some(FunctionSet {
+                convert_from: |value: f64| (value - 273.15) * 0.8,
+                convert_to: |value: f64| (value / 0.8) + 273.15,
+            }),
+            Composition::EMPTY.with(Dimension::Temperature, 1),
+            None,
+        )
+    };
+
+    pub(super) static CALORIE_AT15_C: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Joule))];
+
+        Definition::new(
+            4.1858,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            Some(4185.8),
+        )
+    };
+
+    pub(super) static CALORIE_AT20_C: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Joule))];
+
+        Definition::new(
+            4.1819,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            Some(4181.9),
+        )
+    };
+
+    pub(super) static MEAN_CALORIE: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Joule))];
+
+        Definition::new(
+            4.19002,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            Some(4190.0199999999995),
+        )
+    };
+
+    pub(super) static INTERNATIONAL_TABLE_CALORIE: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Joule))];
+
+        Definition::new(
+            4.1868,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            Some(4186.8),
+        )
+    };
+
+    pub(super) static THERMOCHEMICAL_CALORIE: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Joule))];
+
+        Definition::new(
+            4.184,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            Some(4184.0),
+        )
+    };
+
+    pub(super) static CALORIE: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::ThermochemicalCalorie))];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            Some(4184.0),
+        )
+    };
+
+    pub(super) static NUTRITION_LABEL_CALORIES: Definition = {
+        const TERMS: &[Term] = &[Term::new(
+            Some(Prefix::Kilo),
+            Some(Atom::ThermochemicalCalorie),
+        )];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            Some(4184000.0),
+        )
+    };
+
+    pub(super) static BRITISH_THERMAL_UNIT_AT39_F: Definition = {
+        const TERMS: &[Term] = &[Term::new(Some(Prefix::Kilo), Some(Atom::Joule))];
+
+        Definition::new(
+            1.05967,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            Some(1059670.0),
+        )
+    };
+
+    pub(super) static BRITISH_THERMAL_UNIT_AT59_F: Definition = {
+        const TERMS: &[Term] = &[Term::new(Some(Prefix::Kilo), Some(Atom::Joule))];
+
+        Definition::new(
+            1.0548,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            Some(1054800.0),
+        )
+    };
+
+    pub(super) static BRITISH_THERMAL_UNIT_AT60_F: Definition = {
+        const TERMS: &[Term] = &[Term::new(Some(Prefix::Kilo), Some(Atom::Joule))];
+
+        Definition::new(
+            1.05468,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            Some(1054680.0),
+        )
+    };
+
+    pub(super) static MEAN_BRITISH_THERMAL_UNIT: Definition = {
+        const TERMS: &[Term] = &[Term::new(Some(Prefix::Kilo), Some(Atom::Joule))];
+
+        Definition::new(
+            1.05587,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            Some(1055870.0),
+        )
+    };
+
+    pub(super) static INTERNATIONAL_TABLE_BRITISH_THERMAL_UNIT: Definition = {
+        const TERMS: &[Term] = &[Term::new(Some(Prefix::Kilo), Some(Atom::Joule))];
+
+        Definition::new(
+            1.05505585262,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            Some(1055055.85262),
+        )
+    };
+
+    pub(super) static THERMOCHEMICAL_BRITISH_THERMAL_UNIT: Definition = {
+        const TERMS: &[Term] = &[Term::new(Some(Prefix::Kilo), Some(Atom::Joule))];
+
+        Definition::new(
+            1.05435,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            Some(1054350.0),
+        )
+    };
+
+    pub(super) static BRITISH_THERMAL_UNIT: Definition = {
+        const TERMS: &[Term] = &[Term::new(
+            None,
+            Some(Atom::ThermochemicalBritishThermalUnit),
+        )];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            Some(1054350.0),
+        )
+    };
+
+    pub(super) static HORSEPOWER: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::FootInternational)),
+            Term::new(None, Some(Atom::PoundForceAvoirdupois)),
+            Term {
+                atom: Some(Atom::Second),
+                prefix: None,
+                factor: None,
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            550.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -3),
+            Some(745699.8715822703),
+        )
+    };
+
+    pub(super) static TEX: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Gram)),
+            Term {
+                atom: Some(Atom::Meter),
+                prefix: Some(Prefix::Kilo),
+                factor: None,
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, -1)
+                .with(Dimension::Mass, 1),
+            Some(0.001),
+        )
+    };
+
+    pub(super) static DENIER: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Gram)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(9),
+                exponent: Some(-1),
+                annotation: None,
+            },
+            Term::new(Some(Prefix::Kilo), Some(Atom::Meter)),
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 1)
+                .with(Dimension::Mass, 1),
+            Some(111.1111111111111),
+        )
+    };
+
+    pub(super) static METER_OF_WATER_COLUMN: Definition = {
+        const TERMS: &[Term] = &[Term::new(Some(Prefix::Kilo), Some(Atom::Pascal))];
+
+        Definition::new(
+            9.80665,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, -1)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            Some(9806650.0),
+        )
+    };
+
+    pub(super) static METER_OF_MERCURY_COLUMN: Definition = {
+        const TERMS: &[Term] = &[Term::new(Some(Prefix::Kilo), Some(Atom::Pascal))];
+
+        Definition::new(
+            133.322,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, -1)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            Some(133322000.0),
+        )
+    };
+
+    pub(super) static INCH_OF_WATER_COLUMN: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::MeterOfWaterColumn)),
+            Term::new(None, Some(Atom::InchInternational)),
+            Term {
+                atom: Some(Atom::Meter),
+                prefix: None,
+                factor: None,
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, -1)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            Some(249088.91000000003),
+        )
+    };
+
+    pub(super) static INCH_OF_MERCURY_COLUMN: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::MeterOfMercuryColumn)),
+            Term::new(None, Some(Atom::InchInternational)),
+            Term {
+                atom: Some(Atom::Meter),
+                prefix: None,
+                factor: None,
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, -1)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            Some(3386378.8000000003),
+        )
+    };
+
+    pub(super) static PERIPHERAL_VASCULAR_RESISTANCE_UNIT: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(Some(Prefix::Milli), Some(Atom::MeterOfMercuryColumn)),
+            Term::new(None, Some(Atom::Second)),
+            Term {
+                atom: Some(Atom::Liter),
+                prefix: Some(Prefix::Milli),
+                factor: None,
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, -4)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -1),
+            Some(133321999999.99998),
+        )
+    };
+
+    pub(super) static WOOD_UNIT: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(Some(Prefix::Milli), Some(Atom::MeterOfMercuryColumn)),
+            Term::new(None, Some(Atom::Minute)),
+            Term {
+                atom: Some(Atom::LiterSecondary),
+                prefix: None,
+                factor: None,
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, -4)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -1),
+            Some(7999319999.999998),
+        )
+    };
+
+    pub(super) static DIOPTER: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::Meter),
+            prefix: None,
+            factor: None,
+            exponent: Some(-1),
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, -1),
+            Some(1.0),
+        )
+    };
+
+    pub(super) static PRISM_DIOPTER: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Radian))];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            Some(FunctionSet {
+                convert_from: |value: f64| (value / 100.0).atan(),
+                convert_to: |value: f64| value.tan() * 100.0,
+            }),
+            Composition::EMPTY.with(Dimension::PlaneAngle, 1),
+            None,
+        )
+    };
+
+    pub(super) static PERCENT_OF_SLOPE: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Degree))];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            Some(FunctionSet {
+                convert_from: |value: f64| (value / 100.0).atan(),
+                convert_to: |value: f64| value.tan() * 100.0,
+            }),
+            Composition::EMPTY.with(Dimension::PlaneAngle, 1),
+            None,
+        )
+    };
+
+    pub(super) static MESH_INTERNATIONAL: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::InchInternational),
+            prefix: None,
+            factor: None,
+            exponent: Some(-1),
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, -1),
+            Some(39.370078740157474),
+        )
+    };
+
+    pub(super) static CHARRIERE: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(Some(Prefix::Milli), Some(Atom::Meter)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(3),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(0.0003333333333333333),
+        )
+    };
+
+    pub(super) static DROP: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(Some(Prefix::Milli), Some(Atom::Liter)),
+            Term {
+                atom: None,
+                prefix: None,
+                factor: Some(20),
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(5.000000000000001e-8),
+        )
+    };
+
+    pub(super) static HOUNSFIELD_UNIT: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static METABOLIC_EQUIVALENT: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(Some(Prefix::Milli), Some(Atom::LiterSecondary)),
+            Term {
+                atom: Some(Atom::Minute),
+                prefix: None,
+                factor: None,
+                exponent: Some(-1),
+                annotation: None,
+            },
+            Term::new(Some(Prefix::Kilo), Some(Atom::Gram)),
+        ];
+
+        Definition::new(
+            3.5,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 3)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -1),
+            Some(5.8333333333333346e-5),
+        )
+    };
+
+    pub(super) static HOMEOPATHIC_POTENCY_OF_DECIMAL_SERIES_RETIRED: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            Some(FunctionSet {
+                convert_from: |value: f64| -value.log10(),
+                convert_to: |value: f64| 10_f64.powf(-value),
+            }),
+            Composition::EMPTY,
+            None,
+        )
+    };
+
+    pub(super) static HOMEOPATHIC_POTENCY_OF_CENTESIMAL_SERIES_RETIRED: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            Some(FunctionSet {
+                convert_from: |value: f64| -value.ln() / 100_f64.ln(),
+                convert_to: |value: f64| 100_f64.powf(-value),
+            }),
+            Composition::EMPTY,
+            None,
+        )
+    };
+
+    pub(super) static HOMEOPATHIC_POTENCY_OF_MILLESIMAL_SERIES_RETIRED: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            Some(FunctionSet {
+                convert_from: |value: f64| -value.ln() / 1_000_f64.ln(),
+                convert_to: |value: f64| 1_000_f64.powf(-value),
+            }),
+            Composition::EMPTY,
+            None,
+        )
+    };
+
+    pub(super) static HOMEOPATHIC_POTENCY_OF_QUINTAMILLESIMAL_SERIES_RETIRED: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            Some(FunctionSet {
+                convert_from: |value: f64| -value.ln() / 50_000_f64.ln(),
+                convert_to: |value: f64| 50_000_f64.powf(-value),
+            }),
+            Composition::EMPTY,
+            None,
+        )
+    };
+
+    pub(super) static HOMEOPATHIC_POTENCY_OF_DECIMAL_HAHNEMANNIAN_SERIES: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static HOMEOPATHIC_POTENCY_OF_CENTESIMAL_HAHNEMANNIAN_SERIES: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static HOMEOPATHIC_POTENCY_OF_MILLESIMAL_HAHNEMANNIAN_SERIES: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static HOMEOPATHIC_POTENCY_OF_QUINTAMILLESIMAL_HAHNEMANNIAN_SERIES: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static HOMEOPATHIC_POTENCY_OF_DECIMAL_KORSAKOVIAN_SERIES: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static HOMEOPATHIC_POTENCY_OF_CENTESIMAL_KORSAKOVIAN_SERIES: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static HOMEOPATHIC_POTENCY_OF_MILLESIMAL_KORSAKOVIAN_SERIES: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static HOMEOPATHIC_POTENCY_OF_QUINTAMILLESIMAL_KORSAKOVIAN_SERIES: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static EQUIVALENTS: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Mole))];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(6.0221367e23))
+    };
+
+    pub(super) static OSMOLE: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Mole))];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(6.0221367e23))
+    };
+
+    pub(super) static PH: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Mole)),
+            Term {
+                atom: Some(Atom::Liter),
+                prefix: None,
+                factor: None,
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            Some(FunctionSet {
+                convert_from: |value: f64| 10.0_f64.powf(-value),
+                convert_to: |value: f64| -value.log10(),
+            }),
+            Composition::EMPTY.with(Dimension::Length, -3),
+            None,
+        )
+    };
+
+    pub(super) static GRAM_PERCENT: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Gram)),
+            Term {
+                atom: Some(Atom::Liter),
+                prefix: Some(Prefix::Deci),
+                factor: None,
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, -3)
+                .with(Dimension::Mass, 1),
+            Some(9999.999999999996),
+        )
+    };
+
+    pub(super) static SVEDBERG_UNIT: Definition = {
+        const TERMS: &[Term] = &[
+            Term {
+                atom: Some(Atom::TheNumberTenForArbitraryPowersStar),
+                prefix: None,
+                factor: None,
+                exponent: Some(-13),
+                annotation: None,
+            },
+            Term::new(None, Some(Atom::Second)),
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Time, 1),
+            Some(1e-13),
+        )
+    };
+
+    pub(super) static HIGH_POWER_FIELD: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static LOW_POWER_FIELD: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(100.0, TERMS, None, Composition::EMPTY, Some(100.0))
+    };
+
+    pub(super) static KATAL: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::Mole)),
+            Term {
+                atom: Some(Atom::Second),
+                prefix: None,
+                factor: None,
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Time, -1),
+            Some(6.0221367e23),
+        )
+    };
+
+    pub(super) static UNIT: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(Some(Prefix::Micro), Some(Atom::Mole)),
+            Term {
+                atom: Some(Atom::Minute),
+                prefix: None,
+                factor: None,
+                exponent: Some(-1),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Time, -1),
+            Some(1.00368945e16),
+        )
+    };
+
+    pub(super) static INTERNATIONAL_UNIT: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static INTERNATIONAL_UNIT_SECONDARY: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::InternationalUnit))];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static ARBITRARY_UNIT: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static UNITED_STATES_PHARMACOPEIA_UNIT: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static GPL_UNIT: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static MPL_UNIT: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static APL_UNIT: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static BETHESDA_UNIT: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static ANTI_FACTOR_XA_UNIT: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static TODD_UNIT: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static DYE_UNIT: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static SOMOGYI_UNIT: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static BODANSKY_UNIT: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static KING_ARMSTRONG_UNIT: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static KUNKEL_UNIT: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static MAC_LAGAN_UNIT: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static TUBERCULIN_UNIT: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static CELL_CULTURE_INFECTIOUS_DOSE: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static TISSUE_CULTURE_INFECTIOUS_DOSE: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static EMBRYO_INFECTIOUS_DOSE: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static PLAQUE_FORMING_UNITS: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static FOCUS_FORMING_UNITS: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static COLONY_FORMING_UNITS: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static INDEX_OF_REACTIVITY: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static BIOEQUIVALENT_ALLERGEN_UNIT: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static ALLERGEN_UNIT: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static ALLERGEN_UNIT_FOR_AMBROSIA_ARTEMISIIFOLIA: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static PROTEIN_NITROGEN_UNIT: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static LIMIT_OF_FLOCCULATION: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static D_ANTIGEN_UNIT: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static FIBRINOGEN_EQUIVALENT_UNIT: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static ELISA_UNIT: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static EHRLICH_UNIT: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static NEPER: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            Some(FunctionSet {
+                convert_from: |value: f64| value.ln(),
+                convert_to: |value: f64| value.exp(),
+            }),
+            Composition::EMPTY,
+            None,
+        )
+    };
+
+    pub(super) static BEL: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            Some(FunctionSet {
+                convert_from: |value: f64| value.log10(),
+                convert_to: |value: f64| 10_f64.powf(value),
+            }),
+            Composition::EMPTY,
+            None,
+        )
+    };
+
+    pub(super) static BEL_SOUND_PRESSURE: Definition = {
+        const TERMS: &[Term] = &[
+            Term {
+                atom: Some(Atom::TheNumberTenForArbitraryPowersStar),
+                prefix: None,
+                factor: None,
+                exponent: Some(-5),
+                annotation: None,
+            },
+            Term::new(None, Some(Atom::Pascal)),
+        ];
+
+        Definition::new(
+            2.0,
+            TERMS,
+            Some(FunctionSet {
+                convert_from: |value: f64| 2.0 * value.log10(),
+                convert_to: |value: f64| 10_f64.powf(value / 2.0),
+            }),
+            Composition::EMPTY
+                .with(Dimension::Length, -1)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            None,
+        )
+    };
+
+    pub(super) static BEL_VOLT: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Volt))];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            Some(FunctionSet {
+                convert_from: |value: f64| 2.0 * value.log10(),
+                convert_to: |value: f64| 10_f64.powf(value / 2.0),
+            }),
+            Composition::EMPTY
+                .with(Dimension::ElectricCharge, -1)
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            None,
+        )
+    };
+
+    pub(super) static BEL_MILLIVOLT: Definition = {
+        const TERMS: &[Term] = &[Term::new(Some(Prefix::Milli), Some(Atom::Volt))];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            Some(FunctionSet {
+                convert_from: |value: f64| 2.0 * value.log10(),
+                convert_to: |value: f64| 10_f64.powf(value / 2.0),
+            }),
+            Composition::EMPTY
+                .with(Dimension::ElectricCharge, -1)
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            None,
+        )
+    };
+
+    pub(super) static BEL_MICROVOLT: Definition = {
+        const TERMS: &[Term] = &[Term::new(Some(Prefix::Micro), Some(Atom::Volt))];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            Some(FunctionSet {
+                convert_from: |value: f64| 2.0 * value.log10(),
+                convert_to: |value: f64| 10_f64.powf(value / 2.0),
+            }),
+            Composition::EMPTY
+                .with(Dimension::ElectricCharge, -1)
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            None,
+        )
+    };
+
+    pub(super) static BEL10_NANOVOLT: Definition = {
+        const TERMS: &[Term] = &[Term::new(Some(Prefix::Nano), Some(Atom::Volt))];
+
+        Definition::new(
+            10.0,
+            TERMS,
+            Some(FunctionSet {
+                convert_from: |value: f64| 2.0 * value.log10(),
+                convert_to: |value: f64| 10_f64.powf(value / 2.0),
+            }),
+            Composition::EMPTY
+                .with(Dimension::ElectricCharge, -1)
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            None,
+        )
+    };
+
+    pub(super) static BEL_WATT: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Watt))];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            Some(FunctionSet {
+                convert_from: |value: f64| value.log10(),
+                convert_to: |value: f64| 10_f64.powf(value),
+            }),
+            Composition::EMPTY
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -3),
+            None,
+        )
+    };
+
+    pub(super) static BEL_KILOWATT: Definition = {
+        const TERMS: &[Term] = &[Term::new(Some(Prefix::Kilo), Some(Atom::Watt))];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            Some(FunctionSet {
+                convert_from: |value: f64| value.log10(),
+                convert_to: |value: f64| 10_f64.powf(value),
+            }),
+            Composition::EMPTY
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -3),
+            None,
+        )
+    };
+
+    pub(super) static STERE: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::Meter),
+            prefix: None,
+            factor: None,
+            exponent: Some(3),
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 3),
+            Some(1.0),
+        )
+    };
+
+    pub(super) static ANGSTROM: Definition = {
+        const TERMS: &[Term] = &[Term::new(Some(Prefix::Nano), Some(Atom::Meter))];
+
+        Definition::new(
+            0.1,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(1.0000000000000002e-10),
+        )
+    };
+
+    pub(super) static BARN: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::Meter),
+            prefix: Some(Prefix::Femto),
+            factor: None,
+            exponent: Some(2),
+            annotation: None,
+        }];
+
+        Definition::new(
+            100.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 2),
+            Some(1.0000000000000001e-28),
+        )
+    };
+
+    pub(super) static TECHNICAL_ATMOSPHERE: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(Some(Prefix::Kilo), Some(Atom::GramForce)),
+            Term {
+                atom: Some(Atom::Meter),
+                prefix: Some(Prefix::Centi),
+                factor: None,
+                exponent: Some(-2),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, -1)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            Some(98066500.0),
+        )
+    };
+
+    pub(super) static MHO: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Siemens))];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::ElectricCharge, 2)
+                .with(Dimension::Length, -2)
+                .with(Dimension::Mass, -1)
+                .with(Dimension::Time, 1),
+            Some(0.001),
+        )
+    };
+
+    pub(super) static POUND_PER_SQARE_INCH: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::PoundForceAvoirdupois)),
+            Term {
+                atom: Some(Atom::InchInternational),
+                prefix: None,
+                factor: None,
+                exponent: Some(-2),
+                annotation: None,
+            },
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, -1)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -2),
+            Some(6894757.293168359),
+        )
+    };
+
+    pub(super) static CIRCLE: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::TheNumberPi)),
+            Term::new(None, Some(Atom::Radian)),
+        ];
+
+        Definition::new(
+            2.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::PlaneAngle, 1),
+            Some(6.283185307179586),
+        )
+    };
+
+    pub(super) static SPERE: Definition = {
+        const TERMS: &[Term] = &[
+            Term::new(None, Some(Atom::TheNumberPi)),
+            Term::new(None, Some(Atom::Steradian)),
+        ];
+
+        Definition::new(
+            4.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::PlaneAngle, 2),
+            Some(12.566370614359172),
+        )
+    };
+
+    pub(super) static METRIC_CARAT: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Gram))];
+
+        Definition::new(
+            0.2,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Mass, 1),
+            Some(0.2),
+        )
+    };
+
+    pub(super) static CARAT_OF_GOLD_ALLOYS: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(24),
+            exponent: Some(-1),
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY,
+            Some(0.041666666666666664),
+        )
+    };
+
+    pub(super) static SMOOT: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::InchInternational))];
+
+        Definition::new(
+            67.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Length, 1),
+            Some(1.7018000000000002),
+        )
+    };
+
+    pub(super) static METER_PER_SQUARE_SECONDS_PER_SQUARE_ROOT_OF_HERTZ: Definition = {
+        const TERMS: &[Term] = &[
+            Term {
+                atom: Some(Atom::Meter),
+                prefix: None,
+                factor: None,
+                exponent: Some(2),
+                annotation: None,
+            },
+            Term {
+                atom: Some(Atom::Second),
+                prefix: None,
+                factor: None,
+                exponent: Some(-4),
+                annotation: None,
+            },
+            Term::new(None, Some(Atom::Hertz)),
+        ];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            Some(FunctionSet {
+                convert_from: |value: f64| value.sqrt(),
+                convert_to: |value: f64| value * value,
+            }),
+            Composition::EMPTY
+                .with(Dimension::Length, 2)
+                .with(Dimension::Time, -5),
+            None,
+        )
+    };
+
+    pub(super) static BIT_LOGARITHMUS_DUALIS: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            Some(FunctionSet {
+                convert_from: |value: f64| value.log2(),
+                convert_to: |value: f64| value.exp2(),
+            }),
+            Composition::EMPTY,
+            None,
+        )
+    };
+
+    pub(super) static BIT: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: None,
+            prefix: None,
+            factor: Some(1),
+            exponent: None,
+            annotation: None,
+        }];
+
+        Definition::new(1.0, TERMS, None, Composition::EMPTY, Some(1.0))
+    };
+
+    pub(super) static BYTE: Definition = {
+        const TERMS: &[Term] = &[Term::new(None, Some(Atom::Bit))];
+
+        Definition::new(8.0, TERMS, None, Composition::EMPTY, Some(8.0))
+    };
+
+    pub(super) static BAUD: Definition = {
+        const TERMS: &[Term] = &[Term {
+            atom: Some(Atom::Second),
+            prefix: None,
+            factor: None,
+            exponent: Some(-1),
+            annotation: None,
+        }];
+
+        Definition::new(
+            1.0,
+            TERMS,
+            None,
+            Composition::EMPTY.with(Dimension::Time, -1),
+            Some(1.0),
+        )
+    };
+}
@@ -8,6 +8,42 @@ use crate::parser::{
 use crate::ucum_unit::UcumUnit;
 use approx::{assert_relative_eq, assert_ulps_eq};
 
+// Values that depend on the active `ConstantProfile`: one for each atom that `codata-2018`
+// overrides, plus `eV`, which is defined in terms of `[e]`.
+#[cfg(not(feature = "codata-2018"))]
+mod profile {
+    pub(super) const AVOGADRO_DEFINITION: f64 = 6.022_136_7;
+    pub(super) const AVOGADRO_SCALAR: f64 = 6.022_136_7e+23;
+    pub(super) const BOLTZMANN_CONSTANT_SCALAR: f64 = 1.380_658e-20;
+    pub(super) const ELECTRON_MASS_SCALAR: f64 = 9.109_389_7e-28;
+    pub(super) const ELECTRONVOLT_SCALAR: f64 = 1.602_177_33e-16;
+    pub(super) const ELEMENTARY_CHARGE_SCALAR: f64 = 1.602_177_33e-19;
+    pub(super) const NEWTONIAN_CONSTANT_OF_GRAVITATION_SCALAR: f64 = 6.672_59e-14;
+    pub(super) const PERMEABILITY_OF_VACUUM_SCALAR: f64 = 0.001_256_637_061_435_917_5;
+    pub(super) const PERMITTIVITY_OF_VACUUM_SCALAR: f64 = 8.854_187_817e-15;
+    pub(super) const PLANCK_CONSTANT_SCALAR: f64 = 6.626_075_5e-31;
+    pub(super) const PROTON_MASS_SCALAR: f64 = 1.672_623_1e-24;
+    pub(super) const UNIFIED_ATOMIC_MASS_UNIT_SCALAR: f64 = 1.660_540_2e-24;
+}
+
+#[cfg(feature = "codata-2018")]
+mod profile {
+    pub(super) const AVOGADRO_DEFINITION: f64 = 6.022_140_76;
+    pub(super) const AVOGADRO_SCALAR: f64 = 6.022_140_76e+23;
+    pub(super) const BOLTZMANN_CONSTANT_SCALAR: f64 = 1.380_649e-20;
+    pub(super) const ELECTRON_MASS_SCALAR: f64 = 9.109_383_701_5e-28;
+    pub(super) const ELECTRONVOLT_SCALAR: f64 = 1.602_176_634e-16;
+    pub(super) const ELEMENTARY_CHARGE_SCALAR: f64 = 1.602_176_634e-19;
+    pub(super) const NEWTONIAN_CONSTANT_OF_GRAVITATION_SCALAR: f64 = 6.674_30e-14;
+    pub(super) const PERMEABILITY_OF_VACUUM_SCALAR: f64 = 0.001_256_637_062_12;
+    pub(super) const PERMITTIVITY_OF_VACUUM_SCALAR: f64 = 8.854_187_812_8e-15;
+    pub(super) const PLANCK_CONSTANT_SCALAR: f64 = 6.626_070_15e-31;
+    pub(super) const PROTON_MASS_SCALAR: f64 = 1.672_621_923_69e-24;
+    pub(super) const UNIFIED_ATOMIC_MASS_UNIT_SCALAR: f64 = 1.660_539_066_60e-24;
+}
+
+use profile::*;
+
 macro_rules! validate_definition {
     (
         $test_name:ident,
@@ -20,8 +56,12 @@ macro_rules! validate_definition {
             let atom = Atom::$atom_name;
             let expected = vec![$($expected_term),+];
 
-            assert_relative_eq!(atom.definition().value(), $expected_value);
-            assert_ulps_eq!(atom.definition().value(), $expected_value);
+            assert_relative_eq!(
+                atom.definition().value(),
+                $expected_value,
+                epsilon = 0.0,
+                max_relative = 1e-15
+            );
             assert_eq!(atom.definition().terms(), expected.as_slice());
         }
     };
@@ -32,8 +72,8 @@ macro_rules! validate_scalar {
         #[test]
         fn $test_name() {
             let atom = Atom::$variant;
-            assert_relative_eq!(atom.scalar(), $value);
-            assert_ulps_eq!(atom.scalar(), $value);
+            // The default (absolute) epsilon can't tell small constants (ex. `[m_e]`) apart.
+            assert_relative_eq!(atom.scalar(), $value, epsilon = 0.0, max_relative = 1e-15);
         }
     };
 }
@@ -71,7 +111,7 @@ macro_rules! validate_composition {
         $composition:expr,
         $dimension_variant:ident: $value:expr
     ) => {
-        $composition.insert(Dimension::$dimension_variant, $value);
+        $composition.insert(Dimension::$dimension_variant, $value).unwrap();
     };
 
     (
@@ -300,7 +340,7 @@ validate_definition!(
 validate_definition!(
     validate_definition_mole,
     Mole,
-    6.022_136_7,
+    AVOGADRO_DEFINITION,
     term!(TheNumberTenForArbitraryPowersStar, exponent: 23)
 );
 validate_definition!(
@@ -459,7 +499,7 @@ validate_scalars!(
     validate_scalar_becquerel, Becquerel, 1.0;
     validate_scalar_biot, Biot, 10.0;
     validate_scalar_board_foot_international, BoardFootInternational, 0.002_359_737_216;
-    validate_scalar_boltzmann_constant, BoltzmannConstant, 1.380_658e-20;
+    validate_scalar_boltzmann_constant, BoltzmannConstant, BOLTZMANN_CONSTANT_SCALAR;
     validate_scalar_btu_at_39f, BritishThermalUnitAt39F, 1_059_670.0;
     validate_scalar_bushel_br, BushelBritish, 0.036_368_72;
 validate_scalar_bushel_us, BushelUS, 0.035_239_070_166_88;
@@ -493,10 +533,10 @@ validate_scalar_dry_pint_us, DryPintUS, 0.000_550_610_471_357_5;
 validate_scalar_dry_quart_us, DryQuartUS, 0.001_101_220_942_715;
 validate_scalar_dyne, Dyne, 0.01;
 
-validate_scalar_electron_mass, ElectronMass, 9.109_389_7e-28;
-validate_scalar_electron_vold, Electronvolt, 1.602_177_33e-16;
-validate_scalar_elementary_charge, ElementaryCharge, 1.602_177_33e-19;
-validate_scalar_equivalents, Equivalents, 6.022_136_7e+23;
+validate_scalar_electron_mass, ElectronMass, ELECTRON_MASS_SCALAR;
+validate_scalar_electron_vold, Electronvolt, ELECTRONVOLT_SCALAR;
+validate_scalar_elementary_charge, ElementaryCharge, ELEMENTARY_CHARGE_SCALAR;
+validate_scalar_equivalents, Equivalents, AVOGADRO_SCALAR;
 validate_scalar_erg, Erg, 0.0001;
 
 validate_scalar_farad, Farad, 0.001;
@@ -571,12 +611,13 @@ validate_scalar_mile_international, MileInternational, 1_609.344;
 validate_scalar_mile_us, MileUS, 1_609.347_218_694_437_3;
 validate_scalar_minim_br, MinimBritish, 5.919_388_020_833_333_4e-8;
 validate_scalar_minim_us, MinimUS, 6.161_151_992_187_5e-08;
-validate_scalar_mole, Mole, 6.022_136_7e+23;
+validate_scalar_mole, Mole, AVOGADRO_SCALAR;
 validate_scalar_month, Month, 2_629_800.0;
 
 validate_scalar_nautical_mile_br, NauticalMileBritish, 1_853.182_540_8;
 validate_scalar_nautical_mile_internationa, NauticalMileInternational, 1852.0;
 validate_scalar_newton, Newton, 1000.0;
+validate_scalar_newtonian_constant_of_gravitation, NewtonianConstantOfGravitation, NEWTONIAN_CONSTANT_OF_GRAVITATION_SCALAR;
 validate_scalar_ohm, Ohm, 1000.0;
 validate_scalar_oersted, Oersted, 79.577_471_545_947_67;
 validate_scalar_ounce_ap, OunceApothecaries, 31.103_476_8;
@@ -594,14 +635,14 @@ validate_scalar_pascal, Pascal, 1_000.0;
 validate_scalar_peck_br, PeckBritish, 0.009_092_18;
 validate_scalar_peck_us, PeckUS, 0.008_809_767_541_72;
 validate_scalar_percent, Percent, 0.01;
-validate_scalar_permeability_of_vacuum, PermeabilityOfVacuum, 0.001_256_637_061_435_917_5;
-validate_scalar_permittivity_of_vacuum, PermittivityOfVacuum, 8.854_187_817e-15;
+validate_scalar_permeability_of_vacuum, PermeabilityOfVacuum, PERMEABILITY_OF_VACUUM_SCALAR;
+validate_scalar_permittivity_of_vacuum, PermittivityOfVacuum, PERMITTIVITY_OF_VACUUM_SCALAR;
 validate_scalar_phot, Phot, 0.000_1;
 validate_scalar_pica, Pica, 0.004_233_333_333_333_334;
 validate_scalar_pied, Pied, 0.324_8;
 validate_scalar_pint_br, PintBritish, 0.000_568_261_25;
 validate_scalar_pint_us, PintUS, 0.000_473_176_473;
-validate_scalar_planck_constant, PlanckConstant, 6.626_075_5e-31;
+validate_scalar_planck_constant, PlanckConstant, PLANCK_CONSTANT_SCALAR;
 validate_scalar_point, Point, 0.000_352_777_777_777_777_76;
 validate_scalar_pouce, Pouce, 0.027_066_666_666_666_666;
 validate_scalar_pound_ap, PoundApothecaries, 373.241_721_6;
@@ -612,7 +653,7 @@ validate_scalar_poise, Poise, 100.0;
 validate_scalar_printers_pica, PrintersPica, 0.004_217_517_6;
 validate_scalar_printers_point, PrintersPoint, 0.000_351_459_8;
 validate_scalar_protein_nitrogen_unit, ProteinNitrogenUnit, 1.0;
-validate_scalar_proton_mass, ProtonMass, 1.672_623_100_000_000_2e-24;
+validate_scalar_proton_mass, ProtonMass, PROTON_MASS_SCALAR;
 
 validate_scalar_quart_br, QuartBritish, 0.001_136_522_5;
 validate_scalar_quart_us, QuartUS, 0.000_946_352_946;
@@ -653,7 +694,7 @@ validate_scalar_tonne, Tonne, 1_000_000.0;
 validate_scalar_township, Township, 93_239_944.931_502_76;
 validate_scalar_tropical_year, TropicalYear, 31_556_925.216;
 
-validate_scalar_unified_atomic_mass_unit, UnifiedAtomicMassUnit, 1.660_540_2e-24;
+validate_scalar_unified_atomic_mass_unit, UnifiedAtomicMassUnit, UNIFIED_ATOMIC_MASS_UNIT_SCALAR;
 validate_scalar_velocity_of_light, VelocityOfLight, 299_792_458.0;
 validate_scalar_volt, Volt, 1000.0;
 validate_scalar_watt, Watt, 1000.0;
@@ -29,12 +29,14 @@ macro_rules! def_mul_exponent {
             original_value: Option<i32>,
             exponent: i32,
             new_composition: &mut Composition,
-        ) {
+        ) -> Option<()> {
             if let Some(self_exponent) = original_value {
-                let new_exponent = self_exponent * exponent;
+                let new_exponent = self_exponent.checked_mul(exponent)?;
 
                 new_composition.$composition_method = set_exponent(new_exponent);
             }
+
+            Some(())
         }
     };
 }
@@ -43,11 +45,11 @@ macro_rules! insert_exponent {
     ($composition:expr, $method:ident, $exponent:expr) => {
         match $composition.$method {
             Some(value) => {
-                let new_exponent = $exponent + value;
+                let new_exponent = $exponent.checked_add(value)?;
 
-                set_exponent(new_exponent)
+                Some(set_exponent(new_exponent))
             }
-            None => Some($exponent),
+            None => Some(Some($exponent)),
         }
     };
 }
@@ -58,17 +60,31 @@ macro_rules! def_add_dimension {
             original_value: Option<i32>,
             rhs_composition: Composition,
             new_composition: &mut Composition,
-        ) {
+        ) -> Option<()> {
             new_composition.$composition_method = if let Some(self_value) = original_value {
-                insert_exponent!(rhs_composition, $composition_method, self_value)
+                insert_exponent!(rhs_composition, $composition_method, self_value)?
             } else {
                 rhs_composition.$composition_method
-            }
+            };
+
+            Some(())
         }
     };
 }
 
 impl Composition {
+    /// The `Composition` of something with no dimensions (ex. `"1"`, `"10*3"`, `"[pi]"`).
+    ///
+    pub(crate) const EMPTY: Self = Self {
+        electric_charge: None,
+        length: None,
+        luminous_intensity: None,
+        mass: None,
+        plane_angle: None,
+        temperature: None,
+        time: None,
+    };
+
     #[must_use]
     pub const fn new(dimension: Dimension, exponent: i32) -> Self {
         match dimension {
@@ -173,9 +189,32 @@ impl Composition {
         }
     }
 
-    pub fn insert(&mut self, dimension: Dimension, exponent: i32) {
+    /// `self`, with `dimension`'s exponent set to `exponent`. For building `Composition`s in
+    /// `const`s, starting from `Composition::EMPTY` (ex. the generated `Atom` definitions).
+    ///
+    pub(crate) const fn with(mut self, dimension: Dimension, exponent: i32) -> Self {
+        let exponent = set_exponent(exponent);
+
+        match dimension {
+            Dimension::ElectricCharge => self.electric_charge = exponent,
+            Dimension::Length => self.length = exponent,
+            Dimension::LuminousIntensity => self.luminous_intensity = exponent,
+            Dimension::Mass => self.mass = exponent,
+            Dimension::PlaneAngle => self.plane_angle = exponent,
+            Dimension::Temperature => self.temperature = exponent,
+            Dimension::Time => self.time = exponent,
+        }
+
+        self
+    }
+
+    /// Adds `exponent` to `dimension`'s exponent, or returns `None` (leaving `self` unchanged) if
+    /// the new exponent would overflow an `i32`.
+    ///
+    #[must_use]
+    pub fn insert(&mut self, dimension: Dimension, exponent: i32) -> Option<()> {
         if exponent == 0 {
-            return;
+            return Some(());
         }
 
         match dimension {
@@ -189,32 +228,97 @@ impl Composition {
         }
     }
 
-    fn insert_electric_charge(&mut self, exponent: i32) {
-        self.electric_charge = insert_exponent!(self, electric_charge, exponent);
+    fn insert_electric_charge(&mut self, exponent: i32) -> Option<()> {
+        self.electric_charge = insert_exponent!(self, electric_charge, exponent)?;
+
+        Some(())
+    }
+
+    fn insert_length(&mut self, exponent: i32) -> Option<()> {
+        self.length = insert_exponent!(self, length, exponent)?;
+
+        Some(())
     }
 
-    fn insert_length(&mut self, exponent: i32) {
-        self.length = insert_exponent!(self, length, exponent);
+    fn insert_luminous_intensity(&mut self, exponent: i32) -> Option<()> {
+        self.luminous_intensity = insert_exponent!(self, luminous_intensity, exponent)?;
+
+        Some(())
+    }
+
+    fn insert_mass(&mut self, exponent: i32) -> Option<()> {
+        self.mass = insert_exponent!(self, mass, exponent)?;
+
+        Some(())
+    }
+
+    fn insert_plane_angle(&mut self, exponent: i32) -> Option<()> {
+        self.plane_angle = insert_exponent!(self, plane_angle, exponent)?;
+
+        Some(())
     }
 
-    fn insert_luminous_intensity(&mut self, exponent: i32) {
-        self.luminous_intensity = insert_exponent!(self, luminous_intensity, exponent);
+    fn insert_temperature(&mut self, exponent: i32) -> Option<()> {
+        self.temperature = insert_exponent!(self, temperature, exponent)?;
+
+        Some(())
     }
 
-    fn insert_mass(&mut self, exponent: i32) {
-        self.mass = insert_exponent!(self, mass, exponent);
+    fn insert_time(&mut self, exponent: i32) -> Option<()> {
+        self.time = insert_exponent!(self, time, exponent)?;
+
+        Some(())
     }
 
-    fn insert_plane_angle(&mut self, exponent: i32) {
-        self.plane_angle = insert_exponent!(self, plane_angle, exponent);
+    /// Combines `self` and `rhs` (like `Mul`), or returns `None` if any of the resulting
+    /// exponents would overflow an `i32`.
+    ///
+    #[must_use]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let mut new_composition = Self::default();
+
+        add_electric_charge(self.electric_charge, rhs, &mut new_composition)?;
+        add_length(self.length, rhs, &mut new_composition)?;
+        add_luminous_intensity(self.luminous_intensity, rhs, &mut new_composition)?;
+        add_mass(self.mass, rhs, &mut new_composition)?;
+        add_plane_angle(self.plane_angle, rhs, &mut new_composition)?;
+        add_temperature(self.temperature, rhs, &mut new_composition)?;
+        add_time(self.time, rhs, &mut new_composition)?;
+
+        Some(new_composition)
     }
 
-    fn insert_temperature(&mut self, exponent: i32) {
-        self.temperature = insert_exponent!(self, temperature, exponent);
+    /// Multiplies each of `self`'s exponents by `exponent` (like `Mul<i32>`), or returns `None`
+    /// if any of the resulting exponents would overflow an `i32`.
+    ///
+    #[must_use]
+    pub fn checked_mul_exponent(self, exponent: i32) -> Option<Self> {
+        let mut new_composition = Self::default();
+
+        mul_electric_charge(self.electric_charge, exponent, &mut new_composition)?;
+        mul_length(self.length, exponent, &mut new_composition)?;
+        mul_luminous_intensity(self.luminous_intensity, exponent, &mut new_composition)?;
+        mul_mass(self.mass, exponent, &mut new_composition)?;
+        mul_plane_angle(self.plane_angle, exponent, &mut new_composition)?;
+        mul_temperature(self.temperature, exponent, &mut new_composition)?;
+        mul_time(self.time, exponent, &mut new_composition)?;
+
+        Some(new_composition)
     }
 
-    fn insert_time(&mut self, exponent: i32) {
-        self.time = insert_exponent!(self, time, exponent);
+    /// The exponent for `dimension`, if `self` has it.
+    ///
+    #[must_use]
+    pub const fn exponent(&self, dimension: Dimension) -> Option<i32> {
+        match dimension {
+            Dimension::ElectricCharge => self.electric_charge,
+            Dimension::Length => self.length,
+            Dimension::LuminousIntensity => self.luminous_intensity,
+            Dimension::Mass => self.mass,
+            Dimension::PlaneAngle => self.plane_angle,
+            Dimension::Temperature => self.temperature,
+            Dimension::Time => self.time,
+        }
     }
 
     #[must_use]
@@ -280,24 +384,15 @@ fn push_display_expression(
 }
 
 // impl Mul
-/// Used for combining two `Compositions`.
+/// Used for combining two `Compositions`. Returns `None` if an exponent overflows an `i32`; see
+/// `Composition::checked_mul()`.
 ///
 #[cfg_attr(feature = "cargo-clippy", allow(clippy::suspicious_arithmetic_impl))]
 impl Mul for Composition {
-    type Output = Self;
+    type Output = Option<Self>;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        let mut new_composition = Self::default();
-
-        add_electric_charge(self.electric_charge, rhs, &mut new_composition);
-        add_length(self.length, rhs, &mut new_composition);
-        add_luminous_intensity(self.luminous_intensity, rhs, &mut new_composition);
-        add_mass(self.mass, rhs, &mut new_composition);
-        add_plane_angle(self.plane_angle, rhs, &mut new_composition);
-        add_temperature(self.temperature, rhs, &mut new_composition);
-        add_time(self.time, rhs, &mut new_composition);
-
-        new_composition
+        self.checked_mul(rhs)
     }
 }
 
@@ -312,7 +407,8 @@ def_add_dimension!(add_time, time);
 /// Used essentially for calculating the `Composition` of a `Term`. When a `Term` has an exponent
 /// set, the `Term`'s `Atom`'s `Composition` must be multiplied by it. For example, if a `Term`
 /// has `Atom` `Are` (which is a square meter) and `exponent` 3, the `Composition` should be `L6`
-/// which is the `3[the term's exponent]` * `2[the term's atom's length composition]`.
+/// which is the `3[the term's exponent]` * `2[the term's atom's length composition]`. Returns
+/// `None` if an exponent overflows an `i32`; see `Composition::checked_mul_exponent()`.
 ///
 /// ```rust
 /// use wise_units::{Atom, Composable, Term};
@@ -329,20 +425,10 @@ def_add_dimension!(add_time, time);
 /// ```
 ///
 impl Mul<i32> for Composition {
-    type Output = Self;
+    type Output = Option<Self>;
 
     fn mul(self, rhs: i32) -> Self::Output {
-        let mut new_composition = Self::default();
-
-        mul_electric_charge(self.electric_charge, rhs, &mut new_composition);
-        mul_length(self.length, rhs, &mut new_composition);
-        mul_luminous_intensity(self.luminous_intensity, rhs, &mut new_composition);
-        mul_mass(self.mass, rhs, &mut new_composition);
-        mul_plane_angle(self.plane_angle, rhs, &mut new_composition);
-        mul_temperature(self.temperature, rhs, &mut new_composition);
-        mul_time(self.time, rhs, &mut new_composition);
-
-        new_composition
+        self.checked_mul_exponent(rhs)
     }
 }
 
@@ -414,29 +500,41 @@ mod tests {
     #[test]
     fn validate_insert() {
         let mut composition = Composition::default();
-        composition.insert(Dimension::Mass, 3);
+        composition.insert(Dimension::Mass, 3).unwrap();
         assert_eq!(composition.to_string().as_str(), "M3");
 
-        composition.insert(Dimension::Mass, 3);
+        composition.insert(Dimension::Mass, 3).unwrap();
         assert_eq!(composition.to_string().as_str(), "M6");
 
-        composition.insert(Dimension::Mass, -6);
+        composition.insert(Dimension::Mass, -6).unwrap();
         assert_eq!(composition.to_string().as_str(), "");
 
         let mut composition = Composition::default();
-        composition.insert(Dimension::Mass, -1);
-        composition.insert(Dimension::Temperature, -2);
-        composition.insert(Dimension::ElectricCharge, -3);
-        composition.insert(Dimension::Time, -4);
-        composition.insert(Dimension::Length, -5);
-        composition.insert(Dimension::PlaneAngle, -6);
-        composition.insert(Dimension::LuminousIntensity, -7);
+        composition.insert(Dimension::Mass, -1).unwrap();
+        composition.insert(Dimension::Temperature, -2).unwrap();
+        composition.insert(Dimension::ElectricCharge, -3).unwrap();
+        composition.insert(Dimension::Time, -4).unwrap();
+        composition.insert(Dimension::Length, -5).unwrap();
+        composition.insert(Dimension::PlaneAngle, -6).unwrap();
+        composition
+            .insert(Dimension::LuminousIntensity, -7)
+            .unwrap();
         assert_eq!(
             composition.to_string().as_str(),
             "Q-3.L-5.F-7.M-1.A-6.C-2.T-4"
         );
     }
 
+    #[test]
+    fn validate_exponent() {
+        let mut composition = Composition::new_length(2);
+        composition.insert(Dimension::Time, -1).unwrap();
+
+        assert_eq!(composition.exponent(Dimension::Length), Some(2));
+        assert_eq!(composition.exponent(Dimension::Time), Some(-1));
+        assert_eq!(composition.exponent(Dimension::Mass), None);
+    }
+
     #[test]
     fn validate_is_empty() {
         let composition = Composition {
@@ -514,35 +612,35 @@ mod tests {
         assert_eq!(&subject.to_string(), "T2");
 
         let mut subject = Composition::new(Dimension::ElectricCharge, -2);
-        subject.insert(Dimension::Length, -3);
-        subject.insert(Dimension::LuminousIntensity, -4);
-        subject.insert(Dimension::Mass, 1);
-        subject.insert(Dimension::PlaneAngle, 2);
-        subject.insert(Dimension::Temperature, 3);
-        subject.insert(Dimension::Time, 4);
+        subject.insert(Dimension::Length, -3).unwrap();
+        subject.insert(Dimension::LuminousIntensity, -4).unwrap();
+        subject.insert(Dimension::Mass, 1).unwrap();
+        subject.insert(Dimension::PlaneAngle, 2).unwrap();
+        subject.insert(Dimension::Temperature, 3).unwrap();
+        subject.insert(Dimension::Time, 4).unwrap();
         assert_eq!(&subject.to_string(), "Q-2.L-3.F-4.M.A2.C3.T4");
     }
 
     #[test]
     fn validate_mul_composition_lhs_empty() {
         let subject = Composition::default();
-        let product = subject * subject;
+        let product = (subject * subject).unwrap();
         assert!(product.is_empty());
 
         let other = Composition::new(Dimension::Mass, 1);
-        let product = subject * other;
+        let product = (subject * other).unwrap();
         assert_eq!(product.mass, Some(1));
 
         let other = Composition::new(Dimension::Mass, 2);
-        let product = subject * other;
+        let product = (subject * other).unwrap();
         assert_eq!(product.mass, Some(2));
 
         let other = Composition::new(Dimension::Mass, -1);
-        let product = subject * other;
+        let product = (subject * other).unwrap();
         assert_eq!(product.mass, Some(-1));
 
         let other = Composition::new(Dimension::Mass, -2);
-        let product = subject * other;
+        let product = (subject * other).unwrap();
         assert_eq!(product.mass, Some(-2));
     }
 
@@ -550,22 +648,22 @@ mod tests {
     fn validate_mul_composition_lhs_1() {
         let subject = Composition::new(Dimension::Mass, 1);
         let other = Composition::default();
-        let product = subject * other;
+        let product = (subject * other).unwrap();
         assert_eq!(product.mass, Some(1));
 
-        let product = subject * subject;
+        let product = (subject * subject).unwrap();
         assert_eq!(product.mass, Some(2));
 
         let other = Composition::new(Dimension::Mass, 2);
-        let product = subject * other;
+        let product = (subject * other).unwrap();
         assert_eq!(product.mass, Some(3));
 
         let other = Composition::new(Dimension::Mass, -1);
-        let product = subject * other;
+        let product = (subject * other).unwrap();
         assert_eq!(product.mass, None);
 
         let other = Composition::new(Dimension::Mass, -2);
-        let product = subject * other;
+        let product = (subject * other).unwrap();
         assert_eq!(product.mass, Some(-1));
     }
 
@@ -573,26 +671,26 @@ mod tests {
     fn validate_mul_composition_lhs_2() {
         let subject = Composition::new(Dimension::Mass, 2);
         let other = Composition::default();
-        let product = subject * other;
+        let product = (subject * other).unwrap();
         assert_eq!(product.mass, Some(2));
 
         let other = Composition::new(Dimension::Mass, 1);
-        let product = subject * other;
+        let product = (subject * other).unwrap();
         assert_eq!(product.mass, Some(3));
 
-        let product = subject * subject;
+        let product = (subject * subject).unwrap();
         assert_eq!(product.mass, Some(4));
 
         let other = Composition::new(Dimension::Mass, -1);
-        let product = subject * other;
+        let product = (subject * other).unwrap();
         assert_eq!(product.mass, Some(1));
 
         let other = Composition::new(Dimension::Mass, -2);
-        let product = subject * other;
+        let product = (subject * other).unwrap();
         assert_eq!(product.mass, None);
 
         let other = Composition::new(Dimension::Mass, -3);
-        let product = subject * other;
+        let product = (subject * other).unwrap();
         assert_eq!(product.mass, Some(-1));
     }
 
@@ -600,16 +698,16 @@ mod tests {
     #[allow(clippy::erasing_op, clippy::identity_op)]
     fn validate_mul_i32_lhs_empty() {
         let subject = Composition::default();
-        let product = subject * 0;
+        let product = (subject * 0).unwrap();
         assert!(product.is_empty());
 
-        let product = subject * 1;
+        let product = (subject * 1).unwrap();
         assert_eq!(product.mass, None);
 
-        let product = subject * 2;
+        let product = (subject * 2).unwrap();
         assert_eq!(product.mass, None);
 
-        let product = subject * -1;
+        let product = (subject * -1).unwrap();
         assert_eq!(product.mass, None);
     }
 
@@ -617,19 +715,19 @@ mod tests {
     #[allow(clippy::erasing_op, clippy::identity_op)]
     fn validate_mul_i32_lhs_1() {
         let subject = Composition::new(Dimension::Mass, 1);
-        let product = subject * 0;
+        let product = (subject * 0).unwrap();
         assert!(product.is_empty());
 
-        let product = subject * 1;
+        let product = (subject * 1).unwrap();
         assert_eq!(product.mass, Some(1));
 
-        let product = subject * 2;
+        let product = (subject * 2).unwrap();
         assert_eq!(product.mass, Some(2));
 
-        let product = subject * -1;
+        let product = (subject * -1).unwrap();
         assert_eq!(product.mass, Some(-1));
 
-        let product = subject * -2;
+        let product = (subject * -2).unwrap();
         assert_eq!(product.mass, Some(-2));
     }
 
@@ -637,19 +735,19 @@ mod tests {
     #[allow(clippy::erasing_op, clippy::identity_op)]
     fn validate_mul_i32_lhs_2() {
         let subject = Composition::new(Dimension::Mass, 2);
-        let product = subject * 0;
+        let product = (subject * 0).unwrap();
         assert!(product.is_empty());
 
-        let product = subject * 1;
+        let product = (subject * 1).unwrap();
         assert_eq!(product.mass, Some(2));
 
-        let product = subject * 2;
+        let product = (subject * 2).unwrap();
         assert_eq!(product.mass, Some(4));
 
-        let product = subject * -1;
+        let product = (subject * -1).unwrap();
         assert_eq!(product.mass, Some(-2));
 
-        let product = subject * -2;
+        let product = (subject * -2).unwrap();
         assert_eq!(product.mass, Some(-4));
     }
 }
@@ -0,0 +1,325 @@
+use crate::parser::{definition::Definition, Atom, Composition, Dimension, Prefix, Term};
+use std::mem::discriminant;
+
+/// Which set of values the physical constants (and other measured atoms) are defined with.
+///
+/// The generated `Atom` definitions come from the UCUM 2.1 table, which uses older CODATA
+/// values (ex. Avogadro's number as 6.0221367e23). Building with the `codata-2018` feature
+/// switches to the CODATA 2018 values, which includes the constants that the 2019 SI
+/// redefinition made exact (ex. Avogadro's number as 6.02214076e23). Atoms defined in terms of
+/// these (ex. `eV`, `[e]` times `V`) follow along, so conversions stay consistent within a
+/// profile.
+///
+/// ```
+/// use wise_units::parser::{Atom, ConstantProfile};
+///
+/// let profile = ConstantProfile::ACTIVE;
+///
+/// if cfg!(feature = "codata-2018") {
+///     assert_eq!(profile, ConstantProfile::Codata2018);
+///     assert!(profile.overridden_atoms().contains(&Atom::Mole));
+/// } else {
+///     assert_eq!(profile, ConstantProfile::Ucum21);
+///     assert!(profile.overridden_atoms().is_empty());
+/// }
+/// ```
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConstantProfile {
+    Ucum21,
+    Codata2018,
+}
+
+/// A `&'static [Term]`, for a `Definition`'s `terms`.
+///
+macro_rules! terms {
+    ($($term:expr),+ $(,)?) => {{
+        const TERMS: &[Term] = &[$($term),+];
+        TERMS
+    }};
+}
+
+/// Definitions that CODATA 2018 gives differently than the UCUM 2.1 table, each with the
+/// expression that its `Term`s were written from.
+///
+static CODATA_2018: [(Atom, &str, Definition); 10] = [
+    (
+        Atom::Mole,
+        "10*23",
+        Definition::new(
+            6.022_140_76,
+            terms![
+                Term::new(None, Some(Atom::TheNumberTenForArbitraryPowersStar)).with_exponent(23)
+            ],
+            None,
+            Composition::EMPTY,
+            None,
+        ),
+    ),
+    (
+        Atom::PlanckConstant,
+        "J.s",
+        Definition::new(
+            6.626_070_15e-34,
+            terms![
+                Term::new(None, Some(Atom::Joule)),
+                Term::new(None, Some(Atom::Second))
+            ],
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Time, -1),
+            None,
+        ),
+    ),
+    (
+        Atom::BoltzmannConstant,
+        "J/K",
+        Definition::new(
+            1.380_649e-23,
+            terms![
+                Term::new(None, Some(Atom::Joule)),
+                Term::new(None, Some(Atom::Kelvin)).with_exponent(-1),
+            ],
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 2)
+                .with(Dimension::Mass, 1)
+                .with(Dimension::Temperature, -1)
+                .with(Dimension::Time, -2),
+            None,
+        ),
+    ),
+    (
+        Atom::ElementaryCharge,
+        "C",
+        Definition::new(
+            1.602_176_634e-19,
+            terms![Term::new(None, Some(Atom::Coulomb))],
+            None,
+            Composition::new_electric_charge(1),
+            None,
+        ),
+    ),
+    (
+        Atom::ElectronMass,
+        "g",
+        Definition::new(
+            9.109_383_701_5e-28,
+            terms![Term::new(None, Some(Atom::Gram))],
+            None,
+            Composition::new_mass(1),
+            None,
+        ),
+    ),
+    (
+        Atom::ProtonMass,
+        "g",
+        Definition::new(
+            1.672_621_923_69e-24,
+            terms![Term::new(None, Some(Atom::Gram))],
+            None,
+            Composition::new_mass(1),
+            None,
+        ),
+    ),
+    (
+        Atom::UnifiedAtomicMassUnit,
+        "g",
+        Definition::new(
+            1.660_539_066_60e-24,
+            terms![Term::new(None, Some(Atom::Gram))],
+            None,
+            Composition::new_mass(1),
+            None,
+        ),
+    ),
+    (
+        Atom::NewtonianConstantOfGravitation,
+        "m3.kg-1.s-2",
+        Definition::new(
+            6.674_30e-11,
+            terms![
+                Term::new(None, Some(Atom::Meter)).with_exponent(3),
+                Term::new(Some(Prefix::Kilo), Some(Atom::Gram)).with_exponent(-1),
+                Term::new(None, Some(Atom::Second)).with_exponent(-2),
+            ],
+            None,
+            Composition::EMPTY
+                .with(Dimension::Length, 3)
+                .with(Dimension::Mass, -1)
+                .with(Dimension::Time, -2),
+            None,
+        ),
+    ),
+    (
+        Atom::PermittivityOfVacuum,
+        "F/m",
+        Definition::new(
+            8.854_187_812_8e-12,
+            terms![
+                Term::new(None, Some(Atom::Farad)),
+                Term::new(None, Some(Atom::Meter)).with_exponent(-1),
+            ],
+            None,
+            Composition::EMPTY
+                .with(Dimension::ElectricCharge, 2)
+                .with(Dimension::Length, -3)
+                .with(Dimension::Mass, -1)
+                .with(Dimension::Time, 2),
+            None,
+        ),
+    ),
+    (
+        Atom::PermeabilityOfVacuum,
+        "N/A2",
+        Definition::new(
+            1.256_637_062_12e-6,
+            terms![
+                Term::new(None, Some(Atom::Newton)),
+                Term::new(None, Some(Atom::Ampere)).with_exponent(-2),
+            ],
+            None,
+            Composition::EMPTY
+                .with(Dimension::ElectricCharge, -2)
+                .with(Dimension::Length, 1)
+                .with(Dimension::Mass, 1),
+            None,
+        ),
+    ),
+];
+
+impl ConstantProfile {
+    /// The profile that this build of the crate uses.
+    ///
+    #[cfg(not(feature = "codata-2018"))]
+    pub const ACTIVE: Self = Self::Ucum21;
+
+    /// The profile that this build of the crate uses.
+    ///
+    #[cfg(feature = "codata-2018")]
+    pub const ACTIVE: Self = Self::Codata2018;
+
+    /// The `Atom`s whose definitions this profile replaces, relative to the UCUM 2.1 table.
+    ///
+    #[must_use]
+    pub fn overridden_atoms(self) -> Vec<Atom> {
+        match self {
+            Self::Ucum21 => Vec::new(),
+            Self::Codata2018 => CODATA_2018.iter().map(|(atom, _, _)| *atom).collect(),
+        }
+    }
+
+    /// This profile's `Definition` for `atom`, if it replaces the one from the UCUM 2.1 table.
+    ///
+    pub(crate) fn definition(self, atom: Atom) -> Option<&'static Definition> {
+        match self {
+            Self::Ucum21 => None,
+            Self::Codata2018 => CODATA_2018
+                .iter()
+                // `Atom`'s `PartialEq` compares scalars, which would recurse back into here.
+                .find(|(overridden, _, _)| discriminant(overridden) == discriminant(&atom))
+                .map(|(_, _, definition)| definition),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ConstantProfile, CODATA_2018};
+    use crate::{
+        parser::{Atom, Composable},
+        Convertible, Measurement, UcumUnit,
+    };
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn validate_overridden_atoms() {
+        assert!(ConstantProfile::Ucum21.overridden_atoms().is_empty());
+        assert_eq!(
+            ConstantProfile::Codata2018.overridden_atoms(),
+            vec![
+                Atom::Mole,
+                Atom::PlanckConstant,
+                Atom::BoltzmannConstant,
+                Atom::ElementaryCharge,
+                Atom::ElectronMass,
+                Atom::ProtonMass,
+                Atom::UnifiedAtomicMassUnit,
+                Atom::NewtonianConstantOfGravitation,
+                Atom::PermittivityOfVacuum,
+                Atom::PermeabilityOfVacuum,
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_definitions() {
+        for (atom, expression, definition) in &CODATA_2018 {
+            let terms = crate::parser::parse(expression).unwrap();
+
+            assert_eq!(definition.terms(), terms.as_slice(), "{:?}", atom);
+            assert_eq!(definition.composition(), terms.composition(), "{:?}", atom);
+            assert_eq!(definition.composition(), atom.composition(), "{:?}", atom);
+        }
+
+        assert!(ConstantProfile::Ucum21.definition(Atom::Mole).is_none());
+        assert!(ConstantProfile::Codata2018
+            .definition(Atom::Meter)
+            .is_none());
+    }
+
+    #[test]
+    fn validate_unchanged_atoms() {
+        // Exact in both profiles.
+        assert_relative_eq!(Atom::VelocityOfLight.scalar(), 299_792_458.0);
+        assert_relative_eq!(Atom::StandardAccelerationOfFreeFall.scalar(), 9.806_65);
+    }
+
+    #[cfg(not(feature = "codata-2018"))]
+    #[test]
+    fn validate_ucum_21() {
+        assert_relative_eq!(Atom::Mole.scalar(), 6.022_136_7e23, max_relative = 1e-15);
+        assert_relative_eq!(
+            Atom::ElementaryCharge.scalar(),
+            1.602_177_33e-19,
+            max_relative = 1e-15
+        );
+        assert_relative_eq!(
+            Atom::PlanckConstant.scalar(),
+            6.626_075_5e-31,
+            max_relative = 1e-12
+        );
+
+        let electronvolt = Measurement::try_new(1.0, "eV").unwrap();
+        let joules = electronvolt.convert_to("J").unwrap();
+        assert_relative_eq!(joules.value(), 1.602_177_33e-19, max_relative = 1e-12);
+    }
+
+    #[cfg(feature = "codata-2018")]
+    #[test]
+    fn validate_codata_2018() {
+        assert_relative_eq!(Atom::Mole.scalar(), 6.022_140_76e23, max_relative = 1e-15);
+        assert_relative_eq!(
+            Atom::ElementaryCharge.scalar(),
+            1.602_176_634e-19,
+            max_relative = 1e-15
+        );
+        // J.s is 1000 g.m2/s, so the base-unit scalar is 1000 times the SI value.
+        assert_relative_eq!(
+            Atom::PlanckConstant.scalar(),
+            6.626_070_15e-31,
+            max_relative = 1e-12
+        );
+
+        // eV is defined as [e].V, so it follows the new elementary charge.
+        let electronvolt = Measurement::try_new(1.0, "eV").unwrap();
+        let joules = electronvolt.convert_to("J").unwrap();
+        assert_relative_eq!(joules.value(), 1.602_176_634e-19, max_relative = 1e-12);
+
+        let amount = Measurement::try_new(1.0, "mol").unwrap();
+        let count = amount.convert_to("10*23").unwrap();
+        assert_relative_eq!(count.value(), 6.022_140_76, max_relative = 1e-15);
+    }
+}
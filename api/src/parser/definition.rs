@@ -1,40 +1,57 @@
-use crate::parser::{function_set::FunctionSet, Error, Term};
+use crate::parser::{
+    constant_profile::ConstantProfile, function_set::FunctionSet, Composition, Term,
+};
 use crate::reducible::Reducible;
 
 /// A `Definition` is a slimmed-down version of a `Measurement` that is used to
 /// define `Atom`s in terms of other `Atom`s (ex. an `"[in_i]"` has a
 /// `Definition` of 2.54 cm).
 ///
+/// `Definition`s are precomputed by wise_units-atom_generator (see `Atom::definition()`), so
+/// nothing is parsed when looking one up.
+///
 #[derive(Debug)]
 pub(crate) struct Definition {
     value: f64,
-    terms: Vec<Term>,
+    terms: &'static [Term],
 
     /// Conversion functions only required for special (non-ratio based) atoms.
     function_set: Option<FunctionSet>,
+
+    composition: Composition,
+
+    /// `value` times the scalar of `terms`, calculated with the UCUM 2.1 constants. `None` for
+    /// special atoms, and for `Definition`s that need calculating from `terms` instead.
+    scalar: Option<f64>,
 }
 
 impl Definition {
-    pub(crate) fn new(
+    pub(crate) const fn new(
         value: f64,
-        expression: &str,
+        terms: &'static [Term],
         function_set: Option<FunctionSet>,
-    ) -> Result<Self, Error> {
-        let terms = super::parse(expression)?;
-
-        Ok(Self {
+        composition: Composition,
+        scalar: Option<f64>,
+    ) -> Self {
+        Self {
             value,
             terms,
             function_set,
-        })
+            composition,
+            scalar,
+        }
     }
 
     pub(crate) const fn value(&self) -> f64 {
         self.value
     }
 
-    pub(crate) const fn terms(&self) -> &Vec<Term> {
-        &self.terms
+    pub(crate) const fn terms(&self) -> &'static [Term] {
+        self.terms
+    }
+
+    pub(crate) const fn composition(&self) -> Composition {
+        self.composition
     }
 
     pub(crate) fn is_unity(&self) -> bool {
@@ -45,13 +62,12 @@ impl Definition {
 impl Reducible for Definition {
     fn reduce_value(&self, other_value: f64) -> f64 {
         match self.function_set {
-            None => {
-                if self.is_unity() {
-                    self.value
-                } else {
-                    self.value * self.terms.reduce_value(other_value)
-                }
-            }
+            None => match self.scalar {
+                // Other profiles can change the atoms that the scalar was calculated from.
+                Some(scalar) if ConstantProfile::ACTIVE == ConstantProfile::Ucum21 => scalar,
+                _ if self.is_unity() => self.value,
+                _ => self.value * self.terms.reduce_value(other_value),
+            },
             Some(ref f) => (f.convert_to)(other_value),
         }
     }
@@ -69,13 +85,3 @@ impl Reducible for Definition {
         }
     }
 }
-
-impl Default for Definition {
-    fn default() -> Self {
-        Self {
-            value: 1.0,
-            terms: vec![Term::new_unity()],
-            function_set: None,
-        }
-    }
-}
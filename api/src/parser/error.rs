@@ -27,6 +27,31 @@ pub enum Error {
     ///
     #[error("Unknown unit string: {0}")]
     UnknownUnitString(String),
+
+    /// The unit string is longer than `ParseLimits::max_length()`.
+    ///
+    #[error("Unit string is {length} bytes long; the maximum is {max}")]
+    InputTooLong { length: usize, max: usize },
+
+    /// Parentheses and `.`/`/` operators nest deeper than `ParseLimits::max_depth()`.
+    ///
+    #[error("Unit string nests deeper than the maximum of {max}: {expression}")]
+    NestingTooDeep { expression: String, max: usize },
+
+    /// A `Term`'s exponent is larger in magnitude than `ParseLimits::max_exponent()`.
+    ///
+    #[error("Exponent {exponent} is out of range; the maximum magnitude is {max}")]
+    ExponentOutOfRange { exponent: String, max: i32 },
+
+    /// A `Term`'s factor doesn't fit in a `u32`.
+    ///
+    #[error("Factor is out of range: {0}")]
+    FactorOutOfRange(String),
+
+    /// Combining the `Term`s' exponents overflows an `i32`.
+    ///
+    #[error("Exponents overflow when combined: {0}")]
+    ExponentOverflow(String),
 }
 
 #[cfg(feature = "serde")]
@@ -0,0 +1,194 @@
+use crate::parser::{checked_composition, Error, Term};
+
+/// Bounds on what the parser accepts, for parsing unit strings from untrusted input. Everything
+/// is checked before any value can overflow, and input is rejected before the (recursive)
+/// grammar sees anything nested too deeply, so hostile strings get an `Error` instead of a panic
+/// or a stack overflow.
+///
+/// By default, unit strings may be up to 1024 bytes long, nest up to 64 levels deep, and use
+/// exponents up to +/-1000. `Unit::from_str()` always uses the defaults; use
+/// `Unit::from_str_with()` for others.
+///
+/// ```
+/// use wise_units::{parser::{Error as ParserError, ParseLimits}, Error, Unit};
+///
+/// let limits = ParseLimits::new().with_max_exponent(3);
+///
+/// assert!(Unit::from_str_with("m3", limits).is_ok());
+/// assert_eq!(
+///     Unit::from_str_with("m4", limits).unwrap_err(),
+///     Error::ParsingFailed(ParserError::ExponentOutOfRange {
+///         exponent: "4".to_string(),
+///         max: 3
+///     })
+/// );
+/// ```
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseLimits {
+    max_length: usize,
+    max_depth: usize,
+    max_exponent: i32,
+}
+
+impl ParseLimits {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            max_length: 1024,
+            max_depth: 64,
+            max_exponent: 1000,
+        }
+    }
+
+    /// Sets the longest unit string, in bytes, that will be parsed.
+    ///
+    #[must_use]
+    pub const fn with_max_length(self, max_length: usize) -> Self {
+        Self { max_length, ..self }
+    }
+
+    /// Sets how deeply parentheses and `.`/`/` operators may nest. Each operator counts as a
+    /// level (ex. `kg.m/s2` is 2 deep, `kg.(m/s2)` is 3 deep), since the grammar nests
+    /// everything to the right of an operator. Parsing recurses once per level, so raising this
+    /// far past the default can exhaust the stack.
+    ///
+    #[must_use]
+    pub const fn with_max_depth(self, max_depth: usize) -> Self {
+        Self { max_depth, ..self }
+    }
+
+    /// Sets the largest magnitude allowed for any `Term`'s exponent.
+    ///
+    #[must_use]
+    pub const fn with_max_exponent(self, max_exponent: i32) -> Self {
+        Self {
+            max_exponent,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub const fn max_length(&self) -> usize {
+        self.max_length
+    }
+
+    #[must_use]
+    pub const fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    #[must_use]
+    pub const fn max_exponent(&self) -> i32 {
+        self.max_exponent
+    }
+
+    /// Checks `expression` before it's handed to the grammar.
+    ///
+    pub(super) fn check_expression(self, expression: &str) -> Result<(), Error> {
+        if expression.len() > self.max_length {
+            return Err(Error::InputTooLong {
+                length: expression.len(),
+                max: self.max_length,
+            });
+        }
+
+        let too_deep = || Error::NestingTooDeep {
+            expression: expression.to_string(),
+            max: self.max_depth,
+        };
+
+        let mut depth = 0;
+        let mut outer_depths: Vec<usize> = Vec::new();
+        let mut in_annotation = false;
+
+        for byte in expression.bytes() {
+            if in_annotation {
+                in_annotation = byte != b'}';
+                continue;
+            }
+
+            match byte {
+                b'{' => in_annotation = true,
+                b'(' => {
+                    outer_depths.push(depth);
+                    depth += 1;
+                }
+                b')' => depth = outer_depths.pop().unwrap_or(depth),
+                b'.' | b'/' => depth += 1,
+                _ => continue,
+            }
+
+            if depth > self.max_depth {
+                return Err(too_deep());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks the parsed `terms`: that their exponents are within range, and that summing them
+    /// up (when reducing, or building a `Composition`) can't overflow.
+    ///
+    pub(super) fn check_terms(self, expression: &str, terms: &[Term]) -> Result<(), Error> {
+        for term in terms {
+            let exponent = term.exponent.unwrap_or(1);
+
+            if exponent
+                .checked_abs()
+                .map_or(true, |abs| abs > self.max_exponent)
+            {
+                return Err(Error::ExponentOutOfRange {
+                    exponent: exponent.to_string(),
+                    max: self.max_exponent,
+                });
+            }
+        }
+
+        checked_composition(terms)
+            .map(|_| ())
+            .ok_or_else(|| Error::ExponentOverflow(expression.to_string()))
+    }
+}
+
+impl Default for ParseLimits {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseLimits;
+    use crate::parser::Error;
+
+    #[test]
+    fn validate_check_expression_length() {
+        let limits = ParseLimits::new().with_max_length(4);
+
+        assert!(limits.check_expression("m/s2").is_ok());
+        assert_eq!(
+            limits.check_expression("km/s2").unwrap_err(),
+            Error::InputTooLong { length: 5, max: 4 }
+        );
+    }
+
+    #[test]
+    fn validate_check_expression_depth() {
+        let limits = ParseLimits::new().with_max_depth(2);
+
+        assert!(limits.check_expression("kg.m/s2").is_ok());
+        assert!(limits.check_expression("(kg.m)/s2").is_ok());
+        assert!(limits.check_expression("((kg))").is_ok());
+        assert!(limits.check_expression("kg{a.b.c/d}/s").is_ok());
+        assert_eq!(
+            limits.check_expression("kg.(m/s2)").unwrap_err(),
+            Error::NestingTooDeep {
+                expression: "kg.(m/s2)".to_string(),
+                max: 2
+            }
+        );
+        assert!(limits.check_expression("(((kg)))").is_err());
+        assert!(limits.check_expression("kg.m.s.A").is_err());
+    }
+}
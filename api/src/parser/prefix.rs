@@ -1,9 +1,6 @@
 use super::{Error, Visit};
 use crate::{
-    parser::{
-        definition::Definition, symbols::symbol_parser::Rule, ucum_symbol::UcumSymbol,
-        Classification,
-    },
+    parser::{symbols::symbol_parser::Rule, ucum_symbol::UcumSymbol, Classification},
     unit::Unit,
 };
 use pest::iterators::Pair;
@@ -201,9 +198,7 @@ impl UcumSymbol for Prefix {
     }
 
     fn definition_unit(&self) -> Unit {
-        let definition = Definition::default();
-
-        Unit::new(definition.terms().clone())
+        Unit::new_unity()
     }
 }
 
@@ -219,6 +214,7 @@ impl Visit<Rule> for Prefix {
             Rule::pri_gibi | Rule::sec_gibi => Self::Gibi,
             Rule::pri_giga | Rule::sec_giga => Self::Giga,
             Rule::pri_hecto | Rule::sec_hecto => Self::Hecto,
+            Rule::pri_kibi | Rule::sec_kibi => Self::Kibi,
             Rule::pri_kilo | Rule::sec_kilo => Self::Kilo,
             Rule::pri_mebi | Rule::sec_mebi => Self::Mebi,
             Rule::pri_mega | Rule::sec_mega => Self::Mega,
@@ -10,7 +10,8 @@ use std::fmt;
 /// HTML spec, but is used throughout the
 /// [XML description](http://unitsofmeasure.org/ucum-essence.xml).
 ///
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Property {
     Acceleration,
     Acidity,
@@ -6,6 +6,8 @@ mod is_compatible_with;
 mod reducible;
 mod ucum_unit;
 
+pub(crate) use self::composable::checked_composition;
+
 use crate::parser::{Atom, Prefix};
 
 /// A Term makes up an Atom (at its core) along with any Atom modifiers
@@ -34,6 +36,15 @@ impl Term {
         }
     }
 
+    /// `self`, with its `exponent` set to `exponent`. For building `Term`s in `const`s, starting
+    /// from `Term::new()` (ex. the `units` module).
+    ///
+    pub(crate) const fn with_exponent(mut self, exponent: i32) -> Self {
+        self.exponent = Some(exponent);
+
+        self
+    }
+
     /// Creates a new `Term` that's equivalent to the unit "1".
     ///
     #[must_use]
@@ -1,34 +1,81 @@
 use super::Term;
 use crate::parser::{Composable, Composition};
 
+const OVERFLOW_MESSAGE: &str = "Composition exponent overflowed an i32";
+
 impl Composable for Term {
     /// Combines the `Composition` from the `Term`'s `Atom` with its own `exponent` to build a
     /// `Composition`. If the `Term` has no `Atom`, it has no dimension, thus will have an empty
     /// `Composition`.
     ///
+    /// # Panics
+    ///
+    /// Panics if an exponent overflows an `i32`. Parsing, `Unit::try_new()`, and `UnitBuilder`
+    /// reject `Term`s like that, so this can only happen to ones built by hand.
+    ///
     // TODO: https://agrian.atlassian.net/browse/DEV-971
     //
     fn composition(&self) -> Composition {
-        match self.atom {
-            Some(atom) => {
-                let atom_composition = atom.composition();
+        self.checked_composition().expect(OVERFLOW_MESSAGE)
+    }
+}
 
-                match self.exponent {
-                    Some(term_exponent) => atom_composition * term_exponent,
-                    None => atom_composition,
-                }
-            }
+impl Term {
+    /// Like `composition()`, but returns `None` instead of panicking if any of the resulting
+    /// exponents would overflow an `i32`.
+    ///
+    pub(crate) fn checked_composition(&self) -> Option<Composition> {
+        match (self.atom, self.exponent) {
+            (Some(atom), Some(exponent)) => atom.composition() * exponent,
+            (Some(atom), None) => Some(atom.composition()),
             // If there's no Atom in the Term, there's no dimension--even if there's an exponent on
             // the Term.
-            None => Composition::default(),
+            (None, _) => Some(Composition::default()),
         }
     }
 }
 
-impl Composable for Vec<Term> {
+/// Like `[Term]::composition()`, but returns `None` instead of panicking if any of the resulting
+/// exponents would overflow an `i32`. Anything that builds a `Unit` from `Term`s it didn't parse
+/// should check this first.
+///
+/// That also keeps everything else done with a `Unit`'s exponents from overflowing: the sum of
+/// the `Term`s' exponents' magnitudes has to fit in an `i32`, so reducing them can't overflow,
+/// and no exponent can be `i32::MIN`, so inverting them can't either.
+///
+pub(crate) fn checked_composition(terms: &[Term]) -> Option<Composition> {
+    let _ = terms.iter().try_fold(0_i32, |sum, term| {
+        sum.checked_add(term.exponent.unwrap_or(1).checked_abs()?)
+    })?;
+
+    let composition = terms
+        .iter()
+        .try_fold(Composition::default(), |composition, term| {
+            composition * term.checked_composition()?
+        })?;
+
+    let _ = composition.checked_mul_exponent(-1)?;
+
+    Some(composition)
+}
+
+impl Composable for [Term] {
+    /// # Panics
+    ///
+    /// Panics if an exponent overflows an `i32`; see `Composable for Term`.
+    ///
     fn composition(&self) -> Composition {
         self.iter()
-            .fold(Composition::default(), |acc, term| acc * term.composition())
+            .try_fold(Composition::default(), |acc, term| {
+                acc * term.checked_composition()?
+            })
+            .expect(OVERFLOW_MESSAGE)
+    }
+}
+
+impl Composable for Vec<Term> {
+    fn composition(&self) -> Composition {
+        self.as_slice().composition()
     }
 }
 
@@ -25,8 +25,8 @@ impl ToInverse for Term {
     }
 }
 
-// Vec<Term>
-impl Invert for &mut Vec<Term> {
+// [Term]
+impl Invert for &mut [Term] {
     fn invert(self) {
         for term in self.iter_mut() {
             term.invert()
@@ -34,11 +34,26 @@ impl Invert for &mut Vec<Term> {
     }
 }
 
+impl ToInverse for [Term] {
+    type Output = Vec<Term>;
+
+    fn to_inverse(&self) -> Self::Output {
+        self.iter().map(ToInverse::to_inverse).collect()
+    }
+}
+
+// Vec<Term>
+impl Invert for &mut Vec<Term> {
+    fn invert(self) {
+        self.as_mut_slice().invert();
+    }
+}
+
 impl ToInverse for Vec<Term> {
     type Output = Self;
 
     fn to_inverse(&self) -> Self::Output {
-        self.iter().map(ToInverse::to_inverse).collect()
+        self.as_slice().to_inverse()
     }
 }
 
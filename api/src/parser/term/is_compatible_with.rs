@@ -36,7 +36,7 @@ impl IsCompatibleWith for Term {
     }
 }
 
-impl IsCompatibleWith for Vec<Term> {
+impl IsCompatibleWith for [Term] {
     fn is_compatible_with(&self, rhs: &Self) -> bool {
         let lhs_annotation_composition = self.annotation_composition();
         let rhs_annotation_composition = rhs.annotation_composition();
@@ -46,6 +46,12 @@ impl IsCompatibleWith for Vec<Term> {
     }
 }
 
+impl IsCompatibleWith for Vec<Term> {
+    fn is_compatible_with(&self, rhs: &Self) -> bool {
+        self.as_slice().is_compatible_with(rhs.as_slice())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::is_compatible_with::IsCompatibleWith;
@@ -18,7 +18,7 @@ impl Reducible for Term {
     }
 }
 
-impl Reducible for Vec<Term> {
+impl Reducible for [Term] {
     fn reduce_value(&self, value: f64) -> f64 {
         self.iter()
             .fold(1.0, |acc, term| acc * term.reduce_value(value))
@@ -6,6 +6,14 @@ pub(super) type Digits = i32;
 
 impl Visit<TermRule> for Digits {
     fn visit(pair: Pair<'_, TermRule>) -> Result<Self, Error> {
-        pair.as_span().as_str().parse::<Self>().map_err(Error::from)
+        let string = pair.as_span().as_str();
+
+        // The grammar only allows digits here, so the only way this fails is by overflowing.
+        string
+            .parse::<Self>()
+            .map_err(|_| Error::ExponentOutOfRange {
+                exponent: string.to_string(),
+                max: Self::MAX,
+            })
     }
 }
@@ -0,0 +1,526 @@
+//! A typed layer over `Measurement`, where the dimensions are part of the type.
+//!
+//! Mixing up dimensions (ex. passing a volume where a mass is expected) is then caught when
+//! compiling, instead of returning `Error::IncompatibleUnitTypes`.
+//!
+//! A `Quantity<D>` still holds any `Unit` with the dimensions `D` (ex. a `Quantity<Mass>` can be
+//! in `g`, `[lb_av]` or `[ston_av]`). Multiplying and dividing `Quantity`s works out the
+//! dimensions of the result when compiling:
+//!
+//! ```
+//! use wise_units::{
+//!     quantity::{Area, ArealDensity, Mass, Quantity},
+//!     Convertible,
+//! };
+//!
+//! let mass = Quantity::<Mass>::try_new(1200.0, "[lb_av]").unwrap();
+//! let area = Quantity::<Area>::try_new(40.0, "[acr_us]").unwrap();
+//!
+//! let rate: Quantity<ArealDensity> = (mass / area).unwrap();
+//! let rate = rate.convert_to("kg/har").unwrap();
+//! assert!((rate.value() - 33.626).abs() < 0.001);
+//! ```
+//!
+//! ```compile_fail
+//! use wise_units::quantity::{ArealDensity, Mass, Quantity, Volume};
+//!
+//! let mass = Quantity::<Mass>::try_new(1.0, "kg").unwrap();
+//! let volume = Quantity::<Volume>::try_new(1.0, "L").unwrap();
+//!
+//! // A density isn't an areal density.
+//! let rate: Quantity<ArealDensity> = (mass / volume).unwrap();
+//! ```
+//!
+//! Going between `Quantity` and `Measurement` is checked when running:
+//!
+//! ```
+//! use std::convert::TryFrom;
+//! use wise_units::{quantity::{Mass, Quantity}, Measurement};
+//!
+//! let measurement = Measurement::try_new(2.0, "L").unwrap();
+//! assert!(Quantity::<Mass>::try_from(measurement).is_err());
+//! ```
+//!
+use crate::{Composable, Composition, Convertible, Dimension, Error, Measurement, Unit};
+use std::{
+    convert::TryFrom,
+    fmt,
+    marker::PhantomData,
+    ops::{Add, Div, Mul, Sub},
+};
+use typenum::{Diff, Integer, Sum, N1, N2, N3, P1, P2, P3, Z0};
+
+/// Types that stand for a `Composition`.
+///
+pub trait Dimensions {
+    fn composition() -> Composition;
+}
+
+/// The exponents of each of the seven base dimensions, as `typenum` integers, in the order of the
+/// UCUM base units: length, time, mass, plane angle, temperature, electric charge, luminous
+/// intensity.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Dim<L, T, M, A, C, Q, F> {
+    exponents: PhantomData<(L, T, M, A, C, Q, F)>,
+}
+
+impl<L, T, M, A, C, Q, F> Dimensions for Dim<L, T, M, A, C, Q, F>
+where
+    L: Integer,
+    T: Integer,
+    M: Integer,
+    A: Integer,
+    C: Integer,
+    Q: Integer,
+    F: Integer,
+{
+    fn composition() -> Composition {
+        [
+            (Dimension::Length, L::I32),
+            (Dimension::Time, T::I32),
+            (Dimension::Mass, M::I32),
+            (Dimension::PlaneAngle, A::I32),
+            (Dimension::Temperature, C::I32),
+            (Dimension::ElectricCharge, Q::I32),
+            (Dimension::LuminousIntensity, F::I32),
+        ]
+        .iter()
+        .filter(|(_, exponent)| *exponent != 0)
+        .fold(
+            Composition::default(),
+            |composition, &(dimension, exponent)| composition.with(dimension, exponent),
+        )
+    }
+}
+
+impl<L1, T1, M1, A1, C1, Q1, F1, L2, T2, M2, A2, C2, Q2, F2> Mul<Dim<L2, T2, M2, A2, C2, Q2, F2>>
+    for Dim<L1, T1, M1, A1, C1, Q1, F1>
+where
+    L1: Add<L2>,
+    T1: Add<T2>,
+    M1: Add<M2>,
+    A1: Add<A2>,
+    C1: Add<C2>,
+    Q1: Add<Q2>,
+    F1: Add<F2>,
+{
+    type Output = Dim<
+        Sum<L1, L2>,
+        Sum<T1, T2>,
+        Sum<M1, M2>,
+        Sum<A1, A2>,
+        Sum<C1, C2>,
+        Sum<Q1, Q2>,
+        Sum<F1, F2>,
+    >;
+
+    fn mul(self, _rhs: Dim<L2, T2, M2, A2, C2, Q2, F2>) -> Self::Output {
+        Dim {
+            exponents: PhantomData,
+        }
+    }
+}
+
+impl<L1, T1, M1, A1, C1, Q1, F1, L2, T2, M2, A2, C2, Q2, F2> Div<Dim<L2, T2, M2, A2, C2, Q2, F2>>
+    for Dim<L1, T1, M1, A1, C1, Q1, F1>
+where
+    L1: Sub<L2>,
+    T1: Sub<T2>,
+    M1: Sub<M2>,
+    A1: Sub<A2>,
+    C1: Sub<C2>,
+    Q1: Sub<Q2>,
+    F1: Sub<F2>,
+{
+    type Output = Dim<
+        Diff<L1, L2>,
+        Diff<T1, T2>,
+        Diff<M1, M2>,
+        Diff<A1, A2>,
+        Diff<C1, C2>,
+        Diff<Q1, Q2>,
+        Diff<F1, F2>,
+    >;
+
+    fn div(self, _rhs: Dim<L2, T2, M2, A2, C2, Q2, F2>) -> Self::Output {
+        Dim {
+            exponents: PhantomData,
+        }
+    }
+}
+
+/// The dimensions of `D1` times `D2`.
+///
+pub type Product<D1, D2> = <D1 as Mul<D2>>::Output;
+
+/// The dimensions of `D1` divided by `D2`.
+///
+pub type Quotient<D1, D2> = <D1 as Div<D2>>::Output;
+
+pub type Dimensionless = Dim<Z0, Z0, Z0, Z0, Z0, Z0, Z0>;
+pub type Length = Dim<P1, Z0, Z0, Z0, Z0, Z0, Z0>;
+pub type Time = Dim<Z0, P1, Z0, Z0, Z0, Z0, Z0>;
+pub type Mass = Dim<Z0, Z0, P1, Z0, Z0, Z0, Z0>;
+pub type PlaneAngle = Dim<Z0, Z0, Z0, P1, Z0, Z0, Z0>;
+pub type Temperature = Dim<Z0, Z0, Z0, Z0, P1, Z0, Z0>;
+pub type ElectricCharge = Dim<Z0, Z0, Z0, Z0, Z0, P1, Z0>;
+pub type LuminousIntensity = Dim<Z0, Z0, Z0, Z0, Z0, Z0, P1>;
+
+pub type Area = Dim<P2, Z0, Z0, Z0, Z0, Z0, Z0>;
+pub type Volume = Dim<P3, Z0, Z0, Z0, Z0, Z0, Z0>;
+pub type Frequency = Dim<Z0, N1, Z0, Z0, Z0, Z0, Z0>;
+pub type Velocity = Dim<P1, N1, Z0, Z0, Z0, Z0, Z0>;
+pub type Acceleration = Dim<P1, N2, Z0, Z0, Z0, Z0, Z0>;
+pub type Force = Dim<P1, N2, P1, Z0, Z0, Z0, Z0>;
+pub type Pressure = Dim<N1, N2, P1, Z0, Z0, Z0, Z0>;
+pub type Energy = Dim<P2, N2, P1, Z0, Z0, Z0, Z0>;
+pub type Power = Dim<P2, N3, P1, Z0, Z0, Z0, Z0>;
+pub type Density = Dim<N3, Z0, P1, Z0, Z0, Z0, Z0>;
+pub type ArealDensity = Dim<N2, Z0, P1, Z0, Z0, Z0, Z0>;
+pub type VolumetricFlowRate = Dim<P3, N1, Z0, Z0, Z0, Z0, Z0>;
+pub type MassFlowRate = Dim<Z0, N1, P1, Z0, Z0, Z0, Z0>;
+
+/// A `Measurement` whose `Unit` is known to have the dimensions `D`.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Quantity<D> {
+    measurement: Measurement,
+    dimensions: PhantomData<D>,
+}
+
+impl<D: Dimensions> Quantity<D> {
+    /// # Errors
+    ///
+    /// Returns `Error::IncompatibleUnitTypes` if `unit` doesn't have the dimensions `D`, or
+    /// `Error::NonFiniteValue` if `value` isn't finite.
+    ///
+    pub fn new(value: f64, unit: Unit) -> Result<Self, Error> {
+        Self::try_from(Measurement::new(value, unit)?)
+    }
+
+    /// # Errors
+    ///
+    /// Returns an `Error` if `expression` can't be parsed, or for the same reasons as `new()`.
+    ///
+    pub fn try_new(value: f64, expression: &str) -> Result<Self, Error> {
+        Self::try_from(Measurement::try_new(value, expression)?)
+    }
+
+    #[must_use]
+    pub const fn value(&self) -> f64 {
+        self.measurement.value()
+    }
+
+    #[must_use]
+    pub const fn unit(&self) -> &Unit {
+        self.measurement.unit()
+    }
+
+    #[must_use]
+    pub const fn measurement(&self) -> &Measurement {
+        &self.measurement
+    }
+
+    #[must_use]
+    pub fn into_measurement(self) -> Measurement {
+        self.measurement
+    }
+
+    /// Wraps a `Measurement` that's already known to have the dimensions `D`.
+    ///
+    const fn from_measurement_unchecked(measurement: Measurement) -> Self {
+        Self {
+            measurement,
+            dimensions: PhantomData,
+        }
+    }
+}
+
+impl<D: Dimensions> TryFrom<Measurement> for Quantity<D> {
+    type Error = Error;
+
+    fn try_from(measurement: Measurement) -> Result<Self, Self::Error> {
+        let expected = D::composition();
+
+        if measurement.composition() == expected {
+            Ok(Self::from_measurement_unchecked(measurement))
+        } else {
+            Err(Error::IncompatibleUnitTypes {
+                lhs: measurement.unit().expression(),
+                rhs: expected.to_string(),
+            })
+        }
+    }
+}
+
+impl<D: Dimensions> TryFrom<&Measurement> for Quantity<D> {
+    type Error = Error;
+
+    fn try_from(measurement: &Measurement) -> Result<Self, Self::Error> {
+        Self::try_from(measurement.clone())
+    }
+}
+
+impl<D> From<Quantity<D>> for Measurement {
+    fn from(quantity: Quantity<D>) -> Self {
+        quantity.measurement
+    }
+}
+
+impl<D> AsRef<Measurement> for Quantity<D> {
+    fn as_ref(&self) -> &Measurement {
+        &self.measurement
+    }
+}
+
+impl<D> fmt::Display for Quantity<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.measurement.fmt(f)
+    }
+}
+
+/// Converting only ever changes the `Unit`; `Unit`s with other dimensions are refused, so the
+/// result has the same `D`.
+///
+impl<D, U> Convertible<U> for Quantity<D>
+where
+    D: Dimensions,
+    Measurement: Convertible<U, Output = Measurement, ConversionError = Error>,
+{
+    type Output = Self;
+    type ConversionError = Error;
+
+    fn convert_to(&self, unit: U) -> Result<Self, Error> {
+        self.measurement
+            .convert_to(unit)
+            .map(Self::from_measurement_unchecked)
+    }
+}
+
+//-----------------------------------------------------------------------------
+// impl Add, Sub
+//-----------------------------------------------------------------------------
+impl<D: Dimensions> Add for Quantity<D> {
+    type Output = Result<Self, Error>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl<D: Dimensions> Add for &Quantity<D> {
+    type Output = Result<Quantity<D>, Error>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        (&self.measurement + &rhs.measurement).map(Quantity::from_measurement_unchecked)
+    }
+}
+
+impl<D: Dimensions> Sub for Quantity<D> {
+    type Output = Result<Self, Error>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+impl<D: Dimensions> Sub for &Quantity<D> {
+    type Output = Result<Quantity<D>, Error>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        (&self.measurement - &rhs.measurement).map(Quantity::from_measurement_unchecked)
+    }
+}
+
+//-----------------------------------------------------------------------------
+// impl Mul, Div
+//-----------------------------------------------------------------------------
+impl<D1, D2> Mul<Quantity<D2>> for Quantity<D1>
+where
+    D1: Dimensions + Mul<D2>,
+    D2: Dimensions,
+    Product<D1, D2>: Dimensions,
+{
+    type Output = Result<Quantity<Product<D1, D2>>, Error>;
+
+    fn mul(self, rhs: Quantity<D2>) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl<'a, D1, D2> Mul<&'a Quantity<D2>> for &'a Quantity<D1>
+where
+    D1: Dimensions + Mul<D2>,
+    D2: Dimensions,
+    Product<D1, D2>: Dimensions,
+{
+    type Output = Result<Quantity<Product<D1, D2>>, Error>;
+
+    fn mul(self, rhs: &'a Quantity<D2>) -> Self::Output {
+        (&self.measurement * &rhs.measurement).map(Quantity::from_measurement_unchecked)
+    }
+}
+
+impl<D1, D2> Div<Quantity<D2>> for Quantity<D1>
+where
+    D1: Dimensions + Div<D2>,
+    D2: Dimensions,
+    Quotient<D1, D2>: Dimensions,
+{
+    type Output = Result<Quantity<Quotient<D1, D2>>, Error>;
+
+    fn div(self, rhs: Quantity<D2>) -> Self::Output {
+        &self / &rhs
+    }
+}
+
+impl<'a, D1, D2> Div<&'a Quantity<D2>> for &'a Quantity<D1>
+where
+    D1: Dimensions + Div<D2>,
+    D2: Dimensions,
+    Quotient<D1, D2>: Dimensions,
+{
+    type Output = Result<Quantity<Quotient<D1, D2>>, Error>;
+
+    fn div(self, rhs: &'a Quantity<D2>) -> Self::Output {
+        (&self.measurement / &rhs.measurement).map(Quantity::from_measurement_unchecked)
+    }
+}
+
+impl<D: Dimensions> Mul<f64> for Quantity<D> {
+    type Output = Result<Self, Error>;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        (self.measurement * rhs).map(Self::from_measurement_unchecked)
+    }
+}
+
+impl<D: Dimensions> Div<f64> for Quantity<D> {
+    type Output = Result<Self, Error>;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        (self.measurement / rhs).map(Self::from_measurement_unchecked)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use std::str::FromStr;
+
+    #[test]
+    fn validate_composition() {
+        assert_eq!(Dimensionless::composition(), Composition::default());
+        assert_eq!(Length::composition(), Composition::new_length(1));
+        assert_eq!(
+            ArealDensity::composition(),
+            (Composition::new_mass(1) * Composition::new_length(-2)).unwrap()
+        );
+        assert_eq!(
+            Energy::composition(),
+            Unit::from_str("J").unwrap().composition()
+        );
+        assert_eq!(
+            Power::composition(),
+            Unit::from_str("W").unwrap().composition()
+        );
+        assert_eq!(
+            Pressure::composition(),
+            Unit::from_str("Pa").unwrap().composition()
+        );
+        assert_eq!(
+            Length::composition(),
+            Unit::from_str("L/har").unwrap().composition()
+        );
+    }
+
+    #[test]
+    fn validate_new() {
+        let mass = Quantity::<Mass>::try_new(10.0, "[lb_av]").unwrap();
+        assert_relative_eq!(mass.value(), 10.0);
+        assert_eq!(mass.unit().expression(), "[lb_av]");
+
+        let mass = Quantity::<Mass>::new(1.0, Unit::from_str("[ston_av]").unwrap());
+        assert!(mass.is_ok());
+
+        assert_eq!(
+            Quantity::<Mass>::try_new(10.0, "L").unwrap_err(),
+            Error::IncompatibleUnitTypes {
+                lhs: "L".to_string(),
+                rhs: "M".to_string()
+            }
+        );
+        assert!(Quantity::<Mass>::try_new(f64::NAN, "g").is_err());
+        assert!(Quantity::<Mass>::try_new(1.0, "not_a_unit").is_err());
+    }
+
+    #[test]
+    fn validate_measurement_round_trip() {
+        let measurement = Measurement::try_new(3.0, "[gal_us]").unwrap();
+        let volume = Quantity::<Volume>::try_from(&measurement).unwrap();
+        assert_eq!(volume.measurement(), &measurement);
+        assert_eq!(Measurement::from(volume), measurement);
+
+        assert!(Quantity::<Area>::try_from(measurement).is_err());
+    }
+
+    #[test]
+    fn validate_convert_to() {
+        let area = Quantity::<Area>::try_new(1.0, "[acr_us]").unwrap();
+        let area = area.convert_to("har").unwrap();
+        assert_relative_eq!(area.value(), 0.404_687_260_987_425_2, max_relative = 1e-12);
+
+        let unit = Unit::from_str("m2").unwrap();
+        let area = area.convert_to(&unit).unwrap();
+        assert_relative_eq!(area.value(), 4_046.872_609_874_252, max_relative = 1e-12);
+
+        assert!(area.convert_to("m3").is_err());
+    }
+
+    #[test]
+    fn validate_add_sub() {
+        let a = Quantity::<Length>::try_new(1.0, "km").unwrap();
+        let b = Quantity::<Length>::try_new(500.0, "m").unwrap();
+
+        let sum = (&a + &b).unwrap();
+        assert_relative_eq!(sum.value(), 1.5);
+        assert_eq!(sum.unit().expression(), "km");
+
+        let difference = (a - b).unwrap();
+        assert_relative_eq!(difference.value(), 0.5);
+    }
+
+    #[test]
+    fn validate_mul_div() {
+        let length = Quantity::<Length>::try_new(20.0, "m").unwrap();
+        let width = Quantity::<Length>::try_new(5.0, "m").unwrap();
+
+        let area: Quantity<Area> = (&length * &width).unwrap();
+        assert_relative_eq!(area.value(), 100.0);
+
+        let time = Quantity::<Time>::try_new(10.0, "s").unwrap();
+        let velocity: Quantity<Velocity> = (length / time).unwrap();
+        assert_relative_eq!(velocity.value(), 2.0);
+
+        let mass = Quantity::<Mass>::try_new(100.0, "kg").unwrap();
+        let rate: Quantity<ArealDensity> = (mass / area).unwrap();
+        assert_relative_eq!(rate.value(), 1.0);
+
+        let doubled = (rate * 2.0).unwrap();
+        assert_relative_eq!(doubled.value(), 2.0);
+
+        assert_eq!((doubled / 0.0).unwrap_err(), Error::DivideByZero);
+    }
+
+    #[test]
+    fn validate_dimensionless() {
+        let a = Quantity::<Length>::try_new(1.0, "km").unwrap();
+        let b = Quantity::<Length>::try_new(250.0, "m").unwrap();
+
+        let ratio: Quantity<Dimensionless> = (a / b).unwrap();
+        let ratio = ratio.convert_to("1").unwrap();
+        assert_relative_eq!(ratio.value(), 4.0);
+    }
+}
@@ -0,0 +1,202 @@
+//! Conversions between rates given per length of row (ex. in-furrow products, labeled in
+//! `[foz_us]/1000[ft_i]`) and rates given per area (ex. `[foz_us]/[acr_us]`), for a given
+//! spacing between rows.
+//!
+use crate::{
+    parser::{Composable, Composition},
+    Convertible, Error, Measurement, Unit,
+};
+
+/// The distance between crop rows, which relates a length of row to the area it covers.
+///
+/// ```
+/// use std::str::FromStr;
+/// use wise_units::{row_spacing::RowSpacing, Measurement, Unit};
+///
+/// let spacing = RowSpacing::new(Measurement::try_new(30.0, "[in_i]").unwrap()).unwrap();
+///
+/// let per_row = Measurement::try_new(1.0, "[foz_us]/1000[ft_i]").unwrap();
+/// let per_acre = spacing
+///     .convert(&per_row, &Unit::from_str("[foz_us]/[acr_us]").unwrap())
+///     .unwrap();
+///
+/// assert!((per_acre.value() - 17.424).abs() < 0.001);
+/// ```
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct RowSpacing {
+    spacing: Measurement,
+}
+
+impl RowSpacing {
+    /// # Errors
+    ///
+    /// Returns an `Error::IncompatibleUnitTypes` if `spacing` isn't a length, or an
+    /// `Error::DivideByZero` if it's 0.
+    ///
+    pub fn new(spacing: Measurement) -> Result<Self, Error> {
+        if spacing.unit().composition() != Composition::new_length(1) {
+            return Err(Error::IncompatibleUnitTypes {
+                lhs: spacing.unit().expression(),
+                rhs: "m".to_string(),
+            });
+        }
+
+        if spacing.value() == 0.0 {
+            return Err(Error::DivideByZero);
+        }
+
+        Ok(Self { spacing })
+    }
+
+    #[must_use]
+    pub const fn spacing(&self) -> &Measurement {
+        &self.spacing
+    }
+
+    /// Converts `rate` to `unit`, where one is per length of row and the other is per area. If
+    /// the two are already compatible, this is the same as `Measurement::convert_to()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::IncompatibleUnitTypes` if `rate` and `unit` aren't (something)/length
+    /// and (something)/area (or the reverse) of the same something.
+    ///
+    pub fn convert(&self, rate: &Measurement, unit: &Unit) -> Result<Measurement, Error> {
+        let rate_composition = rate.unit().composition();
+        let unit_composition = unit.composition();
+
+        if rate_composition == unit_composition {
+            return rate.convert_to(unit);
+        }
+
+        let per_length = Some(Composition::new_length(-1));
+        let per_area = Some(Composition::new_length(-2));
+        let length = Composition::new_length(1);
+
+        match (per_composition(rate.unit()), per_composition(unit)) {
+            (from, to)
+                if from == per_length
+                    && to == per_area
+                    && Some(rate_composition) == unit_composition * length =>
+            {
+                (rate / &self.spacing)?.convert_to(unit)
+            }
+            (from, to)
+                if from == per_area
+                    && to == per_length
+                    && rate_composition * length == Some(unit_composition) =>
+            {
+                (rate * &self.spacing)?.convert_to(unit)
+            }
+            _ => Err(Error::IncompatibleUnitTypes {
+                lhs: rate.unit().expression(),
+                rhs: unit.expression(),
+            }),
+        }
+    }
+}
+
+/// The `Composition` of the `Term`s that `unit` is "per", ex. `L-2` for `kg/har`, or `None` if it
+/// overflows.
+///
+fn per_composition(unit: &Unit) -> Option<Composition> {
+    unit.terms()
+        .iter()
+        .filter(|term| term.exponent.map_or(false, |exponent| exponent < 0))
+        .try_fold(Composition::default(), |acc, term| {
+            acc * term.checked_composition()?
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RowSpacing;
+    use crate::{Error, Measurement, Unit};
+    use approx::assert_relative_eq;
+    use std::str::FromStr;
+
+    fn thirty_inch() -> RowSpacing {
+        RowSpacing::new(Measurement::try_new(30.0, "[in_i]").unwrap()).unwrap()
+    }
+
+    fn convert(spacing: &RowSpacing, value: f64, from: &str, to: &str) -> Measurement {
+        let rate = Measurement::try_new(value, from).unwrap();
+        spacing
+            .convert(&rate, &Unit::from_str(to).unwrap())
+            .unwrap()
+    }
+
+    #[test]
+    fn validate_new() {
+        assert!(RowSpacing::new(Measurement::try_new(76.0, "cm").unwrap()).is_ok());
+        assert_eq!(
+            RowSpacing::new(Measurement::try_new(30.0, "[in_i]2").unwrap()).unwrap_err(),
+            Error::IncompatibleUnitTypes {
+                lhs: "[in_i]2".to_string(),
+                rhs: "m".to_string()
+            }
+        );
+        assert_eq!(
+            RowSpacing::new(Measurement::try_new(0.0, "[in_i]").unwrap()).unwrap_err(),
+            Error::DivideByZero
+        );
+    }
+
+    #[test]
+    fn validate_row_to_area() {
+        // 43,560 ft² per acre / 2.5 ft between rows = 17,424 ft of row per acre.
+        let converted = convert(
+            &thirty_inch(),
+            1.0,
+            "[foz_us]/1000[ft_i]",
+            "[foz_us]/[acr_us]",
+        );
+        assert_relative_eq!(converted.value(), 17.424, max_relative = 1e-5);
+        assert_eq!(converted.unit().expression(), "[foz_us]/[acr_us]");
+    }
+
+    #[test]
+    fn validate_area_to_row() {
+        let converted = convert(
+            &thirty_inch(),
+            17.424,
+            "[foz_us]/[acr_us]",
+            "[foz_us]/1000[ft_i]",
+        );
+        assert_relative_eq!(converted.value(), 1.0, max_relative = 1e-5);
+    }
+
+    #[test]
+    fn validate_other_units() {
+        let spacing = RowSpacing::new(Measurement::try_new(0.76, "m").unwrap()).unwrap();
+
+        // 1 g/m of row over 0.76 m rows is 1/0.76 g/m² = 13.157... kg/har.
+        let converted = convert(&spacing, 1.0, "g/m", "kg/har");
+        assert_relative_eq!(converted.value(), 10.0 / 0.76, max_relative = 1e-12);
+
+        let converted = convert(&spacing, 10.0 / 0.76, "kg/har", "g/m");
+        assert_relative_eq!(converted.value(), 1.0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn validate_same_composition() {
+        let converted = convert(&thirty_inch(), 1.0, "L/har", "mL/m2");
+        assert_relative_eq!(converted.value(), 0.1, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn validate_incompatible() {
+        let rate = Measurement::try_new(1.0, "[foz_us]/[ft_i]").unwrap();
+
+        assert!(thirty_inch()
+            .convert(&rate, &Unit::from_str("[lb_av]/[acr_us]").unwrap())
+            .is_err());
+        assert!(thirty_inch()
+            .convert(&rate, &Unit::from_str("[foz_us]").unwrap())
+            .is_err());
+        assert!(thirty_inch()
+            .convert(&rate, &Unit::from_str("[foz_us]/[ft_i]3").unwrap())
+            .is_err());
+    }
+}
@@ -1,7 +1,10 @@
+mod annotation;
 mod as_fraction;
+mod builder;
 mod composable;
 mod deref;
 mod display;
+mod explain;
 mod field_eq;
 mod from_str;
 mod invert;
@@ -9,8 +12,11 @@ mod is_compatible_with;
 mod ops;
 mod partial_eq;
 mod partial_ord;
+mod pow;
 mod reducible;
 mod term_reducing;
+mod terms;
+mod to_base_units;
 mod to_reduced;
 
 #[allow(clippy::module_name_repetitions)]
@@ -23,12 +29,18 @@ mod serde;
 #[cfg(feature = "cffi")]
 use ffi_derive::FFI;
 
-use crate::parser::Term;
+use self::terms::Terms;
+use crate::{
+    parser::{checked_composition, Term},
+    Error,
+};
+
+pub use self::{builder::UnitBuilder, explain::Explanation};
 
 #[cfg_attr(feature = "cffi", derive(FFI), ffi(custom = "src/unit/custom_ffi.rs"))]
 #[derive(Clone, Debug)]
 pub struct Unit {
-    terms: Vec<Term>,
+    terms: Terms,
 }
 
 /// A `Unit` is the piece of data that represents a *valid* UCUM unit or
@@ -60,24 +72,77 @@ impl Unit {
     /// assert_eq!(km_unit.to_string(), "km");
     /// ```
     ///
+    /// Unlike parsing, this doesn't check `terms`; if any of their exponents, or those of their
+    /// `Composition`, overflow an `i32`, methods like `composition()` will panic. Use `try_new()`
+    /// (or `UnitBuilder`) for `Term`s that haven't been checked.
+    ///
     #[must_use]
     pub fn new(terms: Vec<Term>) -> Self {
-        Self { terms }
+        Self {
+            terms: Terms::from(terms),
+        }
     }
 
-    /// Creates a new `Unit` that's equivalent to "1".
+    /// Like `new()`, but checks `terms` the way parsing does.
+    ///
+    /// ```
+    /// use wise_units::{Atom, Term, Unit};
+    ///
+    /// let m2 = Term { exponent: Some(2), ..Term::new(None, Some(Atom::Meter)) };
+    /// assert!(Unit::try_new(vec![m2]).is_ok());
+    ///
+    /// let overflowing = Term { exponent: Some(i32::MAX), ..Term::new(None, Some(Atom::Are)) };
+    /// assert!(Unit::try_new(vec![overflowing]).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::InvalidUnit` if any of the `Term`s' exponents, or those of their
+    /// `Composition`, overflow an `i32`.
+    ///
+    pub fn try_new(terms: Vec<Term>) -> Result<Self, Error> {
+        if checked_composition(&terms).is_none() {
+            return Err(Error::InvalidUnit(
+                "the unit's exponents overflow".to_string(),
+            ));
+        }
+
+        Ok(Self::new(terms))
+    }
+
+    /// Creates a `Unit` that borrows its `Term`s, so it can be built in a `const` or `static`
+    /// (see the `units` module for ones that are predefined). Changing the `Unit` copies the
+    /// `Term`s first. Unlike `new()`, the result doesn't cache its `Composition`, scalar, etc.
+    ///
+    /// ```
+    /// use wise_units::{Atom, Prefix, Term, Unit};
+    ///
+    /// const KILOMETER_TERMS: &[Term] = &[Term::new(Some(Prefix::Kilo), Some(Atom::Meter))];
+    /// const KILOMETER: Unit = Unit::new_static(KILOMETER_TERMS);
+    ///
+    /// assert_eq!(KILOMETER.expression(), "km");
+    /// ```
     ///
     #[must_use]
-    pub fn new_unity() -> Self {
+    pub const fn new_static(terms: &'static [Term]) -> Self {
         Self {
-            terms: vec![Term::new_unity()],
+            terms: Terms::Static(terms),
         }
     }
 
+    /// Creates a new `Unit` that's equivalent to "1".
+    ///
+    #[must_use]
+    pub const fn new_unity() -> Self {
+        const UNITY: &[Term] = &[Term::new_unity()];
+
+        Self::new_static(UNITY)
+    }
+
     /// Accessor for the `Term`s used that make up this `Unit`.
     ///
     #[must_use]
-    pub const fn terms(&self) -> &Vec<Term> {
+    pub fn terms(&self) -> &[Term] {
         &self.terms
     }
 
@@ -141,6 +206,13 @@ mod tests {
     use super::*;
     use std::str::FromStr;
 
+    #[test]
+    fn validate_send_and_sync() {
+        fn assert_send_and_sync<T: Send + Sync>() {}
+
+        assert_send_and_sync::<Unit>();
+    }
+
     #[test]
     fn validate_is_unity() {
         let unit = Unit::new_unity();
@@ -0,0 +1,72 @@
+use super::Unit;
+use crate::parser::Term;
+
+impl Unit {
+    /// The first annotation found in `self`'s `Term`s, if any.
+    ///
+    pub(crate) fn first_annotation(&self) -> Option<&str> {
+        self.terms
+            .iter()
+            .find_map(|term| term.annotation.as_deref())
+    }
+
+    /// A copy of `self` with any `annotation`s removed; `Term`s that consisted only of the
+    /// annotation (ex. `{corn}`) are dropped.
+    ///
+    pub(crate) fn without_annotation(&self, annotation: &str) -> Self {
+        let terms: Vec<Term> = self
+            .terms
+            .iter()
+            .filter_map(|term| {
+                if term.annotation.as_deref() != Some(annotation) {
+                    return Some(term.clone());
+                }
+
+                let stripped = Term {
+                    annotation: None,
+                    ..term.clone()
+                };
+
+                if stripped.is_unity() && stripped.factor.is_none() {
+                    None
+                } else {
+                    Some(stripped)
+                }
+            })
+            .collect();
+
+        if terms.is_empty() {
+            Self::new_unity()
+        } else {
+            Self::new(terms)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Unit;
+    use std::str::FromStr;
+
+    #[test]
+    fn validate_first_annotation() {
+        let unit = Unit::from_str("kg{P2O5}/har").unwrap();
+        assert_eq!(unit.first_annotation(), Some("P2O5"));
+
+        let unit = Unit::from_str("kg/har").unwrap();
+        assert_eq!(unit.first_annotation(), None);
+    }
+
+    #[test]
+    fn validate_without_annotation() {
+        let unit = Unit::from_str("kg{P2O5}/har").unwrap();
+        assert_eq!(unit.without_annotation("P2O5").expression(), "kg/har");
+        assert_eq!(unit.without_annotation("K2O").expression(), "kg{P2O5}/har");
+
+        let unit = Unit::from_str("{corn}").unwrap();
+        assert_eq!(unit.without_annotation("corn").expression(), "1");
+
+        let unit = Unit::from_str("{corn}/[acr_us]").unwrap();
+        assert_eq!(unit.without_annotation("corn").expression(), "/[acr_us]");
+    }
+}
@@ -0,0 +1,390 @@
+use super::Unit;
+use crate::{
+    parser::{Atom, Prefix, Term},
+    ucum_unit::UcumUnit,
+    Error,
+};
+
+/// Builds a `Unit` one `Term` at a time, without parsing a string. `atom()` and `per()` start a
+/// new `Term` in the numerator or denominator; `prefix()`, `factor()`, `pow()` and `annotation()`
+/// then modify the `Term` most recently started.
+///
+/// Mistakes (a prefix without an atom, a zero exponent, etc.) are caught as they're made, but
+/// only reported by `build()`, so calls can be chained. Only the first mistake is reported.
+///
+/// ```
+/// use std::str::FromStr;
+/// use wise_units::{Atom, Prefix, Unit, UnitBuilder};
+///
+/// let unit = UnitBuilder::new()
+///     .atom(Atom::Gram)
+///     .prefix(Prefix::Kilo)
+///     .per(Atom::AcreUS)
+///     .pow(2)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(unit, Unit::from_str("kg/[acr_us]2").unwrap());
+/// assert_eq!(unit.expression(), "kg/[acr_us]2");
+///
+/// assert!(UnitBuilder::new().atom(Atom::Meter).pow(0).build().is_err());
+/// ```
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UnitBuilder {
+    terms: Vec<Term>,
+    error: Option<Error>,
+}
+
+impl UnitBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts a new `Term` for `atom` in the numerator.
+    ///
+    #[must_use]
+    pub fn atom(self, atom: Atom) -> Self {
+        self.push(Term::new(None, Some(atom)))
+    }
+
+    /// Starts a new `Term` for `atom` in the denominator.
+    ///
+    #[must_use]
+    pub fn per(self, atom: Atom) -> Self {
+        let mut term = Term::new(None, Some(atom));
+        term.exponent = Some(-1);
+
+        self.push(term)
+    }
+
+    /// Sets the prefix of the current `Term`, which must have a metric atom and no prefix yet.
+    ///
+    #[must_use]
+    pub fn prefix(mut self, prefix: Prefix) -> Self {
+        let message = match self.current() {
+            Some(Term {
+                atom: Some(atom),
+                prefix: None,
+                ..
+            }) if atom.is_metric() => None,
+            Some(Term {
+                atom: Some(atom),
+                prefix: None,
+                ..
+            }) => Some(format!(
+                "{} isn't metric, so can't take the prefix {}",
+                atom, prefix
+            )),
+            Some(Term {
+                atom: Some(_),
+                prefix: Some(existing),
+                ..
+            }) => Some(format!(
+                "can't add the prefix {} to a term already prefixed with {}",
+                prefix, existing
+            )),
+            _ => Some(format!("the prefix {} needs an atom", prefix)),
+        };
+
+        match message {
+            Some(message) => self.fail(message),
+            None => {
+                if let Some(term) = self.current_mut() {
+                    term.prefix = Some(prefix);
+                }
+
+                self
+            }
+        }
+    }
+
+    /// Sets the factor of the current `Term` (ex. the 1000 in `/1000[ft_i]`). If no `Term` has
+    /// been started, this starts one that's just the factor.
+    ///
+    #[must_use]
+    pub fn factor(mut self, factor: u32) -> Self {
+        if factor == 0 {
+            return self.fail("factors must be greater than 0".to_string());
+        }
+
+        match self.current_mut() {
+            Some(Term {
+                factor: Some(existing),
+                ..
+            }) => {
+                let message = format!("the term already has the factor {}", existing);
+                self.fail(message)
+            }
+            Some(term) => {
+                term.factor = Some(factor);
+                self
+            }
+            None => self.push(Term {
+                factor: Some(factor),
+                ..Term::default()
+            }),
+        }
+    }
+
+    /// Raises the current `Term` to the power of `exponent`. For a `Term` started with `per()`,
+    /// the exponent stays negative (ex. `.per(Atom::Meter).pow(2)` is `/m2`).
+    ///
+    #[must_use]
+    pub fn pow(mut self, exponent: i32) -> Self {
+        if exponent == 0 {
+            return self.fail("exponents can't be 0".to_string());
+        }
+
+        match self.current_mut() {
+            Some(term) => match term.exponent.unwrap_or(1).checked_mul(exponent) {
+                Some(new_exponent) => {
+                    term.exponent = Some(new_exponent);
+                    self
+                }
+                None => {
+                    let message =
+                        format!("raising {} to the power of {} overflows", term, exponent);
+                    self.fail(message)
+                }
+            },
+            None => self.fail(format!("no term to raise to the power of {}", exponent)),
+        }
+    }
+
+    /// Sets the annotation of the current `Term`. If no `Term` has been started, this starts one
+    /// that's just the annotation (ex. `{tot}`). Annotations may contain any printable ASCII
+    /// character but curly braces.
+    ///
+    #[must_use]
+    pub fn annotation(mut self, annotation: &str) -> Self {
+        let is_valid = !annotation.is_empty()
+            && annotation
+                .bytes()
+                .all(|byte| byte.is_ascii_graphic() && byte != b'{' && byte != b'}');
+
+        if !is_valid {
+            return self.fail(format!("invalid annotation {:?}", annotation));
+        }
+
+        match self.current_mut() {
+            Some(Term {
+                annotation: Some(existing),
+                ..
+            }) => {
+                let message = format!("the term is already annotated with {:?}", existing);
+                self.fail(message)
+            }
+            Some(term) => {
+                term.annotation = Some(annotation.to_string());
+                self
+            }
+            None => self.push(Term {
+                annotation: Some(annotation.to_string()),
+                ..Term::default()
+            }),
+        }
+    }
+
+    /// Builds the `Unit`. Exponents and factors of 1 are dropped from each `Term`, so the result
+    /// is the same as parsing its `expression()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidUnit` for the first mistake made while building, if no `Term`s
+    /// were added, or if the `Unit`'s exponents overflow an `i32` (ex. an `Atom::Are`, which is an
+    /// area, raised to the power of 2,000,000,000).
+    ///
+    pub fn build(self) -> Result<Unit, Error> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+
+        if self.terms.is_empty() {
+            return Err(Error::InvalidUnit(
+                "a unit needs at least one term".to_string(),
+            ));
+        }
+
+        let terms = self
+            .terms
+            .into_iter()
+            .map(|mut term| {
+                if term.exponent == Some(1) {
+                    term.exponent = None;
+                }
+
+                if term.atom.is_some() && term.factor == Some(1) {
+                    term.factor = None;
+                }
+
+                term
+            })
+            .collect::<Vec<Term>>();
+
+        Unit::try_new(terms)
+    }
+
+    fn current(&self) -> Option<&Term> {
+        self.terms.last()
+    }
+
+    fn current_mut(&mut self) -> Option<&mut Term> {
+        self.terms.last_mut()
+    }
+
+    fn push(mut self, term: Term) -> Self {
+        if self.error.is_none() {
+            self.terms.push(term);
+        }
+
+        self
+    }
+
+    fn fail(mut self, message: String) -> Self {
+        if self.error.is_none() {
+            self.error = Some(Error::InvalidUnit(message));
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnitBuilder;
+    use crate::{
+        parser::{Atom, Prefix},
+        Error, Unit,
+    };
+    use std::str::FromStr;
+
+    fn assert_builds(builder: UnitBuilder, expression: &str) {
+        let unit = builder.build().unwrap();
+
+        assert_eq!(unit.terms(), Unit::from_str(expression).unwrap().terms());
+        assert_eq!(unit.expression(), expression);
+    }
+
+    fn assert_invalid(builder: UnitBuilder) {
+        assert!(matches!(builder.build(), Err(Error::InvalidUnit(_))));
+    }
+
+    #[test]
+    fn validate_build() {
+        assert_builds(UnitBuilder::new().atom(Atom::Meter), "m");
+        assert_builds(
+            UnitBuilder::new().atom(Atom::Meter).prefix(Prefix::Kilo),
+            "km",
+        );
+        assert_builds(
+            UnitBuilder::new()
+                .atom(Atom::Meter)
+                .per(Atom::Second)
+                .pow(2),
+            "m/s2",
+        );
+        assert_builds(
+            UnitBuilder::new()
+                .atom(Atom::Gram)
+                .prefix(Prefix::Kilo)
+                .atom(Atom::Meter)
+                .pow(2)
+                .per(Atom::Second)
+                .pow(2),
+            "kg.m2/s2",
+        );
+        assert_builds(UnitBuilder::new().per(Atom::Meter), "/m");
+        assert_builds(UnitBuilder::new().atom(Atom::Meter).pow(1), "m");
+        assert_builds(UnitBuilder::new().atom(Atom::Meter).pow(-1).pow(-1), "m");
+    }
+
+    #[test]
+    fn validate_build_with_factors() {
+        assert_builds(
+            UnitBuilder::new()
+                .atom(Atom::FluidOunceUS)
+                .per(Atom::FootInternational)
+                .factor(1000),
+            "[foz_us]/1000[ft_i]",
+        );
+        assert_builds(UnitBuilder::new().factor(10).per(Atom::Meter), "10/m");
+        assert_builds(UnitBuilder::new().atom(Atom::Meter).factor(1), "m");
+    }
+
+    #[test]
+    fn validate_build_with_annotations() {
+        assert_builds(
+            UnitBuilder::new()
+                .atom(Atom::Gram)
+                .prefix(Prefix::Kilo)
+                .annotation("P2O5")
+                .per(Atom::Are)
+                .prefix(Prefix::Hecto),
+            "kg{P2O5}/har",
+        );
+        assert_builds(UnitBuilder::new().annotation("tot"), "{tot}");
+        assert_builds(
+            UnitBuilder::new()
+                .factor(10)
+                .annotation("cells")
+                .per(Atom::Liter),
+            "10{cells}/l",
+        );
+    }
+
+    #[test]
+    fn validate_build_invalid() {
+        assert_invalid(UnitBuilder::new());
+        assert_invalid(UnitBuilder::new().prefix(Prefix::Kilo));
+        assert_invalid(UnitBuilder::new().factor(10).prefix(Prefix::Kilo));
+        assert_invalid(
+            UnitBuilder::new()
+                .atom(Atom::FootInternational)
+                .prefix(Prefix::Kilo),
+        );
+        assert_invalid(
+            UnitBuilder::new()
+                .atom(Atom::Meter)
+                .prefix(Prefix::Kilo)
+                .prefix(Prefix::Milli),
+        );
+        assert_invalid(UnitBuilder::new().atom(Atom::Meter).pow(0));
+        assert_invalid(UnitBuilder::new().pow(2));
+        assert_invalid(UnitBuilder::new().atom(Atom::Meter).pow(i32::MAX).pow(2));
+        assert_invalid(UnitBuilder::new().atom(Atom::Are).pow(2_000_000_000));
+        assert_invalid(
+            UnitBuilder::new()
+                .atom(Atom::Meter)
+                .pow(i32::MAX)
+                .atom(Atom::Meter),
+        );
+        assert_invalid(UnitBuilder::new().atom(Atom::Meter).pow(i32::MIN));
+        assert_invalid(UnitBuilder::new().factor(0));
+        assert_invalid(UnitBuilder::new().atom(Atom::Meter).factor(2).factor(3));
+        assert_invalid(UnitBuilder::new().atom(Atom::Meter).annotation(""));
+        assert_invalid(UnitBuilder::new().atom(Atom::Meter).annotation("a}b"));
+        assert_invalid(UnitBuilder::new().atom(Atom::Meter).annotation("µ"));
+        assert_invalid(
+            UnitBuilder::new()
+                .atom(Atom::Meter)
+                .annotation("a")
+                .annotation("b"),
+        );
+    }
+
+    #[test]
+    fn validate_build_reports_first_error() {
+        let result = UnitBuilder::new()
+            .prefix(Prefix::Kilo)
+            .atom(Atom::Meter)
+            .pow(0)
+            .build();
+
+        assert_eq!(
+            result,
+            Err(Error::InvalidUnit("the prefix k needs an atom".to_string()))
+        );
+    }
+}
@@ -26,10 +26,12 @@ mod tests {
             $expected_dimension_variant:ident,
             $expected_dimension_value:expr
         ) => {
-            $composition.insert(
-                Dimension::$expected_dimension_variant,
-                $expected_dimension_value,
-            );
+            $composition
+                .insert(
+                    Dimension::$expected_dimension_variant,
+                    $expected_dimension_value,
+                )
+                .unwrap();
         };
     }
 
@@ -83,7 +83,7 @@ fn extract_denominator_atom(term: &Term, term_string: &mut String) {
         }
 
         if let Some(exponent) = term.exponent {
-            let ex_abs = exponent.abs();
+            let ex_abs = exponent.unsigned_abs();
 
             if ex_abs == 1 {
                 term_string.push_str(&atom.to_string());
@@ -0,0 +1,337 @@
+use super::Unit;
+use crate::{
+    parser::{Atom, Term, UcumSymbol},
+    ucum_unit::UcumUnit,
+};
+use std::fmt;
+
+/// One step in explaining how a `Unit` relates to the base units: one `expression` is `factor`
+/// times `definition`. Each of the `Term`s in `definition` is then explained by its own step, all
+/// the way down to the base atoms.
+///
+/// ```
+/// use std::str::FromStr;
+/// use wise_units::Unit;
+///
+/// let explanation = Unit::from_str("[gal_us]").unwrap().explain();
+///
+/// assert_eq!(explanation.expression(), "[gal_us]");
+/// assert_eq!(explanation.factor(), 231.0);
+/// assert_eq!(explanation.definition(), Some("[in_i]3"));
+///
+/// let step = &explanation.steps()[0];
+/// assert_eq!(step.expression(), "[in_i]3");
+/// assert_eq!(step.definition(), Some("cm3"));
+/// ```
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Explanation {
+    expression: String,
+    factor: f64,
+    definition: Option<String>,
+    scalar: f64,
+    is_special: bool,
+    steps: Vec<Self>,
+}
+
+impl Explanation {
+    /// The `Unit` or `Term` that this step explains.
+    ///
+    #[must_use]
+    pub fn expression(&self) -> &str {
+        &self.expression
+    }
+
+    /// What `definition` gets multiplied by to get one `expression`. For special units, this is
+    /// only the factor from the UCUM table; the actual conversion is a function.
+    ///
+    #[must_use]
+    pub const fn factor(&self) -> f64 {
+        self.factor
+    }
+
+    /// What `expression` is defined in terms of. `None` for base atoms and for `Unit`s that are
+    /// just a product of their `Term`s.
+    ///
+    #[must_use]
+    pub fn definition(&self) -> Option<&str> {
+        self.definition.as_deref()
+    }
+
+    /// The value of one `expression` in base units.
+    ///
+    #[must_use]
+    pub const fn scalar(&self) -> f64 {
+        self.scalar
+    }
+
+    /// Special units (ex. `Cel`) are converted using functions instead of just `factor`.
+    ///
+    #[must_use]
+    pub const fn is_special(&self) -> bool {
+        self.is_special
+    }
+
+    /// Explanations of each `Term` of `definition`.
+    ///
+    #[must_use]
+    pub fn steps(&self) -> &[Self] {
+        &self.steps
+    }
+
+    fn fmt_indented(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        write!(f, "{:indent$}{}", "", self.expression, indent = depth * 2)?;
+
+        if let Some(ref definition) = self.definition {
+            write!(f, " = {} {}", self.factor, definition)?;
+        }
+
+        if self.is_special {
+            f.write_str(" (special)")?;
+        }
+
+        for step in &self.steps {
+            writeln!(f)?;
+            step.fmt_indented(f, depth + 1)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes each step on its own line, indented under the step it explains:
+///
+/// ```text
+/// [gal_us] = 231 [in_i]3
+///   [in_i]3 = 16.387064 cm3
+///     cm3 = 0.000001 m3
+///       m3
+/// ```
+///
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+impl Unit {
+    /// Builds a tree of `Explanation`s that show how `self` is defined, all the way down to the
+    /// base atoms. Useful for showing how a conversion was computed.
+    ///
+    #[must_use]
+    pub fn explain(&self) -> Explanation {
+        match self.terms() {
+            [term] => explain_term(term),
+            terms => Explanation {
+                expression: self.expression(),
+                factor: 1.0,
+                definition: None,
+                scalar: self.scalar(),
+                is_special: self.is_special(),
+                steps: terms.iter().map(explain_term).collect(),
+            },
+        }
+    }
+}
+
+fn explain_term(term: &Term) -> Explanation {
+    let expression = term.to_string();
+    let scalar = term.scalar();
+    let is_special = term.is_special();
+    let exponent = term.exponent.unwrap_or(1);
+
+    let atom = match term.atom {
+        Some(atom) => atom,
+        None => {
+            let factor = f64::from(term.factor_as_u32()).powi(exponent);
+
+            return Explanation {
+                expression,
+                factor,
+                definition: if term.factor_as_u32() == 1 {
+                    None
+                } else {
+                    Some(String::from("1"))
+                },
+                scalar,
+                is_special,
+                steps: Vec::new(),
+            };
+        }
+    };
+
+    // Peel off the prefix, factor, and annotation so the step below only has to explain the atom.
+    if term.prefix.is_some() || term.factor_as_u32() != 1 || term.annotation.is_some() {
+        let mut bare = Term::new(None, Some(atom));
+        bare.exponent = term.exponent;
+
+        let prefix_value = term.prefix.map_or(1.0, |prefix| prefix.definition_value());
+        let factor = (prefix_value * f64::from(term.factor_as_u32())).powi(exponent);
+
+        return Explanation {
+            expression,
+            factor,
+            definition: Some(bare.to_string()),
+            scalar,
+            is_special,
+            steps: vec![explain_term(&bare)],
+        };
+    }
+
+    if is_base_atom(atom) {
+        return Explanation {
+            expression,
+            factor: 1.0,
+            definition: None,
+            scalar,
+            is_special,
+            steps: Vec::new(),
+        };
+    }
+
+    let definition = atom.definition();
+    let factor = definition.value().powi(exponent);
+
+    if definition.is_unity() {
+        return Explanation {
+            expression,
+            factor,
+            definition: Some(String::from("1")),
+            scalar,
+            is_special,
+            steps: Vec::new(),
+        };
+    }
+
+    let definition_unit = Unit::new_static(definition.terms());
+    let definition_unit = if exponent == 1 {
+        definition_unit
+    } else {
+        definition_unit.pow(exponent).unwrap_or(definition_unit)
+    };
+
+    Explanation {
+        expression,
+        factor,
+        definition: Some(definition_unit.expression()),
+        scalar,
+        is_special,
+        steps: definition_unit
+            .terms()
+            .iter()
+            .filter(|term| !term.is_unity())
+            .map(explain_term)
+            .collect(),
+    }
+}
+
+const fn is_base_atom(atom: Atom) -> bool {
+    matches!(
+        atom,
+        Atom::Candela
+            | Atom::Coulomb
+            | Atom::Gram
+            | Atom::Kelvin
+            | Atom::Meter
+            | Atom::Radian
+            | Atom::Second
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Explanation;
+    use crate::Unit;
+    use approx::assert_relative_eq;
+    use std::str::FromStr;
+
+    fn explain(expression: &str) -> Explanation {
+        Unit::from_str(expression).unwrap().explain()
+    }
+
+    #[test]
+    fn validate_base_atom() {
+        let explanation = explain("m");
+
+        assert_eq!(explanation.expression(), "m");
+        assert_eq!(explanation.definition(), None);
+        assert_relative_eq!(explanation.factor(), 1.0);
+        assert!(explanation.steps().is_empty());
+    }
+
+    #[test]
+    fn validate_prefix() {
+        let explanation = explain("km");
+
+        assert_eq!(explanation.definition(), Some("m"));
+        assert_relative_eq!(explanation.factor(), 1000.0);
+        assert_relative_eq!(explanation.scalar(), 1000.0);
+        assert_eq!(explanation.steps()[0].expression(), "m");
+    }
+
+    #[test]
+    fn validate_gallon() {
+        let explanation = explain("[gal_us]");
+        assert_eq!(explanation.definition(), Some("[in_i]3"));
+        assert_relative_eq!(explanation.factor(), 231.0);
+        assert_relative_eq!(
+            explanation.scalar(),
+            0.003_785_411_784,
+            max_relative = 1e-12
+        );
+
+        let cubic_inch = &explanation.steps()[0];
+        assert_eq!(cubic_inch.expression(), "[in_i]3");
+        assert_eq!(cubic_inch.definition(), Some("cm3"));
+        assert_relative_eq!(cubic_inch.factor(), 16.387_064, max_relative = 1e-12);
+
+        let cubic_centimeter = &cubic_inch.steps()[0];
+        assert_eq!(cubic_centimeter.expression(), "cm3");
+        assert_eq!(cubic_centimeter.definition(), Some("m3"));
+        assert_relative_eq!(cubic_centimeter.factor(), 1.0e-6, max_relative = 1e-12);
+
+        let cubic_meter = &cubic_centimeter.steps()[0];
+        assert_eq!(cubic_meter.expression(), "m3");
+        assert_eq!(cubic_meter.definition(), None);
+        assert!(cubic_meter.steps().is_empty());
+    }
+
+    #[test]
+    fn validate_multiple_terms() {
+        let explanation = explain("kg/[acr_us]");
+
+        assert_eq!(explanation.expression(), "kg/[acr_us]");
+        assert_eq!(explanation.definition(), None);
+        assert_eq!(explanation.steps().len(), 2);
+        assert_eq!(explanation.steps()[0].expression(), "kg");
+        assert_eq!(explanation.steps()[1].expression(), "[acr_us]-1");
+        assert_eq!(explanation.steps()[1].definition(), Some("/[rd_us]2"));
+    }
+
+    #[test]
+    fn validate_dimensionless() {
+        let explanation = explain("%");
+
+        assert_eq!(explanation.definition(), Some("/10*2"));
+        assert_eq!(explanation.steps()[0].definition(), Some("1"));
+        assert_relative_eq!(explanation.steps()[0].factor(), 0.01);
+    }
+
+    #[test]
+    fn validate_special() {
+        let explanation = explain("Cel");
+
+        assert!(explanation.is_special());
+        assert_eq!(explanation.definition(), Some("K"));
+    }
+
+    #[test]
+    fn validate_display() {
+        let explanation = explain("[ft_i]");
+
+        assert_eq!(
+            explanation.to_string(),
+            "[ft_i] = 12 [in_i]\n  [in_i] = 2.54 cm\n    cm = 0.01 m\n      m"
+        );
+    }
+}
@@ -1,6 +1,22 @@
-use crate::{Error, Unit};
+use crate::{parser::ParseLimits, Error, Unit};
 use std::str::FromStr;
 
+impl Unit {
+    /// Like `Unit::from_str()`, but with `limits` instead of the default `ParseLimits`; see
+    /// there for what's checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::ParsingFailed` if `expression` can't be parsed or goes past any of
+    /// `limits`.
+    ///
+    pub fn from_str_with(expression: &str, limits: ParseLimits) -> Result<Self, Error> {
+        Ok(Self::new(crate::parser::parse_with_limits(
+            expression, limits,
+        )?))
+    }
+}
+
 //-----------------------------------------------------------------------------
 // impl FromStr
 //-----------------------------------------------------------------------------
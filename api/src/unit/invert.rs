@@ -4,7 +4,7 @@ use crate::invert::{Invert, ToInverse};
 impl Invert for &mut Unit {
     #[inline]
     fn invert(self) {
-        self.terms.invert();
+        self.terms.to_mut().invert();
     }
 }
 
@@ -2,23 +2,33 @@ use crate::{
     is_compatible_with::IsCompatibleWith, measurement::Measurement, parser::Term, unit::Unit,
 };
 
+impl IsCompatibleWith<[Term]> for Unit {
+    fn is_compatible_with(&self, rhs: &[Term]) -> bool {
+        self.terms().is_compatible_with(rhs)
+    }
+}
+
 impl IsCompatibleWith<Vec<Term>> for Unit {
     fn is_compatible_with(&self, rhs: &Vec<Term>) -> bool {
-        self.terms.is_compatible_with(rhs)
+        self.is_compatible_with(rhs.as_slice())
     }
 }
 
+/// Uses the `Composition`s and annotation compositions that each `Unit` caches, so comparing the
+/// same `Unit`s again doesn't recalculate them.
+///
 impl IsCompatibleWith for Unit {
     #[inline]
     fn is_compatible_with(&self, rhs: &Self) -> bool {
-        self.is_compatible_with(rhs.terms())
+        self.terms.composition() == rhs.terms.composition()
+            && self.terms.annotation_composition() == rhs.terms.annotation_composition()
     }
 }
 
 impl IsCompatibleWith<Measurement> for Unit {
     #[inline]
     fn is_compatible_with(&self, rhs: &Measurement) -> bool {
-        (&*self).is_compatible_with(rhs.unit().terms())
+        self.is_compatible_with(rhs.unit())
     }
 }
 
@@ -1,54 +1,92 @@
 use super::term_reducing;
-use crate::{invert::ToInverse, Term, Unit};
+use crate::{invert::ToInverse, parser::checked_composition, Error, Term, Unit};
 use std::ops::{Div, Mul};
 
-//-----------------------------------------------------------------------------
-// impl Div
-//-----------------------------------------------------------------------------
-fn divide_terms(lhs: &[Term], rhs: &[Term]) -> Vec<Term> {
-    let mut terms = Vec::with_capacity(lhs.len() + rhs.len());
-    terms.extend_from_slice(lhs);
+impl Unit {
+    /// Multiplies `self` by `other`; `Mul` does the same.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::CombinedExponentOverflow` if an exponent overflows.
+    ///
+    pub fn checked_mul(&self, other: &Self) -> Result<Self, Error> {
+        let mut terms = Vec::with_capacity(self.terms.len() + other.terms.len());
+        terms.extend_from_slice(&self.terms);
+        terms.extend_from_slice(&other.terms);
+
+        self.checked_combine(other, &terms)
+    }
+
+    /// Divides `self` by `other`; `Div` does the same.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::CombinedExponentOverflow` if an exponent overflows.
+    ///
+    pub fn checked_div(&self, other: &Self) -> Result<Self, Error> {
+        // Inverting an `i32::MIN` exponent would overflow; this rejects those.
+        if checked_composition(&other.terms).is_none() {
+            return Err(self.overflow(other));
+        }
+
+        let mut terms = Vec::with_capacity(self.terms.len() + other.terms.len());
+        terms.extend_from_slice(&self.terms);
+        terms.extend(other.terms.iter().map(ToInverse::to_inverse));
+
+        self.checked_combine(other, &terms)
+    }
 
-    for term in rhs.iter() {
-        terms.push(term.to_inverse());
+    fn checked_combine(&self, other: &Self, terms: &[Term]) -> Result<Self, Error> {
+        term_reducing::checked_reduce_terms(terms)
+            .filter(|reduced| checked_composition(reduced).is_some())
+            .map(Self::new)
+            .ok_or_else(|| self.overflow(other))
     }
 
-    term_reducing::reduce_terms(&terms)
+    fn overflow(&self, other: &Self) -> Error {
+        Error::CombinedExponentOverflow {
+            lhs: self.expression(),
+            rhs: other.expression(),
+        }
+    }
 }
 
+//-----------------------------------------------------------------------------
+// impl Div
+//-----------------------------------------------------------------------------
 impl Div for Unit {
-    type Output = Self;
+    type Output = Result<Self, Error>;
 
     #[inline]
     fn div(self, other: Self) -> Self::Output {
-        Self::new(divide_terms(&self.terms, &other.terms))
+        self.checked_div(&other)
     }
 }
 
 impl<'a> Div<&'a Self> for Unit {
-    type Output = Self;
+    type Output = Result<Self, Error>;
 
     #[inline]
     fn div(self, other: &'a Self) -> Self::Output {
-        Self::new(divide_terms(&self.terms, &other.terms))
+        self.checked_div(other)
     }
 }
 
 impl<'a> Div for &'a Unit {
-    type Output = Unit;
+    type Output = Result<Unit, Error>;
 
     #[inline]
     fn div(self, other: &'a Unit) -> Self::Output {
-        Unit::new(divide_terms(&self.terms, &other.terms))
+        self.checked_div(other)
     }
 }
 
 impl<'a> Div<Unit> for &'a Unit {
-    type Output = Unit;
+    type Output = Result<Unit, Error>;
 
     #[inline]
     fn div(self, other: Unit) -> Self::Output {
-        Unit::new(divide_terms(self, &other))
+        self.checked_div(&other)
     }
 }
 
@@ -56,50 +94,41 @@ impl<'a> Div<Unit> for &'a Unit {
 // impl Mul
 //-----------------------------------------------------------------------------
 impl Mul for Unit {
-    type Output = Self;
+    type Output = Result<Self, Error>;
 
     #[inline]
     fn mul(self, other: Self) -> Self::Output {
-        Self::new(multiply_terms(&self.terms, &other.terms))
+        self.checked_mul(&other)
     }
 }
 
 impl<'a> Mul<&'a Self> for Unit {
-    type Output = Self;
+    type Output = Result<Self, Error>;
 
     #[inline]
     fn mul(self, other: &'a Self) -> Self::Output {
-        Self::new(multiply_terms(&self.terms, &other.terms))
+        self.checked_mul(other)
     }
 }
 
 impl<'a> Mul for &'a Unit {
-    type Output = Unit;
+    type Output = Result<Unit, Error>;
 
     #[inline]
     fn mul(self, other: &'a Unit) -> Self::Output {
-        Unit::new(multiply_terms(&self.terms, &other.terms))
+        self.checked_mul(other)
     }
 }
 
 impl<'a> Mul<Unit> for &'a Unit {
-    type Output = Unit;
+    type Output = Result<Unit, Error>;
 
     #[inline]
     fn mul(self, other: Unit) -> Self::Output {
-        Unit::new(multiply_terms(&self.terms, &other.terms))
+        self.checked_mul(&other)
     }
 }
 
-fn multiply_terms(lhs: &[Term], rhs: &[Term]) -> Vec<Term> {
-    let mut terms = Vec::with_capacity(lhs.len() + rhs.len());
-
-    terms.extend_from_slice(lhs);
-    terms.extend_from_slice(rhs);
-
-    term_reducing::reduce_terms(&terms)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -117,32 +146,84 @@ mod tests {
     #[allow(clippy::eq_op)]
     fn validate_div() {
         let expected = Unit::from_str("m/km").unwrap();
-        assert_eq!(&*METER / &*KILOMETER, expected);
+        assert_eq!((&*METER / &*KILOMETER).unwrap(), expected);
 
         let unit = Unit::from_str("10m").unwrap();
         let other = Unit::from_str("20m").unwrap();
         let expected = Unit::from_str("10m/20m").unwrap();
-        assert_eq!(unit / other, expected);
+        assert_eq!((unit / other).unwrap(), expected);
+
+        assert_eq!((&*SEED / &*SEED).unwrap(), *UNITY);
+        assert_eq!(
+            (&*UNITY / &*SEED).unwrap(),
+            Unit::from_str("/{seed}").unwrap()
+        );
+        assert_eq!(
+            (&*SEED / &*ACRE).unwrap(),
+            Unit::from_str("{seed}/[acr_us]").unwrap()
+        );
+    }
 
-        assert_eq!(&*SEED / &*SEED, *UNITY);
-        assert_eq!(&*UNITY / &*SEED, Unit::from_str("/{seed}").unwrap());
-        assert_eq!(&*SEED / &*ACRE, Unit::from_str("{seed}/[acr_us]").unwrap());
+    #[test]
+    fn validate_checked_overflow() {
+        let unit = Unit::from_str("m").unwrap().pow(i32::MAX).unwrap();
+
+        assert_eq!(
+            unit.checked_mul(&METER).unwrap_err(),
+            Error::CombinedExponentOverflow {
+                lhs: unit.expression(),
+                rhs: "m".to_string()
+            }
+        );
+        assert!(unit.checked_div(&METER).is_ok());
+        assert!(unit.checked_div(&METER.to_inverse()).is_err());
+
+        // `ar` is L2, so the Composition overflows even though the Terms' exponents don't.
+        let area = Unit::from_str("ar").unwrap().pow(1_073_741_823).unwrap();
+        assert!(area.checked_mul(&Unit::from_str("ar").unwrap()).is_err());
+    }
+
+    #[test]
+    fn validate_ops_overflow() {
+        let unit = Unit::from_str("m").unwrap().pow(i32::MAX).unwrap();
+
+        assert_eq!(
+            unit.clone() * &*METER,
+            Err(Error::CombinedExponentOverflow {
+                lhs: unit.expression(),
+                rhs: "m".to_string()
+            })
+        );
+        assert!(matches!(
+            &unit / &METER.to_inverse(),
+            Err(Error::CombinedExponentOverflow { .. })
+        ));
+
+        // Built without checking, so inverting it would overflow.
+        let unchecked = Unit::new(vec![Term {
+            exponent: Some(i32::MIN),
+            ..Term::new(None, Some(crate::Atom::Meter))
+        }]);
+        assert!(matches!(
+            &*METER / unchecked,
+            Err(Error::CombinedExponentOverflow { .. })
+        ));
     }
 
     #[test]
     fn validate_mul() {
         let expected = Unit::from_str("m.km").unwrap();
-        assert_eq!(&*METER * &*KILOMETER, expected);
+        assert_eq!((&*METER * &*KILOMETER).unwrap(), expected);
 
         let unit = Unit::from_str("10m").unwrap();
         let other = Unit::from_str("20m").unwrap();
         let expected = Unit::from_str("10m.20m").unwrap();
-        assert_eq!(unit * other, expected);
+        assert_eq!((unit * other).unwrap(), expected);
 
         let per_seed = Unit::from_str("/{seed}").unwrap();
-        assert_eq!(&*SEED * &per_seed, *UNITY);
+        assert_eq!((&*SEED * &per_seed).unwrap(), *UNITY);
 
         let seed_per_acre = Unit::from_str("{seed}/[acr_us]").unwrap();
-        assert_eq!(seed_per_acre * &*ACRE, *SEED);
+        assert_eq!((seed_per_acre * &*ACRE).unwrap(), *SEED);
     }
 }
@@ -49,7 +49,7 @@ mod tests {
         let u1 = Unit::from_str("100[ft_i]").unwrap();
         let u2 = Unit::from_str("[in_i]2/5har").unwrap();
         let u3 = Unit::from_str("100[ft_i].[in_i]2/5har").unwrap();
-        assert!((&u1 * &u2) == u3);
-        assert!((&u2 * &u1) == u3);
+        assert!((&u1 * &u2).unwrap() == u3);
+        assert!((&u2 * &u1).unwrap() == u3);
     }
 }
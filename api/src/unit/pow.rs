@@ -0,0 +1,291 @@
+use super::Unit;
+use crate::{
+    parser::{checked_composition, Term},
+    ucum_unit::UcumUnit,
+    Error,
+};
+use std::convert::TryFrom;
+
+impl Unit {
+    /// Raises `self` to the power of `exponent` by multiplying each `Term`'s exponent by it.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wise_units::Unit;
+    ///
+    /// let unit = Unit::from_str("m").unwrap();
+    /// assert_eq!(unit.pow(3).unwrap().expression(), "m3");
+    ///
+    /// let unit = Unit::from_str("m/s").unwrap();
+    /// assert_eq!(unit.pow(2).unwrap().expression(), "m2/s2");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * `Error::SpecialUnitNotSupported` if `self` is a special unit (ex. `Cel`).
+    /// * `Error::ExponentOverflow` if any of the resulting exponents, or the exponents of the
+    ///   resulting `Composition` (ex. `ar` is an area, so its length exponent is double the
+    ///   `Term`'s), overflow an `i32`.
+    ///
+    pub fn pow(&self, exponent: i32) -> Result<Self, Error> {
+        if self.is_special() {
+            return Err(Error::SpecialUnitNotSupported(self.expression()));
+        }
+
+        if exponent == 0 {
+            return Ok(Self::new_unity());
+        }
+
+        let overflow = || Error::ExponentOverflow {
+            unit: self.expression(),
+            exponent,
+        };
+
+        let terms = self
+            .terms
+            .iter()
+            .map(|term| {
+                term.exponent
+                    .unwrap_or(1)
+                    .checked_mul(exponent)
+                    .map(|new_exponent| with_exponent(term, new_exponent))
+                    .ok_or_else(overflow)
+            })
+            .collect::<Result<Vec<Term>, Error>>()?;
+
+        if checked_composition(&terms).is_none() {
+            return Err(overflow());
+        }
+
+        Ok(Self::new(terms))
+    }
+
+    /// Takes the `degree`th root of `self` by dividing each `Term`'s exponent by `degree`. `Term`s
+    /// that are repeated (ex. `m.m`) are combined first.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wise_units::Unit;
+    ///
+    /// let unit = Unit::from_str("m2").unwrap();
+    /// assert_eq!(unit.root(2).unwrap().expression(), "m");
+    ///
+    /// let unit = Unit::from_str("m6/s3").unwrap();
+    /// assert_eq!(unit.root(3).unwrap().expression(), "m2/s");
+    ///
+    /// // Even though an acre is an area, its exponent is 1.
+    /// let unit = Unit::from_str("[acr_us]").unwrap();
+    /// assert!(unit.root(2).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * `Error::DivideByZero` if `degree` is 0.
+    /// * `Error::SpecialUnitNotSupported` if `self` is a special unit (ex. `Cel`).
+    /// * `Error::NoExactRoot` if any `Term`'s exponent isn't divisible by `degree`.
+    ///
+    pub fn root(&self, degree: u32) -> Result<Self, Error> {
+        if degree == 0 {
+            return Err(Error::DivideByZero);
+        }
+
+        if self.is_special() {
+            return Err(Error::SpecialUnitNotSupported(self.expression()));
+        }
+
+        let no_exact_root = || Error::NoExactRoot {
+            unit: self.expression(),
+            degree,
+        };
+
+        let divisor = i32::try_from(degree).map_err(|_| no_exact_root())?;
+
+        let terms = combine_like_terms(&self.terms)
+            .iter()
+            .map(|term| {
+                let exponent = term.exponent.unwrap_or(1);
+
+                if exponent % divisor == 0 {
+                    Ok(with_exponent(term, exponent / divisor))
+                } else {
+                    Err(no_exact_root())
+                }
+            })
+            .collect::<Result<Vec<Term>, Error>>()?;
+
+        if terms.is_empty() {
+            return Ok(Self::new_unity());
+        }
+
+        Ok(Self::new(terms))
+    }
+}
+
+fn with_exponent(term: &Term, exponent: i32) -> Term {
+    let mut new_term = term.clone();
+    new_term.exponent = if exponent == 1 { None } else { Some(exponent) };
+
+    new_term
+}
+
+/// Sums the exponents of `Term`s that only differ by exponent, keeping the position of the first
+/// occurrence. `Term`s whose exponents sum to 0 are dropped.
+///
+fn combine_like_terms(terms: &[Term]) -> Vec<Term> {
+    let mut combined: Vec<(Term, i32)> = Vec::with_capacity(terms.len());
+
+    for term in terms {
+        let exponent = term.exponent.unwrap_or(1);
+
+        match combined
+            .iter_mut()
+            .find(|(other, _)| is_like_term(term, other))
+        {
+            Some((_, sum)) => *sum += exponent,
+            None => combined.push((term.clone(), exponent)),
+        }
+    }
+
+    combined
+        .into_iter()
+        .filter(|(_, exponent)| *exponent != 0)
+        .map(|(term, exponent)| with_exponent(&term, exponent))
+        .collect()
+}
+
+fn is_like_term(lhs: &Term, rhs: &Term) -> bool {
+    lhs.atom == rhs.atom
+        && lhs.prefix == rhs.prefix
+        && lhs.factor == rhs.factor
+        && lhs.annotation == rhs.annotation
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Unit};
+    use std::str::FromStr;
+
+    macro_rules! validate_pow {
+        ($test_name:ident, $input:expr, $exponent:expr, $expected:expr) => {
+            #[test]
+            fn $test_name() {
+                let unit = Unit::from_str($input).unwrap();
+                assert_eq!(unit.pow($exponent).unwrap().expression(), $expected);
+            }
+        };
+    }
+
+    macro_rules! validate_root {
+        ($test_name:ident, $input:expr, $degree:expr, $expected:expr) => {
+            #[test]
+            fn $test_name() {
+                let unit = Unit::from_str($input).unwrap();
+                assert_eq!(unit.root($degree).unwrap().expression(), $expected);
+            }
+        };
+    }
+
+    validate_pow!(validate_pow_meter_squared, "m", 2, "m2");
+    validate_pow!(validate_pow_meter_cubed, "m", 3, "m3");
+    validate_pow!(validate_pow_meter_one, "m", 1, "m");
+    validate_pow!(validate_pow_zero, "m", 0, "1");
+    validate_pow!(validate_pow_negative, "m", -2, "/m2");
+    validate_pow!(validate_pow_existing_exponent, "cm2", 3, "cm6");
+    validate_pow!(validate_pow_multiple_terms, "kg.m/s2", 2, "kg2.m2/s4");
+    validate_pow!(validate_pow_factor, "10m", 2, "10m2");
+    validate_pow!(validate_pow_annotation, "m{row}", 2, "m2{row}");
+    validate_pow!(validate_pow_non_metric, "[ft_i]", 2, "[ft_i]2");
+
+    validate_root!(validate_root_square_meter, "m2", 2, "m");
+    validate_root!(validate_root_cubic_meter, "m3", 3, "m");
+    validate_root!(validate_root_one, "m", 1, "m");
+    validate_root!(validate_root_repeated_terms, "m.m", 2, "m");
+    validate_root!(validate_root_multiple_terms, "m4/s2", 2, "m2/s");
+    validate_root!(validate_root_canceled_terms, "m2.s/s", 2, "m");
+    validate_root!(validate_root_unity, "m/m", 2, "1");
+    validate_root!(validate_root_non_metric, "[ft_i]2", 2, "[ft_i]");
+
+    #[test]
+    fn validate_pow_special() {
+        let unit = Unit::from_str("Cel").unwrap();
+        assert_eq!(
+            unit.pow(2).unwrap_err(),
+            Error::SpecialUnitNotSupported("Cel".to_string())
+        );
+    }
+
+    #[test]
+    fn validate_pow_overflow() {
+        let unit = Unit::from_str("m2").unwrap();
+        assert_eq!(
+            unit.pow(i32::MAX).unwrap_err(),
+            Error::ExponentOverflow {
+                unit: "m2".to_string(),
+                exponent: i32::MAX
+            }
+        );
+    }
+
+    #[test]
+    fn validate_pow_composition_overflow() {
+        // Each Term's exponent fits, but an are is L2, so its composition's doesn't.
+        let unit = Unit::from_str("ar").unwrap();
+        assert_eq!(
+            unit.pow(2_000_000_000).unwrap_err(),
+            Error::ExponentOverflow {
+                unit: "ar".to_string(),
+                exponent: 2_000_000_000
+            }
+        );
+        assert!(unit.pow(1_000_000_000).is_ok());
+
+        // Repeated Terms are summed into the same dimension.
+        let unit = Unit::from_str("m.km").unwrap();
+        assert!(matches!(
+            unit.pow(i32::MAX),
+            Err(Error::ExponentOverflow { .. })
+        ));
+
+        let measurement = crate::Measurement::try_new(1.0, "ar").unwrap();
+        assert!(matches!(
+            measurement.powi(2_000_000_000),
+            Err(Error::ExponentOverflow { .. })
+        ));
+    }
+
+    #[test]
+    fn validate_root_not_divisible() {
+        let unit = Unit::from_str("[acr_us]").unwrap();
+        assert_eq!(
+            unit.root(2).unwrap_err(),
+            Error::NoExactRoot {
+                unit: "[acr_us]".to_string(),
+                degree: 2
+            }
+        );
+
+        let unit = Unit::from_str("m2/s").unwrap();
+        assert!(matches!(unit.root(2), Err(Error::NoExactRoot { .. })));
+    }
+
+    #[test]
+    fn validate_root_zero() {
+        let unit = Unit::from_str("m2").unwrap();
+        assert_eq!(unit.root(0).unwrap_err(), Error::DivideByZero);
+    }
+
+    #[test]
+    fn validate_root_special() {
+        let unit = Unit::from_str("[pH]").unwrap();
+        assert_eq!(
+            unit.root(2).unwrap_err(),
+            Error::SpecialUnitNotSupported("[pH]".to_string())
+        );
+    }
+
+    #[test]
+    fn validate_pow_then_root() {
+        let unit = Unit::from_str("kg.m/s2").unwrap();
+        assert_eq!(unit.pow(4).unwrap().root(4).unwrap(), unit);
+    }
+}
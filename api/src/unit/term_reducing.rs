@@ -46,39 +46,52 @@ impl From<Parts> for Term {
 
 /// Function used in `Unit` for reducing its `Term`s.
 ///
+/// # Panics
+///
+/// Panics if summing exponents overflows an `i32`, which can't happen for the `Term`s of a
+/// `Unit` that's been checked (see `parser::checked_composition()`).
+///
 pub(super) fn reduce_terms(terms: &[Term]) -> Vec<Term> {
-    let map = reduce_to_map(terms);
+    checked_reduce_terms(terms).expect("Term exponent overflowed an i32")
+}
+
+/// Like `reduce_terms()`, but returns `None` if summing exponents would overflow an `i32`.
+///
+pub(super) fn checked_reduce_terms(terms: &[Term]) -> Option<Vec<Term>> {
+    let map = reduce_to_map(terms)?;
 
     // If everything is reduced away, the effective Unit should be "1".
     if map.is_empty() {
-        vec![Term::new_unity()]
+        Some(vec![Term::new_unity()])
     } else {
         // Reconstructs the map into the Vec<Term>.
-        map.into_iter().map(Term::from).collect()
+        Some(map.into_iter().map(Term::from).collect())
     }
 }
 
 /// Iterates through `terms`, finds `Term`s that have the same attributes that determine
-/// uniqueness (`atom`, `prefix`, `factor`), and sums those exponents. This is the destructuring
-/// part of `reduce_terms()`.
+/// uniqueness (`atom`, `prefix`, `factor`), and sums those exponents (returning `None` if a sum
+/// overflows). This is the destructuring part of `reduce_terms()`.
 ///
-fn reduce_to_map(terms: &[Term]) -> BTreeMap<ComposableTerm, i32> {
-    terms
+fn reduce_to_map(terms: &[Term]) -> Option<BTreeMap<ComposableTerm, i32>> {
+    let map = terms
         .iter()
         .map(|term| (ComposableTerm::from(term), term.exponent.unwrap_or(1)))
-        .fold(
+        .try_fold(
             BTreeMap::<ComposableTerm, i32>::new(),
             |mut map, (key, exponent)| {
-                let _ = map
-                    .entry(key)
-                    .and_modify(|entry| *entry += exponent)
-                    .or_insert(exponent);
+                let entry = map.entry(key).or_insert(0);
+                *entry = entry.checked_add(exponent)?;
 
-                map
+                Some(map)
             },
-        )
+        )?;
+
+    let reduced = map
         .into_iter()
         // Filter out things that have no values
         .filter(|(ct, exponent)| ct.has_value() && *exponent != 0)
-        .collect()
+        .collect();
+
+    Some(reduced)
 }
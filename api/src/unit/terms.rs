@@ -0,0 +1,207 @@
+use crate::{
+    parser::{AnnotationComposable, AnnotationComposition, Composable, Composition, Term},
+    reducible::Reducible,
+    ucum_unit::UcumUnit,
+};
+use std::{borrow::Cow, fmt, ops::Deref, sync::Arc, sync::OnceLock};
+
+/// Where a `Unit` keeps its `Term`s. `Term`s are never changed once a `Unit` has them (except
+/// by `to_mut()`), so they're either borrowed for `'static` (predefined `Unit`s) or shared
+/// between clones. Either way, cloning a `Unit` doesn't allocate.
+///
+/// Shared `Term`s also cache what gets derived from them (see `SharedTerms`), so clones of a
+/// `Unit` only calculate those once between them.
+///
+#[derive(Clone)]
+pub(super) enum Terms {
+    Static(&'static [Term]),
+    Shared(Arc<SharedTerms>),
+}
+
+/// `Term`s, along with the values that comparing and converting `Unit`s use over and over,
+/// each calculated the first time it's asked for. `Terms::to_mut()` clears them.
+///
+#[derive(Default)]
+pub(super) struct SharedTerms {
+    terms: Vec<Term>,
+    composition: OnceLock<Composition>,
+    annotation_composition: OnceLock<Option<AnnotationComposition>>,
+    scalar: OnceLock<f64>,
+    is_special: OnceLock<bool>,
+}
+
+impl SharedTerms {
+    fn clear(&mut self) {
+        let _ = self.composition.take();
+        let _ = self.annotation_composition.take();
+        let _ = self.scalar.take();
+        let _ = self.is_special.take();
+    }
+}
+
+impl Terms {
+    /// Mutable access to the `Term`s, copying them first unless this is the only `Unit` that has
+    /// them.
+    ///
+    pub(super) fn to_mut(&mut self) -> &mut [Term] {
+        let is_unique = match self {
+            Self::Static(_) => false,
+            Self::Shared(shared) => Arc::get_mut(shared).is_some(),
+        };
+
+        if !is_unique {
+            *self = Self::from(self.to_vec());
+        }
+
+        match self {
+            Self::Shared(shared) => {
+                let shared = Arc::get_mut(shared).expect("terms were just made unique");
+                shared.clear();
+
+                &mut shared.terms
+            }
+            Self::Static(_) => unreachable!("terms were just made shared"),
+        }
+    }
+
+    pub(super) fn composition(&self) -> Composition {
+        self.cached(|shared| &shared.composition, Composable::composition)
+            .into_owned()
+    }
+
+    pub(super) fn annotation_composition(&self) -> Cow<'_, Option<AnnotationComposition>> {
+        self.cached(
+            |shared| &shared.annotation_composition,
+            |terms| terms.annotation_composition(),
+        )
+    }
+
+    pub(super) fn scalar(&self) -> f64 {
+        self.cached(|shared| &shared.scalar, |terms| terms.reduce_value(1.0))
+            .into_owned()
+    }
+
+    pub(super) fn is_special(&self) -> bool {
+        self.cached(
+            |shared| &shared.is_special,
+            |terms| terms.iter().any(UcumUnit::is_special),
+        )
+        .into_owned()
+    }
+
+    /// `calculate`s a value from the `Term`s, or for shared `Term`s, gets it from (or stores it
+    /// in) `cell`.
+    ///
+    fn cached<T: Clone>(
+        &self,
+        cell: fn(&SharedTerms) -> &OnceLock<T>,
+        calculate: fn(&[Term]) -> T,
+    ) -> Cow<'_, T> {
+        match self {
+            Self::Static(terms) => Cow::Owned(calculate(terms)),
+            Self::Shared(shared) => {
+                Cow::Borrowed(cell(shared).get_or_init(|| calculate(&shared.terms)))
+            }
+        }
+    }
+}
+
+impl From<Vec<Term>> for Terms {
+    fn from(terms: Vec<Term>) -> Self {
+        Self::Shared(Arc::new(SharedTerms {
+            terms,
+            ..SharedTerms::default()
+        }))
+    }
+}
+
+impl Deref for Terms {
+    type Target = [Term];
+
+    fn deref(&self) -> &[Term] {
+        match self {
+            Self::Static(terms) => terms,
+            Self::Shared(shared) => &shared.terms,
+        }
+    }
+}
+
+impl fmt::Debug for Terms {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl PartialEq for Terms {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Terms;
+    use crate::parser::{Atom, Composition, Prefix, Term};
+    use std::sync::Arc;
+
+    const KILOMETER: &[Term] = &[Term::new(Some(Prefix::Kilo), Some(Atom::Meter))];
+
+    #[test]
+    fn validate_clone_shares_terms() {
+        let terms = Terms::from(KILOMETER.to_vec());
+
+        match (&terms, &terms.clone()) {
+            (Terms::Shared(lhs), Terms::Shared(rhs)) => assert!(Arc::ptr_eq(lhs, rhs)),
+            _ => panic!("expected shared terms"),
+        }
+    }
+
+    #[test]
+    fn validate_to_mut_copies_shared_terms() {
+        let terms = Terms::from(KILOMETER.to_vec());
+        let mut other = terms.clone();
+        other.to_mut()[0].prefix = Some(Prefix::Milli);
+
+        assert_eq!(&*terms, KILOMETER);
+        assert_eq!(other[0].prefix, Some(Prefix::Milli));
+    }
+
+    #[test]
+    fn validate_clones_share_cache() {
+        let terms = Terms::from(KILOMETER.to_vec());
+        let other = terms.clone();
+        approx::assert_relative_eq!(terms.scalar(), 1000.0);
+
+        match other {
+            Terms::Shared(ref shared) => assert!(shared.scalar.get().is_some()),
+            Terms::Static(_) => panic!("expected shared terms"),
+        }
+    }
+
+    #[test]
+    fn validate_to_mut_clears_cache() {
+        let mut terms = Terms::from(KILOMETER.to_vec());
+        assert_eq!(terms.composition(), Composition::new_length(1));
+        assert!(!terms.is_special());
+        assert!(terms.annotation_composition().is_none());
+
+        let term = &mut terms.to_mut()[0];
+        term.atom = Some(Atom::DegreeCelsius);
+        term.exponent = Some(-1);
+        term.annotation = Some("foo".to_string());
+
+        assert_eq!(terms.composition(), Composition::new_temperature(-1));
+        assert!(terms.is_special());
+        assert!(terms.annotation_composition().is_some());
+    }
+
+    #[test]
+    fn validate_to_mut_copies_static_terms() {
+        let mut terms = Terms::Static(KILOMETER);
+        terms.to_mut()[0].exponent = Some(2);
+
+        assert!(matches!(terms, Terms::Shared(_)));
+        assert_eq!(KILOMETER[0].exponent, None);
+        assert_eq!(terms[0].exponent, Some(2));
+    }
+}
@@ -0,0 +1,103 @@
+use super::Unit;
+use crate::{
+    parser::{Atom, Composable, Composition, Dimension, Term},
+    ucum_unit::UcumUnit,
+    Error, Measurement,
+};
+
+/// The order that base `Atom`s are listed in when building a `Unit` from a `Composition`.
+///
+const BASE_ATOMS: [(Dimension, Atom); 7] = [
+    (Dimension::Mass, Atom::Gram),
+    (Dimension::Length, Atom::Meter),
+    (Dimension::Time, Atom::Second),
+    (Dimension::Temperature, Atom::Kelvin),
+    (Dimension::ElectricCharge, Atom::Coulomb),
+    (Dimension::LuminousIntensity, Atom::Candela),
+    (Dimension::PlaneAngle, Atom::Radian),
+];
+
+impl Unit {
+    /// Expresses `self` using only the seven base atoms (`m`, `s`, `g`, `rad`, `K`, `C`, `cd`).
+    /// The returned `Measurement`'s value is how many of those base units make up one of `self`.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wise_units::Unit;
+    ///
+    /// let unit = Unit::from_str("kN").unwrap();
+    /// let base = unit.to_base_units().unwrap();
+    ///
+    /// assert_eq!(base.value(), 1_000_000.0);
+    /// assert_eq!(base.unit().expression(), "g.m/s2");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::NonFiniteValue` if `self`'s scalar isn't finite (ex. `10*400`).
+    ///
+    pub fn to_base_units(&self) -> Result<Measurement, Error> {
+        Measurement::new(self.scalar(), Self::from_composition(self.composition()))
+    }
+
+    /// Builds a `Unit` of base atoms that has the `composition`.
+    ///
+    pub(crate) fn from_composition(composition: Composition) -> Self {
+        let terms: Vec<Term> = BASE_ATOMS
+            .iter()
+            .filter_map(|(dimension, atom)| {
+                composition.exponent(*dimension).map(|exponent| {
+                    let mut term = Term::new(None, Some(*atom));
+
+                    if exponent != 1 {
+                        term.exponent = Some(exponent);
+                    }
+
+                    term
+                })
+            })
+            .collect();
+
+        if terms.is_empty() {
+            Self::new_unity()
+        } else {
+            Self::new(terms)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parser::Composable, Unit};
+    use approx::assert_relative_eq;
+    use std::str::FromStr;
+
+    macro_rules! validate_to_base_units {
+        ($test_name:ident, $input:expr, $expected_value:expr, $expected_unit:expr) => {
+            #[test]
+            fn $test_name() {
+                let unit = Unit::from_str($input).unwrap();
+                let base = unit.to_base_units().unwrap();
+
+                assert_relative_eq!(base.value(), $expected_value, max_relative = 1e-12);
+                assert_eq!(base.unit().expression(), $expected_unit);
+                assert_eq!(base.unit().composition(), unit.composition());
+            }
+        };
+    }
+
+    validate_to_base_units!(validate_meter, "m", 1.0, "m");
+    validate_to_base_units!(validate_kilometer, "km", 1000.0, "m");
+    validate_to_base_units!(validate_kilogram, "kg", 1000.0, "g");
+    validate_to_base_units!(validate_gallon, "[gal_us]", 0.003_785_411_784, "m3");
+    validate_to_base_units!(validate_acre, "[acr_us]", 4_046.872_609_874_252, "m2");
+    validate_to_base_units!(validate_newton, "N", 1000.0, "g.m/s2");
+    validate_to_base_units!(validate_rate, "kg/har", 0.1, "g/m2");
+    validate_to_base_units!(validate_hertz, "Hz", 1.0, "/s");
+    validate_to_base_units!(validate_volt, "V", 1000.0, "g.m2/s2.C");
+    validate_to_base_units!(validate_candela, "cd", 1.0, "cd");
+    validate_to_base_units!(validate_degree, "deg", std::f64::consts::PI / 180.0, "rad");
+    validate_to_base_units!(validate_unity, "1", 1.0, "1");
+    validate_to_base_units!(validate_percent, "%", 0.01, "1");
+    validate_to_base_units!(validate_canceling_terms, "m/[ft_i]", 1.0 / 0.3048, "1");
+}
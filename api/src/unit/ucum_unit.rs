@@ -11,7 +11,7 @@ impl UcumUnit for Unit {
     /// special if any of its `Term`s has an `Atom` that is special.
     ///
     fn is_special(&self) -> bool {
-        self.terms.iter().any(UcumUnit::is_special)
+        self.terms.is_special()
     }
 
     /// The UCUM defines "metric units" using four points. First:
@@ -74,7 +74,7 @@ impl UcumUnit for Unit {
     ///
     #[inline]
     fn scalar(&self) -> f64 {
-        self.reduce_value(1.0)
+        self.terms.scalar()
     }
 
     /// The scalar value of `self` in terms of `self`'s actual unit(s).
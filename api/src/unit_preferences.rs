@@ -0,0 +1,499 @@
+//! Profiles of preferred `Unit`s, for showing the same data in the units a particular audience
+//! expects (ex. `kg/har` for metric users, `[lb_av]/[acr_us]` for US customary users).
+//!
+use crate::{
+    as_fraction::AsFraction,
+    parser::{Classification, Composable, Composition, Property, Term, UcumSymbol},
+    Error, Unit,
+};
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A preferred `Unit` for all atoms of a `Property`, ex. `[degF]` for `Property::Temperature`.
+///
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PropertyPreference {
+    property: Property,
+    unit: Unit,
+}
+
+impl PropertyPreference {
+    #[must_use]
+    pub const fn property(&self) -> Property {
+        self.property
+    }
+
+    #[must_use]
+    pub const fn unit(&self) -> &Unit {
+        &self.unit
+    }
+}
+
+/// Says which `Unit`s `Measurement::to_preferred()` should convert to. A `Unit` is picked by
+/// checking, in order:
+///
+/// 1. If all of the `Unit`'s atoms are already in one of the profile's `Classification`s, it's
+///    left alone (so a metric profile leaves `km` as `km`).
+/// 2. If the `Unit` is a single atom, a preference for that atom's `Property`.
+/// 3. A preferred `Unit` with the same numerator and denominator `Composition`s (so `kg/har` is
+///    picked for `[lb_av]/[acr_us]`, but `m` isn't picked for `[gal_us]/[acr_us]`).
+/// 4. Otherwise, each `Term` is converted on its own, using steps 1-3 for the `Term`'s atom.
+///
+/// With the `serde` feature, profiles can be loaded from any serde format, ex. TOML:
+///
+/// ```toml
+/// classifications = ["Si", "Iso1000"]
+/// units = ["m", "har", "L", "kg", "kg/har"]
+///
+/// [[properties]]
+/// property = "Temperature"
+/// unit = "Cel"
+/// ```
+///
+/// ```
+/// use wise_units::{Measurement, UnitPreferences};
+///
+/// let rate = Measurement::try_new(100.0, "[lb_av]/[acr_us]").unwrap();
+/// let metric = rate.to_preferred(&UnitPreferences::si()).unwrap();
+///
+/// assert_eq!(metric.unit().expression(), "kg/har");
+/// assert!((metric.value() - 112.085).abs() < 0.001);
+/// ```
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UnitPreferences {
+    #[cfg_attr(feature = "serde", serde(default))]
+    classifications: Vec<Classification>,
+
+    #[cfg_attr(feature = "serde", serde(default))]
+    properties: Vec<PropertyPreference>,
+
+    #[cfg_attr(feature = "serde", serde(default))]
+    units: Vec<Unit>,
+}
+
+impl UnitPreferences {
+    /// A profile without any preferences; `to_preferred()` leaves everything alone.
+    ///
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            classifications: Vec::new(),
+            properties: Vec::new(),
+            units: Vec::new(),
+        }
+    }
+
+    /// Metric preferences: leaves SI and ISO 1000 units alone, and otherwise prefers `m`, `har`,
+    /// `L`, `kg`, `Cel`, `kg/har`, `L/har`, and `km/h`.
+    ///
+    #[must_use]
+    pub fn si() -> Self {
+        Self::built_in(
+            &[
+                Classification::Si,
+                Classification::Iso1000,
+                Classification::Dimless,
+            ],
+            &[(Property::Temperature, "Cel")],
+            &["m", "har", "L", "kg", "kg/har", "L/har", "km/h"],
+        )
+    }
+
+    /// US customary preferences: leaves US and international customary units alone, and
+    /// otherwise prefers `[ft_i]`, `[acr_us]`, `[gal_us]`, `[lb_av]`, `[degF]`,
+    /// `[lb_av]/[acr_us]`, `[gal_us]/[acr_us]`, and `[mi_i]/h`.
+    ///
+    #[must_use]
+    pub fn us_customary() -> Self {
+        Self::built_in(
+            &[
+                Classification::Intcust,
+                Classification::UsLengths,
+                Classification::UsVolumes,
+                Classification::Avoirdupois,
+                Classification::Heat,
+                Classification::Dimless,
+            ],
+            &[(Property::Temperature, "[degF]")],
+            &[
+                "[ft_i]",
+                "[acr_us]",
+                "[gal_us]",
+                "[lb_av]",
+                "[lb_av]/[acr_us]",
+                "[gal_us]/[acr_us]",
+                "[mi_i]/h",
+            ],
+        )
+    }
+
+    fn built_in(
+        classifications: &[Classification],
+        properties: &[(Property, &str)],
+        units: &[&str],
+    ) -> Self {
+        let parse = |expression: &str| {
+            Unit::from_str(expression).expect("BUG! Bad built-in preferred unit!")
+        };
+
+        Self {
+            classifications: classifications.to_vec(),
+            properties: properties
+                .iter()
+                .map(|(property, expression)| PropertyPreference {
+                    property: *property,
+                    unit: parse(expression),
+                })
+                .collect(),
+            units: units.iter().map(|expression| parse(expression)).collect(),
+        }
+    }
+
+    /// Leaves `Unit`s whose atoms are all in `classification` alone.
+    ///
+    #[must_use]
+    pub fn with_classification(mut self, classification: Classification) -> Self {
+        if !self.classifications.contains(&classification) {
+            self.classifications.push(classification);
+        }
+
+        self
+    }
+
+    /// Prefers `unit` for atoms of `property`, replacing any existing preference for it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::IncompatibleUnitTypes` if `unit` isn't compatible with the atoms of
+    /// `property`.
+    ///
+    pub fn with_property(mut self, property: Property, unit: Unit) -> Result<Self, Error> {
+        if let Some(atom) = property.atoms().first() {
+            if atom.composition() != unit.composition() {
+                return Err(Error::IncompatibleUnitTypes {
+                    lhs: unit.expression(),
+                    rhs: property.to_string(),
+                });
+            }
+        }
+
+        self.properties
+            .retain(|preference| preference.property != property);
+        self.properties.push(PropertyPreference { property, unit });
+
+        Ok(self)
+    }
+
+    /// Prefers `unit` for `Unit`s of `composition` that are shaped like `unit` (ex. a preferred
+    /// `L/har` is used for other volume/area `Unit`s, but not for lengths). This replaces any
+    /// existing preference of the same shape.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::IncompatibleUnitTypes` if `unit` doesn't have `composition`.
+    ///
+    pub fn with_composition(mut self, composition: Composition, unit: Unit) -> Result<Self, Error> {
+        if unit.composition() != composition {
+            return Err(Error::IncompatibleUnitTypes {
+                lhs: unit.expression(),
+                rhs: composition.to_string(),
+            });
+        }
+
+        let key = shape(&unit);
+        self.units.retain(|existing| shape(existing) != key);
+        self.units.push(unit);
+
+        Ok(self)
+    }
+
+    #[must_use]
+    pub fn classifications(&self) -> &[Classification] {
+        &self.classifications
+    }
+
+    #[must_use]
+    pub fn properties(&self) -> &[PropertyPreference] {
+        &self.properties
+    }
+
+    #[must_use]
+    pub fn units(&self) -> &[Unit] {
+        &self.units
+    }
+
+    /// The `Unit` that `unit` should be shown in, or `None` if it should be left alone.
+    ///
+    #[must_use]
+    pub fn preferred_unit(&self, unit: &Unit) -> Option<Unit> {
+        if unit.terms().iter().all(|term| self.is_preferred_term(term)) {
+            return None;
+        }
+
+        let has_annotation = unit.terms().iter().any(|term| term.annotation.is_some());
+
+        if !has_annotation {
+            if let Some(preferred) = self.preferred_for_whole(unit) {
+                return Some(preferred);
+            }
+        }
+
+        let mut terms: Vec<Term> = Vec::new();
+
+        for term in unit.terms() {
+            match self.preferred_for_term(term) {
+                Some(mut replacement) => {
+                    if let Some(first) = replacement.first_mut() {
+                        first.annotation = term.annotation.clone();
+                    }
+
+                    terms.extend(replacement);
+                }
+                None => terms.push(term.clone()),
+            }
+        }
+
+        let preferred = Unit::new(terms);
+
+        if preferred.expression() == unit.expression() {
+            None
+        } else {
+            Some(preferred)
+        }
+    }
+
+    fn is_preferred_term(&self, term: &Term) -> bool {
+        term.atom.map_or(true, |atom| {
+            self.classifications.contains(&atom.classification())
+        })
+    }
+
+    fn preferred_for_whole(&self, unit: &Unit) -> Option<Unit> {
+        if let [term] = unit.terms() {
+            if term.exponent.map_or(true, |exponent| exponent == 1) {
+                if let Some(atom) = term.atom {
+                    let property = atom.property();
+
+                    let found = self.properties.iter().find(|preference| {
+                        preference.property == property
+                            && preference.unit.composition() == unit.composition()
+                    });
+
+                    if let Some(preference) = found {
+                        return Some(preference.unit.clone());
+                    }
+                }
+            }
+        }
+
+        let key = shape(unit);
+
+        self.units
+            .iter()
+            .find(|preferred| shape(preferred) == key)
+            .cloned()
+    }
+
+    /// The `Term`s to replace `term` with, found by looking up a preference for `term`'s atom and
+    /// raising it to `term`'s exponent.
+    ///
+    fn preferred_for_term(&self, term: &Term) -> Option<Vec<Term>> {
+        if self.is_preferred_term(term) {
+            return None;
+        }
+
+        let mut bare = Term::new(None, term.atom);
+        bare.prefix = term.prefix;
+
+        let preferred = self.preferred_for_whole(&Unit::new(vec![bare]))?;
+
+        match term.exponent {
+            None | Some(1) => Some(preferred.terms().to_vec()),
+            Some(exponent) => preferred
+                .pow(exponent)
+                .ok()
+                .map(|unit| unit.terms().to_vec()),
+        }
+    }
+}
+
+/// The `Composition`s of `unit`'s numerator and denominator.
+///
+fn shape(unit: &Unit) -> (Composition, Composition) {
+    let (numerator, denominator) = unit.as_fraction();
+
+    (
+        numerator.map(|unit| unit.composition()).unwrap_or_default(),
+        denominator
+            .map(|unit| unit.composition())
+            .unwrap_or_default(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnitPreferences;
+    use crate::{
+        parser::{Classification, Composition, Property},
+        Error, Unit,
+    };
+    use std::str::FromStr;
+
+    fn preferred(preferences: &UnitPreferences, expression: &str) -> Option<String> {
+        preferences
+            .preferred_unit(&Unit::from_str(expression).unwrap())
+            .map(|unit| unit.expression())
+    }
+
+    #[test]
+    fn validate_si() {
+        let si = UnitPreferences::si();
+
+        assert_eq!(preferred(&si, "[acr_us]"), Some("har".to_string()));
+        assert_eq!(
+            preferred(&si, "[lb_av]/[acr_us]"),
+            Some("kg/har".to_string())
+        );
+        assert_eq!(
+            preferred(&si, "[gal_us]/[acr_us]"),
+            Some("L/har".to_string())
+        );
+        assert_eq!(preferred(&si, "[degF]"), Some("Cel".to_string()));
+        assert_eq!(preferred(&si, "[mi_i]/h"), Some("km/h".to_string()));
+        assert_eq!(preferred(&si, "km"), None);
+        assert_eq!(preferred(&si, "kg/har"), None);
+        assert_eq!(preferred(&si, "%"), None);
+    }
+
+    #[test]
+    fn validate_us_customary() {
+        let us = UnitPreferences::us_customary();
+
+        assert_eq!(preferred(&us, "har"), Some("[acr_us]".to_string()));
+        assert_eq!(
+            preferred(&us, "kg/har"),
+            Some("[lb_av]/[acr_us]".to_string())
+        );
+        assert_eq!(preferred(&us, "Cel"), Some("[degF]".to_string()));
+        assert_eq!(preferred(&us, "K"), Some("[degF]".to_string()));
+        assert_eq!(preferred(&us, "[in_i]"), None);
+    }
+
+    #[test]
+    fn validate_term_by_term() {
+        let si = UnitPreferences::si();
+
+        // No kg/L preference, so each term gets converted.
+        assert_eq!(preferred(&si, "[lb_av]/[gal_us]"), Some("kg/L".to_string()));
+        assert_eq!(preferred(&si, "[lb_av]/[ft_i]"), Some("kg/m".to_string()));
+        assert_eq!(
+            preferred(&si, "[lb_av]/[ft_i]2"),
+            Some("kg/har".to_string())
+        );
+
+        // Terms that are already metric are left alone.
+        assert_eq!(preferred(&si, "[lb_av]/s"), Some("kg/s".to_string()));
+
+        // Annotations stay with their terms.
+        assert_eq!(
+            preferred(&si, "[lb_av]{P2O5}/[acr_us]"),
+            Some("kg{P2O5}/har".to_string())
+        );
+    }
+
+    #[test]
+    fn validate_empty() {
+        assert_eq!(preferred(&UnitPreferences::new(), "[lb_av]"), None);
+    }
+
+    #[test]
+    fn validate_with_property() {
+        let preferences = UnitPreferences::new()
+            .with_property(Property::FluidVolume, Unit::from_str("[qt_us]").unwrap())
+            .unwrap();
+
+        assert_eq!(preferred(&preferences, "L"), None);
+        assert_eq!(
+            preferred(&preferences, "[gal_us]"),
+            Some("[qt_us]".to_string())
+        );
+
+        let error = UnitPreferences::new()
+            .with_property(Property::FluidVolume, Unit::from_str("m").unwrap())
+            .unwrap_err();
+        assert_eq!(
+            error,
+            Error::IncompatibleUnitTypes {
+                lhs: "m".to_string(),
+                rhs: "FluidVolume".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn validate_with_composition() {
+        let preferences = UnitPreferences::si()
+            .with_composition(
+                (Composition::new_mass(1) * Composition::new_length(-2)).unwrap(),
+                Unit::from_str("g/m2").unwrap(),
+            )
+            .unwrap()
+            .with_classification(Classification::Intcust);
+
+        assert_eq!(
+            preferred(&preferences, "[lb_av]/[acr_us]"),
+            Some("g/m2".to_string())
+        );
+        assert_eq!(preferred(&preferences, "[ft_i]"), None);
+
+        assert!(UnitPreferences::new()
+            .with_composition(Composition::new_mass(1), Unit::from_str("m").unwrap())
+            .is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn validate_serde() {
+        let json = r#"{
+            "classifications": ["Si"],
+            "properties": [{ "property": "Temperature", "unit": "Cel" }],
+            "units": ["kg/har"]
+        }"#;
+        let preferences: UnitPreferences = serde_json::from_str(json).unwrap();
+
+        assert_eq!(preferred(&preferences, "[degF]"), Some("Cel".to_string()));
+        assert_eq!(
+            preferred(&preferences, "[lb_av]/[acr_us]"),
+            Some("kg/har".to_string())
+        );
+
+        let round_tripped: UnitPreferences =
+            serde_json::from_str(&serde_json::to_string(&preferences).unwrap()).unwrap();
+        assert_eq!(round_tripped, preferences);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn validate_toml() {
+        let toml = r#"
+            classifications = ["Intcust", "UsVolumes"]
+            units = ["[gal_us]/[acr_us]"]
+
+            [[properties]]
+            property = "Temperature"
+            unit = "[degF]"
+        "#;
+        let preferences: UnitPreferences = toml::from_str(toml).unwrap();
+
+        assert_eq!(preferred(&preferences, "Cel"), Some("[degF]".to_string()));
+        assert_eq!(
+            preferred(&preferences, "L/har"),
+            Some("[gal_us]/[acr_us]".to_string())
+        );
+    }
+}
@@ -0,0 +1,280 @@
+//! Predefined `Unit`s, as `const`s.
+//!
+//! There's one for each `Atom` (ex. `units::ACRE_US`, generated by wise_units-atom_generator),
+//! plus common prefixed and compound units (ex. `units::KILOGRAM_PER_HECTARE`). They borrow
+//! `'static` `Term`s, so they cost nothing to create and can be used in `const`s and `static`s.
+//!
+//! ```
+//! use std::str::FromStr;
+//! use wise_units::{units, Measurement, Unit};
+//!
+//! static RATE_UNIT: Unit = units::POUND_AVOIRDUPOIS_PER_ACRE_US;
+//!
+//! assert_eq!(RATE_UNIT, Unit::from_str("[lb_av]/[acr_us]").unwrap());
+//!
+//! let m = Measurement::new(12.5, units::KILOGRAM).unwrap();
+//! assert_eq!(m.unit().expression(), "kg");
+//! ```
+//!
+//! `Unit`'s `PartialEq` compares what `Unit`s measure rather than their `Term`s (ex. `1000.g` equals
+//! `kg`), so these can't be used as patterns in a `match`; compare with `==` or `FieldEq` instead.
+//!
+//! Being `const`s, these can't cache what's derived from their `Term`s (their `Composition`,
+//! scalar, etc.) like other `Unit`s do, so each use calculates them again. On hot paths (ex.
+//! converting in a loop), make a `Unit` that caches them once, and reuse it:
+//!
+//! ```
+//! use wise_units::{units, Convertible, Measurement, Unit};
+//!
+//! let kilogram = Unit::new(units::KILOGRAM.terms().to_vec());
+//!
+//! for pounds in &[1.0, 2.5, 10.0] {
+//!     let m = Measurement::new(*pounds, units::POUND_AVOIRDUPOIS).unwrap();
+//!     assert!(m.convert_to(&kilogram).is_ok());
+//! }
+//! ```
+//!
+mod atoms;
+
+pub use self::atoms::*;
+
+use crate::{
+    parser::{Atom, Prefix, Term},
+    unit::Unit,
+};
+
+/// Defines a `Unit` `const` from `Term`s.
+///
+macro_rules! def_unit {
+    ($(#[$docs:meta])* $name:ident => [$($term:expr),+ $(,)?]) => {
+        $(#[$docs])*
+        pub const $name: Unit = {
+            const TERMS: &[Term] = &[$($term),+];
+            Unit::new_static(TERMS)
+        };
+    };
+}
+
+//-----------------------------------------------------------------------------
+// Prefixed units
+//-----------------------------------------------------------------------------
+def_unit!(
+    /// `kg` (kilogram)
+    ///
+    KILOGRAM => [Term::new(Some(Prefix::Kilo), Some(Atom::Gram))]
+);
+def_unit!(
+    /// `mg` (milligram)
+    ///
+    MILLIGRAM => [Term::new(Some(Prefix::Milli), Some(Atom::Gram))]
+);
+def_unit!(
+    /// `ug` (microgram)
+    ///
+    MICROGRAM => [Term::new(Some(Prefix::Micro), Some(Atom::Gram))]
+);
+def_unit!(
+    /// `Mg` (megagram)
+    ///
+    MEGAGRAM => [Term::new(Some(Prefix::Mega), Some(Atom::Gram))]
+);
+def_unit!(
+    /// `km` (kilometer)
+    ///
+    KILOMETER => [Term::new(Some(Prefix::Kilo), Some(Atom::Meter))]
+);
+def_unit!(
+    /// `cm` (centimeter)
+    ///
+    CENTIMETER => [Term::new(Some(Prefix::Centi), Some(Atom::Meter))]
+);
+def_unit!(
+    /// `mm` (millimeter)
+    ///
+    MILLIMETER => [Term::new(Some(Prefix::Milli), Some(Atom::Meter))]
+);
+def_unit!(
+    /// `mL` (milliliter)
+    ///
+    MILLILITER => [Term::new(Some(Prefix::Milli), Some(Atom::LiterSecondary))]
+);
+def_unit!(
+    /// `har` (hectare)
+    ///
+    HECTARE => [Term::new(Some(Prefix::Hecto), Some(Atom::Are))]
+);
+def_unit!(
+    /// `kPa` (kilopascal)
+    ///
+    KILOPASCAL => [Term::new(Some(Prefix::Kilo), Some(Atom::Pascal))]
+);
+def_unit!(
+    /// `kW` (kilowatt)
+    ///
+    KILOWATT => [Term::new(Some(Prefix::Kilo), Some(Atom::Watt))]
+);
+
+//-----------------------------------------------------------------------------
+// Compound units
+//-----------------------------------------------------------------------------
+def_unit!(
+    /// `m2` (square meter)
+    ///
+    SQUARE_METER => [Term::new(None, Some(Atom::Meter)).with_exponent(2)]
+);
+def_unit!(
+    /// `m3` (cubic meter)
+    ///
+    CUBIC_METER => [Term::new(None, Some(Atom::Meter)).with_exponent(3)]
+);
+def_unit!(
+    /// `m/s` (meter per second)
+    ///
+    METER_PER_SECOND => [
+        Term::new(None, Some(Atom::Meter)),
+        Term::new(None, Some(Atom::Second)).with_exponent(-1),
+    ]
+);
+def_unit!(
+    /// `km/h` (kilometer per hour)
+    ///
+    KILOMETER_PER_HOUR => [
+        Term::new(Some(Prefix::Kilo), Some(Atom::Meter)),
+        Term::new(None, Some(Atom::Hour)).with_exponent(-1),
+    ]
+);
+def_unit!(
+    /// `[mi_i]/h` (mile per hour)
+    ///
+    MILE_INTERNATIONAL_PER_HOUR => [
+        Term::new(None, Some(Atom::MileInternational)),
+        Term::new(None, Some(Atom::Hour)).with_exponent(-1),
+    ]
+);
+def_unit!(
+    /// `kg/har` (kilogram per hectare)
+    ///
+    KILOGRAM_PER_HECTARE => [
+        Term::new(Some(Prefix::Kilo), Some(Atom::Gram)),
+        Term::new(Some(Prefix::Hecto), Some(Atom::Are)).with_exponent(-1),
+    ]
+);
+def_unit!(
+    /// `L/har` (liter per hectare)
+    ///
+    LITER_PER_HECTARE => [
+        Term::new(None, Some(Atom::LiterSecondary)),
+        Term::new(Some(Prefix::Hecto), Some(Atom::Are)).with_exponent(-1),
+    ]
+);
+def_unit!(
+    /// `[lb_av]/[acr_us]` (pound per acre)
+    ///
+    POUND_AVOIRDUPOIS_PER_ACRE_US => [
+        Term::new(None, Some(Atom::PoundAvoirdupois)),
+        Term::new(None, Some(Atom::AcreUS)).with_exponent(-1),
+    ]
+);
+def_unit!(
+    /// `[lb_av]/[bu_us]` (pound per bushel)
+    ///
+    POUND_AVOIRDUPOIS_PER_BUSHEL_US => [
+        Term::new(None, Some(Atom::PoundAvoirdupois)),
+        Term::new(None, Some(Atom::BushelUS)).with_exponent(-1),
+    ]
+);
+def_unit!(
+    /// `[gal_us]/[acr_us]` (gallon per acre)
+    ///
+    GALLON_US_PER_ACRE_US => [
+        Term::new(None, Some(Atom::QueenAnnesWineGallonUS)),
+        Term::new(None, Some(Atom::AcreUS)).with_exponent(-1),
+    ]
+);
+def_unit!(
+    /// `[foz_us]/[acr_us]` (fluid ounce per acre)
+    ///
+    FLUID_OUNCE_US_PER_ACRE_US => [
+        Term::new(None, Some(Atom::FluidOunceUS)),
+        Term::new(None, Some(Atom::AcreUS)).with_exponent(-1),
+    ]
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Convertible, FieldEq, Measurement};
+    use std::str::FromStr;
+
+    macro_rules! validate_units {
+        ($($unit:expr => $expression:expr),+ $(,)?) => {
+            $(
+                let expected = Unit::from_str($expression).unwrap();
+                assert!($unit.field_eq(&expected), "{}", $expression);
+                assert_eq!($unit.expression(), $expression);
+            )+
+        };
+    }
+
+    #[test]
+    fn validate_atom_units() {
+        validate_units!(
+            METER => "m",
+            GRAM => "g",
+            ACRE_US => "[acr_us]",
+            POUND_AVOIRDUPOIS => "[lb_av]",
+            QUEEN_ANNES_WINE_GALLON_US => "[gal_us]",
+            DEGREE_CELSIUS => "Cel",
+            PH => "[pH]",
+        );
+    }
+
+    #[test]
+    fn validate_prefixed_units() {
+        validate_units!(
+            KILOGRAM => "kg",
+            MILLIGRAM => "mg",
+            MICROGRAM => "ug",
+            MEGAGRAM => "Mg",
+            KILOMETER => "km",
+            CENTIMETER => "cm",
+            MILLIMETER => "mm",
+            MILLILITER => "mL",
+            HECTARE => "har",
+            KILOPASCAL => "kPa",
+            KILOWATT => "kW",
+        );
+    }
+
+    #[test]
+    fn validate_compound_units() {
+        validate_units!(
+            SQUARE_METER => "m2",
+            CUBIC_METER => "m3",
+            METER_PER_SECOND => "m/s",
+            KILOMETER_PER_HOUR => "km/h",
+            MILE_INTERNATIONAL_PER_HOUR => "[mi_i]/h",
+            KILOGRAM_PER_HECTARE => "kg/har",
+            LITER_PER_HECTARE => "L/har",
+            POUND_AVOIRDUPOIS_PER_ACRE_US => "[lb_av]/[acr_us]",
+            POUND_AVOIRDUPOIS_PER_BUSHEL_US => "[lb_av]/[bu_us]",
+            GALLON_US_PER_ACRE_US => "[gal_us]/[acr_us]",
+            FLUID_OUNCE_US_PER_ACRE_US => "[foz_us]/[acr_us]",
+        );
+    }
+
+    #[test]
+    fn validate_usable_as_values() {
+        static RATE: Unit = POUND_AVOIRDUPOIS_PER_ACRE_US;
+
+        let m = Measurement::new(1.0, RATE.clone()).unwrap();
+        let converted = m.convert_to(&KILOGRAM_PER_HECTARE).unwrap();
+        approx::assert_relative_eq!(converted.value(), 1.120_846_7, max_relative = 1e-7);
+
+        // Changing a predefined unit copies its terms first.
+        let mut unit = KILOGRAM;
+        crate::invert::Invert::invert(&mut unit);
+        assert_eq!(unit.expression(), "/kg");
+        assert_eq!(KILOGRAM.expression(), "kg");
+    }
+}
@@ -0,0 +1,2236 @@
+//-----------------------------------------------------------------------------
+// DO NOT EDIT THIS FILE!
+// This is generated by wise_units-atom_generator.
+//-----------------------------------------------------------------------------
+
+use crate::{
+    parser::{Atom, Term},
+    unit::Unit,
+};
+
+/// `m` (meter)
+///
+pub const METER: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Meter))];
+    Unit::new_static(TERMS)
+};
+
+/// `s` (second)
+///
+pub const SECOND: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Second))];
+    Unit::new_static(TERMS)
+};
+
+/// `g` (gram)
+///
+pub const GRAM: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Gram))];
+    Unit::new_static(TERMS)
+};
+
+/// `rad` (radian)
+///
+pub const RADIAN: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Radian))];
+    Unit::new_static(TERMS)
+};
+
+/// `K` (kelvin)
+///
+pub const KELVIN: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Kelvin))];
+    Unit::new_static(TERMS)
+};
+
+/// `C` (coulomb)
+///
+pub const COULOMB: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Coulomb))];
+    Unit::new_static(TERMS)
+};
+
+/// `cd` (candela)
+///
+pub const CANDELA: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Candela))];
+    Unit::new_static(TERMS)
+};
+
+/// `10*` (the number ten for arbitrary powers)
+///
+pub const THE_NUMBER_TEN_FOR_ARBITRARY_POWERS_STAR: Unit = {
+    const TERMS: &[Term] = &[Term::new(
+        None,
+        Some(Atom::TheNumberTenForArbitraryPowersStar),
+    )];
+    Unit::new_static(TERMS)
+};
+
+/// `10^` (the number ten for arbitrary powers)
+///
+pub const THE_NUMBER_TEN_FOR_ARBITRARY_POWERS_CARET: Unit = {
+    const TERMS: &[Term] = &[Term::new(
+        None,
+        Some(Atom::TheNumberTenForArbitraryPowersCaret),
+    )];
+    Unit::new_static(TERMS)
+};
+
+/// `[pi]` (the number pi)
+///
+pub const THE_NUMBER_PI: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::TheNumberPi))];
+    Unit::new_static(TERMS)
+};
+
+/// `%` (percent)
+///
+pub const PERCENT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Percent))];
+    Unit::new_static(TERMS)
+};
+
+/// `[ppth]` (parts per thousand)
+///
+pub const PARTS_PER_THOUSAND: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::PartsPerThousand))];
+    Unit::new_static(TERMS)
+};
+
+/// `[ppm]` (parts per million)
+///
+pub const PARTS_PER_MILLION: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::PartsPerMillion))];
+    Unit::new_static(TERMS)
+};
+
+/// `[ppb]` (parts per billion)
+///
+pub const PARTS_PER_BILLION: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::PartsPerBillion))];
+    Unit::new_static(TERMS)
+};
+
+/// `[pptr]` (parts per trillion)
+///
+pub const PARTS_PER_TRILLION: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::PartsPerTrillion))];
+    Unit::new_static(TERMS)
+};
+
+/// `mol` (mole)
+///
+pub const MOLE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Mole))];
+    Unit::new_static(TERMS)
+};
+
+/// `sr` (steradian)
+///
+pub const STERADIAN: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Steradian))];
+    Unit::new_static(TERMS)
+};
+
+/// `Hz` (hertz)
+///
+pub const HERTZ: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Hertz))];
+    Unit::new_static(TERMS)
+};
+
+/// `N` (newton)
+///
+pub const NEWTON: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Newton))];
+    Unit::new_static(TERMS)
+};
+
+/// `Pa` (pascal)
+///
+pub const PASCAL: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Pascal))];
+    Unit::new_static(TERMS)
+};
+
+/// `J` (joule)
+///
+pub const JOULE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Joule))];
+    Unit::new_static(TERMS)
+};
+
+/// `W` (watt)
+///
+pub const WATT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Watt))];
+    Unit::new_static(TERMS)
+};
+
+/// `A` (ampère)
+///
+pub const AMPERE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Ampere))];
+    Unit::new_static(TERMS)
+};
+
+/// `V` (volt)
+///
+pub const VOLT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Volt))];
+    Unit::new_static(TERMS)
+};
+
+/// `F` (farad)
+///
+pub const FARAD: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Farad))];
+    Unit::new_static(TERMS)
+};
+
+/// `Ohm` (ohm)
+///
+pub const OHM: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Ohm))];
+    Unit::new_static(TERMS)
+};
+
+/// `S` (siemens)
+///
+pub const SIEMENS: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Siemens))];
+    Unit::new_static(TERMS)
+};
+
+/// `Wb` (weber)
+///
+pub const WEBER: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Weber))];
+    Unit::new_static(TERMS)
+};
+
+/// `Cel` (degree Celsius)
+///
+pub const DEGREE_CELSIUS: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::DegreeCelsius))];
+    Unit::new_static(TERMS)
+};
+
+/// `T` (tesla)
+///
+pub const TESLA: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Tesla))];
+    Unit::new_static(TERMS)
+};
+
+/// `H` (henry)
+///
+pub const HENRY: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Henry))];
+    Unit::new_static(TERMS)
+};
+
+/// `lm` (lumen)
+///
+pub const LUMEN: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Lumen))];
+    Unit::new_static(TERMS)
+};
+
+/// `lx` (lux)
+///
+pub const LUX: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Lux))];
+    Unit::new_static(TERMS)
+};
+
+/// `Bq` (becquerel)
+///
+pub const BECQUEREL: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Becquerel))];
+    Unit::new_static(TERMS)
+};
+
+/// `Gy` (gray)
+///
+pub const GRAY: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Gray))];
+    Unit::new_static(TERMS)
+};
+
+/// `Sv` (sievert)
+///
+pub const SIEVERT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Sievert))];
+    Unit::new_static(TERMS)
+};
+
+/// `gon` (gon)
+///
+pub const GON: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Gon))];
+    Unit::new_static(TERMS)
+};
+
+/// `deg` (degree)
+///
+pub const DEGREE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Degree))];
+    Unit::new_static(TERMS)
+};
+
+/// `'` (minute)
+///
+pub const MINUTE_ANGLE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::MinuteAngle))];
+    Unit::new_static(TERMS)
+};
+
+/// `''` (second)
+///
+pub const SECOND_ANGLE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::SecondAngle))];
+    Unit::new_static(TERMS)
+};
+
+/// `l` (liter)
+///
+pub const LITER: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Liter))];
+    Unit::new_static(TERMS)
+};
+
+/// `L` (liter)
+///
+pub const LITER_SECONDARY: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::LiterSecondary))];
+    Unit::new_static(TERMS)
+};
+
+/// `ar` (are)
+///
+pub const ARE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Are))];
+    Unit::new_static(TERMS)
+};
+
+/// `min` (minute)
+///
+pub const MINUTE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Minute))];
+    Unit::new_static(TERMS)
+};
+
+/// `h` (hour)
+///
+pub const HOUR: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Hour))];
+    Unit::new_static(TERMS)
+};
+
+/// `d` (day)
+///
+pub const DAY: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Day))];
+    Unit::new_static(TERMS)
+};
+
+/// `a_t` (tropical year)
+///
+pub const TROPICAL_YEAR: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::TropicalYear))];
+    Unit::new_static(TERMS)
+};
+
+/// `a_j` (mean Julian year)
+///
+pub const MEAN_JULIAN_YEAR: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::MeanJulianYear))];
+    Unit::new_static(TERMS)
+};
+
+/// `a_g` (mean Gregorian year)
+///
+pub const MEAN_GREGORIAN_YEAR: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::MeanGregorianYear))];
+    Unit::new_static(TERMS)
+};
+
+/// `a` (year)
+///
+pub const YEAR: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Year))];
+    Unit::new_static(TERMS)
+};
+
+/// `wk` (week)
+///
+pub const WEEK: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Week))];
+    Unit::new_static(TERMS)
+};
+
+/// `mo_s` (synodal month)
+///
+pub const SYNODAL_MONTH: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::SynodalMonth))];
+    Unit::new_static(TERMS)
+};
+
+/// `mo_j` (mean Julian month)
+///
+pub const MEAN_JULIAN_MONTH: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::MeanJulianMonth))];
+    Unit::new_static(TERMS)
+};
+
+/// `mo_g` (mean Gregorian month)
+///
+pub const MEAN_GREGORIAN_MONTH: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::MeanGregorianMonth))];
+    Unit::new_static(TERMS)
+};
+
+/// `mo` (month)
+///
+pub const MONTH: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Month))];
+    Unit::new_static(TERMS)
+};
+
+/// `t` (tonne)
+///
+pub const TONNE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Tonne))];
+    Unit::new_static(TERMS)
+};
+
+/// `bar` (bar)
+///
+pub const BAR: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Bar))];
+    Unit::new_static(TERMS)
+};
+
+/// `u` (unified atomic mass unit)
+///
+pub const UNIFIED_ATOMIC_MASS_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::UnifiedAtomicMassUnit))];
+    Unit::new_static(TERMS)
+};
+
+/// `eV` (electronvolt)
+///
+pub const ELECTRONVOLT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Electronvolt))];
+    Unit::new_static(TERMS)
+};
+
+/// `AU` (astronomic unit)
+///
+pub const ASTRONOMIC_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::AstronomicUnit))];
+    Unit::new_static(TERMS)
+};
+
+/// `pc` (parsec)
+///
+pub const PARSEC: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Parsec))];
+    Unit::new_static(TERMS)
+};
+
+/// `[c]` (velocity of light)
+///
+pub const VELOCITY_OF_LIGHT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::VelocityOfLight))];
+    Unit::new_static(TERMS)
+};
+
+/// `[h]` (Planck constant)
+///
+pub const PLANCK_CONSTANT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::PlanckConstant))];
+    Unit::new_static(TERMS)
+};
+
+/// `[k]` (Boltzmann constant)
+///
+pub const BOLTZMANN_CONSTANT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::BoltzmannConstant))];
+    Unit::new_static(TERMS)
+};
+
+/// `[eps_0]` (permittivity of vacuum)
+///
+pub const PERMITTIVITY_OF_VACUUM: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::PermittivityOfVacuum))];
+    Unit::new_static(TERMS)
+};
+
+/// `[mu_0]` (permeability of vacuum)
+///
+pub const PERMEABILITY_OF_VACUUM: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::PermeabilityOfVacuum))];
+    Unit::new_static(TERMS)
+};
+
+/// `[e]` (elementary charge)
+///
+pub const ELEMENTARY_CHARGE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::ElementaryCharge))];
+    Unit::new_static(TERMS)
+};
+
+/// `[m_e]` (electron mass)
+///
+pub const ELECTRON_MASS: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::ElectronMass))];
+    Unit::new_static(TERMS)
+};
+
+/// `[m_p]` (proton mass)
+///
+pub const PROTON_MASS: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::ProtonMass))];
+    Unit::new_static(TERMS)
+};
+
+/// `[G]` (Newtonian constant of gravitation)
+///
+pub const NEWTONIAN_CONSTANT_OF_GRAVITATION: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::NewtonianConstantOfGravitation))];
+    Unit::new_static(TERMS)
+};
+
+/// `[g]` (standard acceleration of free fall)
+///
+pub const STANDARD_ACCELERATION_OF_FREE_FALL: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::StandardAccelerationOfFreeFall))];
+    Unit::new_static(TERMS)
+};
+
+/// `atm` (standard atmosphere)
+///
+pub const STANDARD_ATMOSPHERE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::StandardAtmosphere))];
+    Unit::new_static(TERMS)
+};
+
+/// `[ly]` (light-year)
+///
+pub const LIGHT_YEAR: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::LightYear))];
+    Unit::new_static(TERMS)
+};
+
+/// `gf` (gram-force)
+///
+pub const GRAM_FORCE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::GramForce))];
+    Unit::new_static(TERMS)
+};
+
+/// `[lbf_av]` (pound force)
+///
+pub const POUND_FORCE_AVOIRDUPOIS: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::PoundForceAvoirdupois))];
+    Unit::new_static(TERMS)
+};
+
+/// `Ky` (Kayser)
+///
+pub const KAYSER: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Kayser))];
+    Unit::new_static(TERMS)
+};
+
+/// `Gal` (Gal)
+///
+pub const GAL: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Gal))];
+    Unit::new_static(TERMS)
+};
+
+/// `dyn` (dyne)
+///
+pub const DYNE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Dyne))];
+    Unit::new_static(TERMS)
+};
+
+/// `erg` (erg)
+///
+pub const ERG: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Erg))];
+    Unit::new_static(TERMS)
+};
+
+/// `P` (Poise)
+///
+pub const POISE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Poise))];
+    Unit::new_static(TERMS)
+};
+
+/// `Bi` (Biot)
+///
+pub const BIOT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Biot))];
+    Unit::new_static(TERMS)
+};
+
+/// `St` (Stokes)
+///
+pub const STOKES: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Stokes))];
+    Unit::new_static(TERMS)
+};
+
+/// `Mx` (Maxwell)
+///
+pub const MAXWELL: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Maxwell))];
+    Unit::new_static(TERMS)
+};
+
+/// `G` (Gauss)
+///
+pub const GAUSS: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Gauss))];
+    Unit::new_static(TERMS)
+};
+
+/// `Oe` (Oersted)
+///
+pub const OERSTED: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Oersted))];
+    Unit::new_static(TERMS)
+};
+
+/// `Gb` (Gilbert)
+///
+pub const GILBERT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Gilbert))];
+    Unit::new_static(TERMS)
+};
+
+/// `sb` (stilb)
+///
+pub const STILB: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Stilb))];
+    Unit::new_static(TERMS)
+};
+
+/// `Lmb` (Lambert)
+///
+pub const LAMBERT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Lambert))];
+    Unit::new_static(TERMS)
+};
+
+/// `ph` (phot)
+///
+pub const PHOT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Phot))];
+    Unit::new_static(TERMS)
+};
+
+/// `Ci` (Curie)
+///
+pub const CURIE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Curie))];
+    Unit::new_static(TERMS)
+};
+
+/// `R` (Roentgen)
+///
+pub const ROENTGEN: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Roentgen))];
+    Unit::new_static(TERMS)
+};
+
+/// `RAD` (radiation absorbed dose)
+///
+pub const RADIATION_ABSORBED_DOSE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::RadiationAbsorbedDose))];
+    Unit::new_static(TERMS)
+};
+
+/// `REM` (radiation equivalent man)
+///
+pub const RADIATION_EQUIVALENT_MAN: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::RadiationEquivalentMan))];
+    Unit::new_static(TERMS)
+};
+
+/// `[in_i]` (inch)
+///
+pub const INCH_INTERNATIONAL: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::InchInternational))];
+    Unit::new_static(TERMS)
+};
+
+/// `[ft_i]` (foot)
+///
+pub const FOOT_INTERNATIONAL: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::FootInternational))];
+    Unit::new_static(TERMS)
+};
+
+/// `[yd_i]` (yard)
+///
+pub const YARD_INTERNATIONAL: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::YardInternational))];
+    Unit::new_static(TERMS)
+};
+
+/// `[mi_i]` (mile)
+///
+pub const MILE_INTERNATIONAL: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::MileInternational))];
+    Unit::new_static(TERMS)
+};
+
+/// `[fth_i]` (fathom)
+///
+pub const FATHOM_INTERNATIONAL: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::FathomInternational))];
+    Unit::new_static(TERMS)
+};
+
+/// `[nmi_i]` (nautical mile)
+///
+pub const NAUTICAL_MILE_INTERNATIONAL: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::NauticalMileInternational))];
+    Unit::new_static(TERMS)
+};
+
+/// `[kn_i]` (knot)
+///
+pub const KNOT_INTERNATIONAL: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::KnotInternational))];
+    Unit::new_static(TERMS)
+};
+
+/// `[sin_i]` (square inch)
+///
+pub const SQUARE_INCH_INTERNATIONAL: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::SquareInchInternational))];
+    Unit::new_static(TERMS)
+};
+
+/// `[sft_i]` (square foot)
+///
+pub const SQUARE_FOOT_INTERNATIONAL: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::SquareFootInternational))];
+    Unit::new_static(TERMS)
+};
+
+/// `[syd_i]` (square yard)
+///
+pub const SQUARE_YARD_INTERNATIONAL: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::SquareYardInternational))];
+    Unit::new_static(TERMS)
+};
+
+/// `[cin_i]` (cubic inch)
+///
+pub const CUBIC_INCH_INTERNATIONAL: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::CubicInchInternational))];
+    Unit::new_static(TERMS)
+};
+
+/// `[cft_i]` (cubic foot)
+///
+pub const CUBIC_FOOT_INTERNATIONAL: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::CubicFootInternational))];
+    Unit::new_static(TERMS)
+};
+
+/// `[cyd_i]` (cubic yard)
+///
+pub const CUBIC_YARD_INTERNATIONAL: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::CubicYardInternational))];
+    Unit::new_static(TERMS)
+};
+
+/// `[bf_i]` (board foot)
+///
+pub const BOARD_FOOT_INTERNATIONAL: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::BoardFootInternational))];
+    Unit::new_static(TERMS)
+};
+
+/// `[cr_i]` (cord)
+///
+pub const CORD_INTERNATIONAL: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::CordInternational))];
+    Unit::new_static(TERMS)
+};
+
+/// `[mil_i]` (mil)
+///
+pub const MIL_INTERNATIONAL: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::MilInternational))];
+    Unit::new_static(TERMS)
+};
+
+/// `[cml_i]` (circular mil)
+///
+pub const CIRCULAR_MIL_INTERNATIONAL: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::CircularMilInternational))];
+    Unit::new_static(TERMS)
+};
+
+/// `[hd_i]` (hand)
+///
+pub const HAND_INTERNATIONAL: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::HandInternational))];
+    Unit::new_static(TERMS)
+};
+
+/// `[ft_us]` (foot)
+///
+pub const FOOT_US: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::FootUS))];
+    Unit::new_static(TERMS)
+};
+
+/// `[yd_us]` (yard)
+///
+pub const YARD_US: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::YardUS))];
+    Unit::new_static(TERMS)
+};
+
+/// `[in_us]` (inch)
+///
+pub const INCH_US: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::InchUS))];
+    Unit::new_static(TERMS)
+};
+
+/// `[rd_us]` (rod)
+///
+pub const ROD_US: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::RodUS))];
+    Unit::new_static(TERMS)
+};
+
+/// `[ch_us]` (Gunter's chain)
+///
+pub const GUNTERS_CHAIN_US: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::GuntersChainUS))];
+    Unit::new_static(TERMS)
+};
+
+/// `[lk_us]` (link for Gunter's chain)
+///
+pub const LINK_FOR_GUNTERS_CHAIN_US: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::LinkForGuntersChainUS))];
+    Unit::new_static(TERMS)
+};
+
+/// `[rch_us]` (Ramden's chain)
+///
+pub const RAMDENS_CHAIN_US: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::RamdensChainUS))];
+    Unit::new_static(TERMS)
+};
+
+/// `[rlk_us]` (link for Ramden's chain)
+///
+pub const LINK_FOR_RAMDENS_CHAIN_US: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::LinkForRamdensChainUS))];
+    Unit::new_static(TERMS)
+};
+
+/// `[fth_us]` (fathom)
+///
+pub const FATHOM_US: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::FathomUS))];
+    Unit::new_static(TERMS)
+};
+
+/// `[fur_us]` (furlong)
+///
+pub const FURLONG_US: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::FurlongUS))];
+    Unit::new_static(TERMS)
+};
+
+/// `[mi_us]` (mile)
+///
+pub const MILE_US: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::MileUS))];
+    Unit::new_static(TERMS)
+};
+
+/// `[acr_us]` (acre)
+///
+pub const ACRE_US: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::AcreUS))];
+    Unit::new_static(TERMS)
+};
+
+/// `[srd_us]` (square rod)
+///
+pub const SQUARE_ROD_US: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::SquareRodUS))];
+    Unit::new_static(TERMS)
+};
+
+/// `[smi_us]` (square mile)
+///
+pub const SQUARE_MILE_US: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::SquareMileUS))];
+    Unit::new_static(TERMS)
+};
+
+/// `[sct]` (section)
+///
+pub const SECTION: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Section))];
+    Unit::new_static(TERMS)
+};
+
+/// `[twp]` (township)
+///
+pub const TOWNSHIP: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Township))];
+    Unit::new_static(TERMS)
+};
+
+/// `[mil_us]` (mil)
+///
+pub const MIL_US: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::MilUS))];
+    Unit::new_static(TERMS)
+};
+
+/// `[in_br]` (inch)
+///
+pub const INCH_BRITISH: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::InchBritish))];
+    Unit::new_static(TERMS)
+};
+
+/// `[ft_br]` (foot)
+///
+pub const FOOT_BRITISH: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::FootBritish))];
+    Unit::new_static(TERMS)
+};
+
+/// `[rd_br]` (rod)
+///
+pub const ROD_BRITISH: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::RodBritish))];
+    Unit::new_static(TERMS)
+};
+
+/// `[ch_br]` (Gunter's chain)
+///
+pub const GUNTERS_CHAIN_BRITISH: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::GuntersChainBritish))];
+    Unit::new_static(TERMS)
+};
+
+/// `[lk_br]` (link for Gunter's chain)
+///
+pub const LINK_FOR_GUNTERS_CHAIN_BRITISH: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::LinkForGuntersChainBritish))];
+    Unit::new_static(TERMS)
+};
+
+/// `[fth_br]` (fathom)
+///
+pub const FATHOM_BRITISH: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::FathomBritish))];
+    Unit::new_static(TERMS)
+};
+
+/// `[pc_br]` (pace)
+///
+pub const PACE_BRITISH: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::PaceBritish))];
+    Unit::new_static(TERMS)
+};
+
+/// `[yd_br]` (yard)
+///
+pub const YARD_BRITISH: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::YardBritish))];
+    Unit::new_static(TERMS)
+};
+
+/// `[mi_br]` (mile)
+///
+pub const MILE_BRITISH: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::MileBritish))];
+    Unit::new_static(TERMS)
+};
+
+/// `[nmi_br]` (nautical mile)
+///
+pub const NAUTICAL_MILE_BRITISH: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::NauticalMileBritish))];
+    Unit::new_static(TERMS)
+};
+
+/// `[kn_br]` (knot)
+///
+pub const KNOT_BRITISH: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::KnotBritish))];
+    Unit::new_static(TERMS)
+};
+
+/// `[acr_br]` (acre)
+///
+pub const ACRE_BRITISH: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::AcreBritish))];
+    Unit::new_static(TERMS)
+};
+
+/// `[gal_us]` (Queen Anne's wine gallon)
+///
+pub const QUEEN_ANNES_WINE_GALLON_US: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::QueenAnnesWineGallonUS))];
+    Unit::new_static(TERMS)
+};
+
+/// `[bbl_us]` (barrel)
+///
+pub const BARREL_US: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::BarrelUS))];
+    Unit::new_static(TERMS)
+};
+
+/// `[qt_us]` (quart)
+///
+pub const QUART_US: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::QuartUS))];
+    Unit::new_static(TERMS)
+};
+
+/// `[pt_us]` (pint)
+///
+pub const PINT_US: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::PintUS))];
+    Unit::new_static(TERMS)
+};
+
+/// `[gil_us]` (gill)
+///
+pub const GILL_US: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::GillUS))];
+    Unit::new_static(TERMS)
+};
+
+/// `[foz_us]` (fluid ounce)
+///
+pub const FLUID_OUNCE_US: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::FluidOunceUS))];
+    Unit::new_static(TERMS)
+};
+
+/// `[fdr_us]` (fluid dram)
+///
+pub const FLUID_DRAM_US: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::FluidDramUS))];
+    Unit::new_static(TERMS)
+};
+
+/// `[min_us]` (minim)
+///
+pub const MINIM_US: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::MinimUS))];
+    Unit::new_static(TERMS)
+};
+
+/// `[crd_us]` (cord)
+///
+pub const CORD_US: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::CordUS))];
+    Unit::new_static(TERMS)
+};
+
+/// `[bu_us]` (bushel)
+///
+pub const BUSHEL_US: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::BushelUS))];
+    Unit::new_static(TERMS)
+};
+
+/// `[gal_wi]` (historical winchester gallon)
+///
+pub const HISTORICAL_WINCHESTER_GALLON: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::HistoricalWinchesterGallon))];
+    Unit::new_static(TERMS)
+};
+
+/// `[pk_us]` (peck)
+///
+pub const PECK_US: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::PeckUS))];
+    Unit::new_static(TERMS)
+};
+
+/// `[dqt_us]` (dry quart)
+///
+pub const DRY_QUART_US: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::DryQuartUS))];
+    Unit::new_static(TERMS)
+};
+
+/// `[dpt_us]` (dry pint)
+///
+pub const DRY_PINT_US: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::DryPintUS))];
+    Unit::new_static(TERMS)
+};
+
+/// `[tbs_us]` (tablespoon)
+///
+pub const TABLESPOON_US: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::TablespoonUS))];
+    Unit::new_static(TERMS)
+};
+
+/// `[tsp_us]` (teaspoon)
+///
+pub const TEASPOON_US: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::TeaspoonUS))];
+    Unit::new_static(TERMS)
+};
+
+/// `[cup_us]` (cup)
+///
+pub const CUP_US: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::CupUS))];
+    Unit::new_static(TERMS)
+};
+
+/// `[foz_m]` (metric fluid ounce)
+///
+pub const METRIC_FLUID_OUNCE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::MetricFluidOunce))];
+    Unit::new_static(TERMS)
+};
+
+/// `[cup_m]` (metric cup)
+///
+pub const METRIC_CUP: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::MetricCup))];
+    Unit::new_static(TERMS)
+};
+
+/// `[tsp_m]` (metric teaspoon)
+///
+pub const METRIC_TEASPOON: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::MetricTeaspoon))];
+    Unit::new_static(TERMS)
+};
+
+/// `[tbs_m]` (metric tablespoon)
+///
+pub const METRIC_TABLESPOON: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::MetricTablespoon))];
+    Unit::new_static(TERMS)
+};
+
+/// `[gal_br]` (gallon)
+///
+pub const GALLON_BRITISH: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::GallonBritish))];
+    Unit::new_static(TERMS)
+};
+
+/// `[pk_br]` (peck)
+///
+pub const PECK_BRITISH: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::PeckBritish))];
+    Unit::new_static(TERMS)
+};
+
+/// `[bu_br]` (bushel)
+///
+pub const BUSHEL_BRITISH: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::BushelBritish))];
+    Unit::new_static(TERMS)
+};
+
+/// `[qt_br]` (quart)
+///
+pub const QUART_BRITISH: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::QuartBritish))];
+    Unit::new_static(TERMS)
+};
+
+/// `[pt_br]` (pint)
+///
+pub const PINT_BRITISH: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::PintBritish))];
+    Unit::new_static(TERMS)
+};
+
+/// `[gil_br]` (gill)
+///
+pub const GILL_BRITISH: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::GillBritish))];
+    Unit::new_static(TERMS)
+};
+
+/// `[foz_br]` (fluid ounce)
+///
+pub const FLUID_OUNCE_BRITISH: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::FluidOunceBritish))];
+    Unit::new_static(TERMS)
+};
+
+/// `[fdr_br]` (fluid dram)
+///
+pub const FLUID_DRAM_BRITISH: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::FluidDramBritish))];
+    Unit::new_static(TERMS)
+};
+
+/// `[min_br]` (minim)
+///
+pub const MINIM_BRITISH: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::MinimBritish))];
+    Unit::new_static(TERMS)
+};
+
+/// `[gr]` (grain)
+///
+pub const GRAIN: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Grain))];
+    Unit::new_static(TERMS)
+};
+
+/// `[lb_av]` (pound)
+///
+pub const POUND_AVOIRDUPOIS: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::PoundAvoirdupois))];
+    Unit::new_static(TERMS)
+};
+
+/// `[oz_av]` (ounce)
+///
+pub const OUNCE_AVOIRDUPOIS: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::OunceAvoirdupois))];
+    Unit::new_static(TERMS)
+};
+
+/// `[dr_av]` (dram)
+///
+pub const DRAM_AVOIRDUPOIS: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::DramAvoirdupois))];
+    Unit::new_static(TERMS)
+};
+
+/// `[scwt_av]` (short hundredweight)
+///
+pub const SHORT_HUNDREDWEIGHT_AVOIRDUPOIS: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::ShortHundredweightAvoirdupois))];
+    Unit::new_static(TERMS)
+};
+
+/// `[lcwt_av]` (long hunderdweight)
+///
+pub const LONG_HUNDERDWEIGHT_AVOIRDUPOIS: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::LongHunderdweightAvoirdupois))];
+    Unit::new_static(TERMS)
+};
+
+/// `[ston_av]` (short ton)
+///
+pub const SHORT_TON_AVOIRDUPOIS: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::ShortTonAvoirdupois))];
+    Unit::new_static(TERMS)
+};
+
+/// `[lton_av]` (long ton)
+///
+pub const LONG_TON_AVOIRDUPOIS: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::LongTonAvoirdupois))];
+    Unit::new_static(TERMS)
+};
+
+/// `[stone_av]` (stone)
+///
+pub const STONE_AVOIRDUPOIS: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::StoneAvoirdupois))];
+    Unit::new_static(TERMS)
+};
+
+/// `[pwt_tr]` (pennyweight)
+///
+pub const PENNYWEIGHT_TROY: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::PennyweightTroy))];
+    Unit::new_static(TERMS)
+};
+
+/// `[oz_tr]` (ounce)
+///
+pub const OUNCE_TROY: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::OunceTroy))];
+    Unit::new_static(TERMS)
+};
+
+/// `[lb_tr]` (pound)
+///
+pub const POUND_TROY: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::PoundTroy))];
+    Unit::new_static(TERMS)
+};
+
+/// `[sc_ap]` (scruple)
+///
+pub const SCRUPLE_APOTHECARIES: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::ScrupleApothecaries))];
+    Unit::new_static(TERMS)
+};
+
+/// `[dr_ap]` (dram)
+///
+pub const DRAM_APOTHECARIES: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::DramApothecaries))];
+    Unit::new_static(TERMS)
+};
+
+/// `[oz_ap]` (ounce)
+///
+pub const OUNCE_APOTHECARIES: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::OunceApothecaries))];
+    Unit::new_static(TERMS)
+};
+
+/// `[lb_ap]` (pound)
+///
+pub const POUND_APOTHECARIES: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::PoundApothecaries))];
+    Unit::new_static(TERMS)
+};
+
+/// `[oz_m]` (metric ounce)
+///
+pub const METRIC_OUNCE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::MetricOunce))];
+    Unit::new_static(TERMS)
+};
+
+/// `[lne]` (line)
+///
+pub const LINE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Line))];
+    Unit::new_static(TERMS)
+};
+
+/// `[pnt]` (point)
+///
+pub const POINT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Point))];
+    Unit::new_static(TERMS)
+};
+
+/// `[pca]` (pica)
+///
+pub const PICA: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Pica))];
+    Unit::new_static(TERMS)
+};
+
+/// `[pnt_pr]` (Printer's point)
+///
+pub const PRINTERS_POINT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::PrintersPoint))];
+    Unit::new_static(TERMS)
+};
+
+/// `[pca_pr]` (Printer's pica)
+///
+pub const PRINTERS_PICA: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::PrintersPica))];
+    Unit::new_static(TERMS)
+};
+
+/// `[pied]` (pied)
+///
+pub const PIED: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Pied))];
+    Unit::new_static(TERMS)
+};
+
+/// `[pouce]` (pouce)
+///
+pub const POUCE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Pouce))];
+    Unit::new_static(TERMS)
+};
+
+/// `[ligne]` (ligne)
+///
+pub const LIGNE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Ligne))];
+    Unit::new_static(TERMS)
+};
+
+/// `[didot]` (didot)
+///
+pub const DIDOT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Didot))];
+    Unit::new_static(TERMS)
+};
+
+/// `[cicero]` (cicero)
+///
+pub const CICERO: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Cicero))];
+    Unit::new_static(TERMS)
+};
+
+/// `[degF]` (degree Fahrenheit)
+///
+pub const DEGREE_FAHRENHEIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::DegreeFahrenheit))];
+    Unit::new_static(TERMS)
+};
+
+/// `[degR]` (degree Rankine)
+///
+pub const DEGREE_RANKINE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::DegreeRankine))];
+    Unit::new_static(TERMS)
+};
+
+/// `[degRe]` (degree Réaumur)
+///
+pub const DEGREE_REAUMUR: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::DegreeReaumur))];
+    Unit::new_static(TERMS)
+};
+
+/// `cal_[15]` (calorie at 15 °C)
+///
+pub const CALORIE_AT15_C: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::CalorieAt15C))];
+    Unit::new_static(TERMS)
+};
+
+/// `cal_[20]` (calorie at 20 °C)
+///
+pub const CALORIE_AT20_C: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::CalorieAt20C))];
+    Unit::new_static(TERMS)
+};
+
+/// `cal_m` (mean calorie)
+///
+pub const MEAN_CALORIE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::MeanCalorie))];
+    Unit::new_static(TERMS)
+};
+
+/// `cal_IT` (international table calorie)
+///
+pub const INTERNATIONAL_TABLE_CALORIE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::InternationalTableCalorie))];
+    Unit::new_static(TERMS)
+};
+
+/// `cal_th` (thermochemical calorie)
+///
+pub const THERMOCHEMICAL_CALORIE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::ThermochemicalCalorie))];
+    Unit::new_static(TERMS)
+};
+
+/// `cal` (calorie)
+///
+pub const CALORIE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Calorie))];
+    Unit::new_static(TERMS)
+};
+
+/// `[Cal]` (nutrition label Calories)
+///
+pub const NUTRITION_LABEL_CALORIES: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::NutritionLabelCalories))];
+    Unit::new_static(TERMS)
+};
+
+/// `[Btu_39]` (British thermal unit at 39 °F)
+///
+pub const BRITISH_THERMAL_UNIT_AT39_F: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::BritishThermalUnitAt39F))];
+    Unit::new_static(TERMS)
+};
+
+/// `[Btu_59]` (British thermal unit at 59 °F)
+///
+pub const BRITISH_THERMAL_UNIT_AT59_F: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::BritishThermalUnitAt59F))];
+    Unit::new_static(TERMS)
+};
+
+/// `[Btu_60]` (British thermal unit at 60 °F)
+///
+pub const BRITISH_THERMAL_UNIT_AT60_F: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::BritishThermalUnitAt60F))];
+    Unit::new_static(TERMS)
+};
+
+/// `[Btu_m]` (mean British thermal unit)
+///
+pub const MEAN_BRITISH_THERMAL_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::MeanBritishThermalUnit))];
+    Unit::new_static(TERMS)
+};
+
+/// `[Btu_IT]` (international table British thermal unit)
+///
+pub const INTERNATIONAL_TABLE_BRITISH_THERMAL_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(
+        None,
+        Some(Atom::InternationalTableBritishThermalUnit),
+    )];
+    Unit::new_static(TERMS)
+};
+
+/// `[Btu_th]` (thermochemical British thermal unit)
+///
+pub const THERMOCHEMICAL_BRITISH_THERMAL_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(
+        None,
+        Some(Atom::ThermochemicalBritishThermalUnit),
+    )];
+    Unit::new_static(TERMS)
+};
+
+/// `[Btu]` (British thermal unit)
+///
+pub const BRITISH_THERMAL_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::BritishThermalUnit))];
+    Unit::new_static(TERMS)
+};
+
+/// `[HP]` (horsepower)
+///
+pub const HORSEPOWER: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Horsepower))];
+    Unit::new_static(TERMS)
+};
+
+/// `tex` (tex)
+///
+pub const TEX: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Tex))];
+    Unit::new_static(TERMS)
+};
+
+/// `[den]` (Denier)
+///
+pub const DENIER: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Denier))];
+    Unit::new_static(TERMS)
+};
+
+/// `m[H2O]` (meter of water column)
+///
+pub const METER_OF_WATER_COLUMN: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::MeterOfWaterColumn))];
+    Unit::new_static(TERMS)
+};
+
+/// `m[Hg]` (meter of mercury column)
+///
+pub const METER_OF_MERCURY_COLUMN: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::MeterOfMercuryColumn))];
+    Unit::new_static(TERMS)
+};
+
+/// `[in_i'H2O]` (inch of water column)
+///
+pub const INCH_OF_WATER_COLUMN: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::InchOfWaterColumn))];
+    Unit::new_static(TERMS)
+};
+
+/// `[in_i'Hg]` (inch of mercury column)
+///
+pub const INCH_OF_MERCURY_COLUMN: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::InchOfMercuryColumn))];
+    Unit::new_static(TERMS)
+};
+
+/// `[PRU]` (peripheral vascular resistance unit)
+///
+pub const PERIPHERAL_VASCULAR_RESISTANCE_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(
+        None,
+        Some(Atom::PeripheralVascularResistanceUnit),
+    )];
+    Unit::new_static(TERMS)
+};
+
+/// `[wood'U]` (Wood unit)
+///
+pub const WOOD_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::WoodUnit))];
+    Unit::new_static(TERMS)
+};
+
+/// `[diop]` (diopter)
+///
+pub const DIOPTER: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Diopter))];
+    Unit::new_static(TERMS)
+};
+
+/// `[p'diop]` (prism diopter)
+///
+pub const PRISM_DIOPTER: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::PrismDiopter))];
+    Unit::new_static(TERMS)
+};
+
+/// `%[slope]` (percent of slope)
+///
+pub const PERCENT_OF_SLOPE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::PercentOfSlope))];
+    Unit::new_static(TERMS)
+};
+
+/// `[mesh_i]` (mesh)
+///
+pub const MESH_INTERNATIONAL: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::MeshInternational))];
+    Unit::new_static(TERMS)
+};
+
+/// `[Ch]` (Charrière)
+///
+pub const CHARRIERE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Charriere))];
+    Unit::new_static(TERMS)
+};
+
+/// `[drp]` (drop)
+///
+pub const DROP: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Drop))];
+    Unit::new_static(TERMS)
+};
+
+/// `[hnsf'U]` (Hounsfield unit)
+///
+pub const HOUNSFIELD_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::HounsfieldUnit))];
+    Unit::new_static(TERMS)
+};
+
+/// `[MET]` (metabolic equivalent)
+///
+pub const METABOLIC_EQUIVALENT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::MetabolicEquivalent))];
+    Unit::new_static(TERMS)
+};
+
+/// `[hp'_X]` (homeopathic potency of decimal series (retired))
+///
+pub const HOMEOPATHIC_POTENCY_OF_DECIMAL_SERIES_RETIRED: Unit = {
+    const TERMS: &[Term] = &[Term::new(
+        None,
+        Some(Atom::HomeopathicPotencyOfDecimalSeriesRetired),
+    )];
+    Unit::new_static(TERMS)
+};
+
+/// `[hp'_C]` (homeopathic potency of centesimal series (retired))
+///
+pub const HOMEOPATHIC_POTENCY_OF_CENTESIMAL_SERIES_RETIRED: Unit = {
+    const TERMS: &[Term] = &[Term::new(
+        None,
+        Some(Atom::HomeopathicPotencyOfCentesimalSeriesRetired),
+    )];
+    Unit::new_static(TERMS)
+};
+
+/// `[hp'_M]` (homeopathic potency of millesimal series (retired))
+///
+pub const HOMEOPATHIC_POTENCY_OF_MILLESIMAL_SERIES_RETIRED: Unit = {
+    const TERMS: &[Term] = &[Term::new(
+        None,
+        Some(Atom::HomeopathicPotencyOfMillesimalSeriesRetired),
+    )];
+    Unit::new_static(TERMS)
+};
+
+/// `[hp'_Q]` (homeopathic potency of quintamillesimal series (retired))
+///
+pub const HOMEOPATHIC_POTENCY_OF_QUINTAMILLESIMAL_SERIES_RETIRED: Unit = {
+    const TERMS: &[Term] = &[Term::new(
+        None,
+        Some(Atom::HomeopathicPotencyOfQuintamillesimalSeriesRetired),
+    )];
+    Unit::new_static(TERMS)
+};
+
+/// `[hp_X]` (homeopathic potency of decimal hahnemannian series)
+///
+pub const HOMEOPATHIC_POTENCY_OF_DECIMAL_HAHNEMANNIAN_SERIES: Unit = {
+    const TERMS: &[Term] = &[Term::new(
+        None,
+        Some(Atom::HomeopathicPotencyOfDecimalHahnemannianSeries),
+    )];
+    Unit::new_static(TERMS)
+};
+
+/// `[hp_C]` (homeopathic potency of centesimal hahnemannian series)
+///
+pub const HOMEOPATHIC_POTENCY_OF_CENTESIMAL_HAHNEMANNIAN_SERIES: Unit = {
+    const TERMS: &[Term] = &[Term::new(
+        None,
+        Some(Atom::HomeopathicPotencyOfCentesimalHahnemannianSeries),
+    )];
+    Unit::new_static(TERMS)
+};
+
+/// `[hp_M]` (homeopathic potency of millesimal hahnemannian series)
+///
+pub const HOMEOPATHIC_POTENCY_OF_MILLESIMAL_HAHNEMANNIAN_SERIES: Unit = {
+    const TERMS: &[Term] = &[Term::new(
+        None,
+        Some(Atom::HomeopathicPotencyOfMillesimalHahnemannianSeries),
+    )];
+    Unit::new_static(TERMS)
+};
+
+/// `[hp_Q]` (homeopathic potency of quintamillesimal hahnemannian series)
+///
+pub const HOMEOPATHIC_POTENCY_OF_QUINTAMILLESIMAL_HAHNEMANNIAN_SERIES: Unit = {
+    const TERMS: &[Term] = &[Term::new(
+        None,
+        Some(Atom::HomeopathicPotencyOfQuintamillesimalHahnemannianSeries),
+    )];
+    Unit::new_static(TERMS)
+};
+
+/// `[kp_X]` (homeopathic potency of decimal korsakovian series)
+///
+pub const HOMEOPATHIC_POTENCY_OF_DECIMAL_KORSAKOVIAN_SERIES: Unit = {
+    const TERMS: &[Term] = &[Term::new(
+        None,
+        Some(Atom::HomeopathicPotencyOfDecimalKorsakovianSeries),
+    )];
+    Unit::new_static(TERMS)
+};
+
+/// `[kp_C]` (homeopathic potency of centesimal korsakovian series)
+///
+pub const HOMEOPATHIC_POTENCY_OF_CENTESIMAL_KORSAKOVIAN_SERIES: Unit = {
+    const TERMS: &[Term] = &[Term::new(
+        None,
+        Some(Atom::HomeopathicPotencyOfCentesimalKorsakovianSeries),
+    )];
+    Unit::new_static(TERMS)
+};
+
+/// `[kp_M]` (homeopathic potency of millesimal korsakovian series)
+///
+pub const HOMEOPATHIC_POTENCY_OF_MILLESIMAL_KORSAKOVIAN_SERIES: Unit = {
+    const TERMS: &[Term] = &[Term::new(
+        None,
+        Some(Atom::HomeopathicPotencyOfMillesimalKorsakovianSeries),
+    )];
+    Unit::new_static(TERMS)
+};
+
+/// `[kp_Q]` (homeopathic potency of quintamillesimal korsakovian series)
+///
+pub const HOMEOPATHIC_POTENCY_OF_QUINTAMILLESIMAL_KORSAKOVIAN_SERIES: Unit = {
+    const TERMS: &[Term] = &[Term::new(
+        None,
+        Some(Atom::HomeopathicPotencyOfQuintamillesimalKorsakovianSeries),
+    )];
+    Unit::new_static(TERMS)
+};
+
+/// `eq` (equivalents)
+///
+pub const EQUIVALENTS: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Equivalents))];
+    Unit::new_static(TERMS)
+};
+
+/// `osm` (osmole)
+///
+pub const OSMOLE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Osmole))];
+    Unit::new_static(TERMS)
+};
+
+/// `[pH]` (pH)
+///
+pub const PH: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::PH))];
+    Unit::new_static(TERMS)
+};
+
+/// `g%` (gram percent)
+///
+pub const GRAM_PERCENT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::GramPercent))];
+    Unit::new_static(TERMS)
+};
+
+/// `[S]` (Svedberg unit)
+///
+pub const SVEDBERG_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::SvedbergUnit))];
+    Unit::new_static(TERMS)
+};
+
+/// `[HPF]` (high power field)
+///
+pub const HIGH_POWER_FIELD: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::HighPowerField))];
+    Unit::new_static(TERMS)
+};
+
+/// `[LPF]` (low power field)
+///
+pub const LOW_POWER_FIELD: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::LowPowerField))];
+    Unit::new_static(TERMS)
+};
+
+/// `kat` (katal)
+///
+pub const KATAL: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Katal))];
+    Unit::new_static(TERMS)
+};
+
+/// `U` (Unit)
+///
+pub const UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Unit))];
+    Unit::new_static(TERMS)
+};
+
+/// `[iU]` (international unit)
+///
+pub const INTERNATIONAL_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::InternationalUnit))];
+    Unit::new_static(TERMS)
+};
+
+/// `[IU]` (international unit)
+///
+pub const INTERNATIONAL_UNIT_SECONDARY: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::InternationalUnitSecondary))];
+    Unit::new_static(TERMS)
+};
+
+/// `[arb'U]` (arbitrary unit)
+///
+pub const ARBITRARY_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::ArbitraryUnit))];
+    Unit::new_static(TERMS)
+};
+
+/// `[USP'U]` (United States Pharmacopeia unit)
+///
+pub const UNITED_STATES_PHARMACOPEIA_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::UnitedStatesPharmacopeiaUnit))];
+    Unit::new_static(TERMS)
+};
+
+/// `[GPL'U]` (GPL unit)
+///
+pub const GPL_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::GplUnit))];
+    Unit::new_static(TERMS)
+};
+
+/// `[MPL'U]` (MPL unit)
+///
+pub const MPL_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::MplUnit))];
+    Unit::new_static(TERMS)
+};
+
+/// `[APL'U]` (APL unit)
+///
+pub const APL_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::AplUnit))];
+    Unit::new_static(TERMS)
+};
+
+/// `[beth'U]` (Bethesda unit)
+///
+pub const BETHESDA_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::BethesdaUnit))];
+    Unit::new_static(TERMS)
+};
+
+/// `[anti'Xa'U]` (anti factor Xa unit)
+///
+pub const ANTI_FACTOR_XA_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::AntiFactorXaUnit))];
+    Unit::new_static(TERMS)
+};
+
+/// `[todd'U]` (Todd unit)
+///
+pub const TODD_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::ToddUnit))];
+    Unit::new_static(TERMS)
+};
+
+/// `[dye'U]` (Dye unit)
+///
+pub const DYE_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::DyeUnit))];
+    Unit::new_static(TERMS)
+};
+
+/// `[smgy'U]` (Somogyi unit)
+///
+pub const SOMOGYI_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::SomogyiUnit))];
+    Unit::new_static(TERMS)
+};
+
+/// `[bdsk'U]` (Bodansky unit)
+///
+pub const BODANSKY_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::BodanskyUnit))];
+    Unit::new_static(TERMS)
+};
+
+/// `[ka'U]` (King-Armstrong unit)
+///
+pub const KING_ARMSTRONG_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::KingArmstrongUnit))];
+    Unit::new_static(TERMS)
+};
+
+/// `[knk'U]` (Kunkel unit)
+///
+pub const KUNKEL_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::KunkelUnit))];
+    Unit::new_static(TERMS)
+};
+
+/// `[mclg'U]` (Mac Lagan unit)
+///
+pub const MAC_LAGAN_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::MacLaganUnit))];
+    Unit::new_static(TERMS)
+};
+
+/// `[tb'U]` (tuberculin unit)
+///
+pub const TUBERCULIN_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::TuberculinUnit))];
+    Unit::new_static(TERMS)
+};
+
+/// `[CCID_50]` (50% cell culture infectious dose)
+///
+pub const CELL_CULTURE_INFECTIOUS_DOSE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::CellCultureInfectiousDose))];
+    Unit::new_static(TERMS)
+};
+
+/// `[TCID_50]` (50% tissue culture infectious dose)
+///
+pub const TISSUE_CULTURE_INFECTIOUS_DOSE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::TissueCultureInfectiousDose))];
+    Unit::new_static(TERMS)
+};
+
+/// `[EID_50]` (50% embryo infectious dose)
+///
+pub const EMBRYO_INFECTIOUS_DOSE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::EmbryoInfectiousDose))];
+    Unit::new_static(TERMS)
+};
+
+/// `[PFU]` (plaque forming units)
+///
+pub const PLAQUE_FORMING_UNITS: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::PlaqueFormingUnits))];
+    Unit::new_static(TERMS)
+};
+
+/// `[FFU]` (focus forming units)
+///
+pub const FOCUS_FORMING_UNITS: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::FocusFormingUnits))];
+    Unit::new_static(TERMS)
+};
+
+/// `[CFU]` (colony forming units)
+///
+pub const COLONY_FORMING_UNITS: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::ColonyFormingUnits))];
+    Unit::new_static(TERMS)
+};
+
+/// `[IR]` (index of reactivity)
+///
+pub const INDEX_OF_REACTIVITY: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::IndexOfReactivity))];
+    Unit::new_static(TERMS)
+};
+
+/// `[BAU]` (bioequivalent allergen unit)
+///
+pub const BIOEQUIVALENT_ALLERGEN_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::BioequivalentAllergenUnit))];
+    Unit::new_static(TERMS)
+};
+
+/// `[AU]` (allergen unit)
+///
+pub const ALLERGEN_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::AllergenUnit))];
+    Unit::new_static(TERMS)
+};
+
+/// `[Amb'a'1'U]` (allergen unit for Ambrosia artemisiifolia)
+///
+pub const ALLERGEN_UNIT_FOR_AMBROSIA_ARTEMISIIFOLIA: Unit = {
+    const TERMS: &[Term] = &[Term::new(
+        None,
+        Some(Atom::AllergenUnitForAmbrosiaArtemisiifolia),
+    )];
+    Unit::new_static(TERMS)
+};
+
+/// `[PNU]` (protein nitrogen unit)
+///
+pub const PROTEIN_NITROGEN_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::ProteinNitrogenUnit))];
+    Unit::new_static(TERMS)
+};
+
+/// `[Lf]` (Limit of flocculation)
+///
+pub const LIMIT_OF_FLOCCULATION: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::LimitOfFlocculation))];
+    Unit::new_static(TERMS)
+};
+
+/// `[D'ag'U]` (D-antigen unit)
+///
+pub const D_ANTIGEN_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::DAntigenUnit))];
+    Unit::new_static(TERMS)
+};
+
+/// `[FEU]` (fibrinogen equivalent unit)
+///
+pub const FIBRINOGEN_EQUIVALENT_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::FibrinogenEquivalentUnit))];
+    Unit::new_static(TERMS)
+};
+
+/// `[ELU]` (ELISA unit)
+///
+pub const ELISA_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::ElisaUnit))];
+    Unit::new_static(TERMS)
+};
+
+/// `[EU]` (Ehrlich unit)
+///
+pub const EHRLICH_UNIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::EhrlichUnit))];
+    Unit::new_static(TERMS)
+};
+
+/// `Np` (neper)
+///
+pub const NEPER: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Neper))];
+    Unit::new_static(TERMS)
+};
+
+/// `B` (bel)
+///
+pub const BEL: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Bel))];
+    Unit::new_static(TERMS)
+};
+
+/// `B[SPL]` (bel sound pressure)
+///
+pub const BEL_SOUND_PRESSURE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::BelSoundPressure))];
+    Unit::new_static(TERMS)
+};
+
+/// `B[V]` (bel volt)
+///
+pub const BEL_VOLT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::BelVolt))];
+    Unit::new_static(TERMS)
+};
+
+/// `B[mV]` (bel millivolt)
+///
+pub const BEL_MILLIVOLT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::BelMillivolt))];
+    Unit::new_static(TERMS)
+};
+
+/// `B[uV]` (bel microvolt)
+///
+pub const BEL_MICROVOLT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::BelMicrovolt))];
+    Unit::new_static(TERMS)
+};
+
+/// `B[10.nV]` (bel 10 nanovolt)
+///
+pub const BEL10_NANOVOLT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Bel10Nanovolt))];
+    Unit::new_static(TERMS)
+};
+
+/// `B[W]` (bel watt)
+///
+pub const BEL_WATT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::BelWatt))];
+    Unit::new_static(TERMS)
+};
+
+/// `B[kW]` (bel kilowatt)
+///
+pub const BEL_KILOWATT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::BelKilowatt))];
+    Unit::new_static(TERMS)
+};
+
+/// `st` (stere)
+///
+pub const STERE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Stere))];
+    Unit::new_static(TERMS)
+};
+
+/// `Ao` (Ångström)
+///
+pub const ANGSTROM: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Angstrom))];
+    Unit::new_static(TERMS)
+};
+
+/// `b` (barn)
+///
+pub const BARN: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Barn))];
+    Unit::new_static(TERMS)
+};
+
+/// `att` (technical atmosphere)
+///
+pub const TECHNICAL_ATMOSPHERE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::TechnicalAtmosphere))];
+    Unit::new_static(TERMS)
+};
+
+/// `mho` (mho)
+///
+pub const MHO: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Mho))];
+    Unit::new_static(TERMS)
+};
+
+/// `[psi]` (pound per sqare inch)
+///
+pub const POUND_PER_SQARE_INCH: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::PoundPerSqareInch))];
+    Unit::new_static(TERMS)
+};
+
+/// `circ` (circle)
+///
+pub const CIRCLE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Circle))];
+    Unit::new_static(TERMS)
+};
+
+/// `sph` (spere)
+///
+pub const SPERE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Spere))];
+    Unit::new_static(TERMS)
+};
+
+/// `[car_m]` (metric carat)
+///
+pub const METRIC_CARAT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::MetricCarat))];
+    Unit::new_static(TERMS)
+};
+
+/// `[car_Au]` (carat of gold alloys)
+///
+pub const CARAT_OF_GOLD_ALLOYS: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::CaratOfGoldAlloys))];
+    Unit::new_static(TERMS)
+};
+
+/// `[smoot]` (Smoot)
+///
+pub const SMOOT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Smoot))];
+    Unit::new_static(TERMS)
+};
+
+/// `[m/s2/Hz^(1/2)]` (meter per square seconds per square root of hertz)
+///
+pub const METER_PER_SQUARE_SECONDS_PER_SQUARE_ROOT_OF_HERTZ: Unit = {
+    const TERMS: &[Term] = &[Term::new(
+        None,
+        Some(Atom::MeterPerSquareSecondsPerSquareRootOfHertz),
+    )];
+    Unit::new_static(TERMS)
+};
+
+/// `bit_s` (bit)
+///
+pub const BIT_LOGARITHMUS_DUALIS: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::BitLogarithmusDualis))];
+    Unit::new_static(TERMS)
+};
+
+/// `bit` (bit)
+///
+pub const BIT: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Bit))];
+    Unit::new_static(TERMS)
+};
+
+/// `By` (byte)
+///
+pub const BYTE: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Byte))];
+    Unit::new_static(TERMS)
+};
+
+/// `Bd` (baud)
+///
+pub const BAUD: Unit = {
+    const TERMS: &[Term] = &[Term::new(None, Some(Atom::Baud))];
+    Unit::new_static(TERMS)
+};
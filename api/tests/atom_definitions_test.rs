@@ -0,0 +1,104 @@
+//! Each `Atom`'s definition is precomputed by wise_units-atom_generator; these check that what it
+//! generated matches parsing the definitions in `Atoms.toml`, which it was generated from.
+//!
+#[macro_use]
+extern crate approx;
+extern crate wise_units;
+
+use std::str::FromStr;
+use wise_units::{Atom, Composable, Convertible, Measurement, Term, UcumSymbol, UcumUnit, Unit};
+
+static ATOMS_TOML: &str = include_str!("../../atom_generator/Atoms.toml");
+
+/// An `[[unit]]` from `Atoms.toml`: its code, and the value and unit string it's defined as.
+///
+struct TomlDefinition {
+    code: String,
+    value: f64,
+    unit: String,
+    is_special: bool,
+}
+
+fn toml_definitions() -> Vec<TomlDefinition> {
+    let atoms: toml::Value = ATOMS_TOML.parse().unwrap();
+
+    atoms["unit"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|unit| {
+            let value = &unit["value"];
+
+            TomlDefinition {
+                code: unit["Code"].as_str().unwrap().to_string(),
+                value: value.get("value").map_or(1.0, |value| match value {
+                    toml::Value::Integer(integer) => *integer as f64,
+                    other => other.as_float().unwrap(),
+                }),
+                unit: value["Unit"].as_str().unwrap().to_string(),
+                is_special: unit.get("isSpecial").and_then(toml::Value::as_str) == Some("yes"),
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn validate_definitions_match_toml() {
+    let mut checked = 0;
+
+    for definition in toml_definitions() {
+        // The parser doesn't support a few codes (ex. `[eps_0]`), or definitions (ex. special
+        // units, like `cel(1 K)`); those are skipped.
+        let atom = match Unit::from_str(&definition.code) {
+            Ok(unit) => unit.terms()[0].atom.unwrap(),
+            Err(_) => continue,
+        };
+        let parsed = match Unit::from_str(&definition.unit) {
+            Ok(parsed) => parsed,
+            Err(_) => continue,
+        };
+        let generated = atom.definition_unit();
+
+        assert_eq!(generated.terms(), parsed.terms(), "{}", definition.code);
+        assert_eq!(
+            generated.composition(),
+            parsed.composition(),
+            "{}",
+            definition.code
+        );
+
+        // The `codata-2018` feature replaces some constants' values from the UCUM table.
+        if !definition.is_special && cfg!(not(feature = "codata-2018")) {
+            assert_relative_eq!(
+                atom.definition_value(),
+                definition.value,
+                max_relative = 1e-12
+            );
+            assert_relative_eq!(
+                atom.scalar(),
+                definition.value * parsed.scalar(),
+                max_relative = 1e-12
+            );
+        }
+
+        checked += 1;
+    }
+
+    assert!(checked > 250, "only checked {} atoms", checked);
+}
+
+#[test]
+fn validate_water_column_atoms() {
+    // `[in_i'H2O]` is defined in terms of `m[H2O]`, and used to panic when its definition was
+    // looked up.
+    let inch = Atom::InchOfWaterColumn;
+    assert_eq!(inch.definition_unit().expression(), "m[H2O].[in_i]/m");
+    assert_relative_eq!(inch.scalar(), 249_088.91, max_relative = 1e-9);
+
+    let meter = Atom::MeterOfWaterColumn;
+    assert_relative_eq!(meter.scalar(), 9_806_650.0, max_relative = 1e-12);
+
+    let inches = Measurement::new(1.0, Unit::new(vec![Term::new(None, Some(inch))])).unwrap();
+    let pascals = inches.convert_to("Pa").unwrap();
+    assert_relative_eq!(pascals.value(), 249.088_91, max_relative = 1e-9);
+}
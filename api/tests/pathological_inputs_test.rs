@@ -0,0 +1,299 @@
+//! Unit strings that a hostile (or just broken) client could send. Each of these must come back as
+//! an `Error`; none may panic, overflow, or exhaust the stack.
+//!
+extern crate wise_units;
+
+use std::str::FromStr;
+use wise_units::{
+    invert::ToInverse,
+    parser::{Error as ParserError, ParseLimits},
+    Atom, Composable, Composition, Error, Measurement, Term, Unit,
+};
+
+fn parser_error(expression: &str, limits: ParseLimits) -> ParserError {
+    match Unit::from_str_with(expression, limits) {
+        Err(Error::ParsingFailed(error)) => error,
+        other => panic!(
+            "expected a parsing error for {:?}, got {:?}",
+            expression, other
+        ),
+    }
+}
+
+fn default_parser_error(expression: &str) -> ParserError {
+    parser_error(expression, ParseLimits::default())
+}
+
+/// Limits that leave only the default nesting depth in place.
+///
+fn unlimited() -> ParseLimits {
+    ParseLimits::new()
+        .with_max_length(usize::MAX)
+        .with_max_exponent(i32::MAX)
+}
+
+#[test]
+fn validate_long_input() {
+    let expression = "m.".repeat(500_000) + "m";
+
+    assert_eq!(
+        default_parser_error(&expression),
+        ParserError::InputTooLong {
+            length: 1_000_001,
+            max: 1024
+        }
+    );
+
+    let annotation = format!("{{{}}}", "a".repeat(2000));
+    assert!(matches!(
+        default_parser_error(&annotation),
+        ParserError::InputTooLong { .. }
+    ));
+
+    assert!(Measurement::try_new(1.0, &expression).is_err());
+}
+
+#[test]
+fn validate_deeply_nested_parentheses() {
+    let expression = format!("{}m{}", "(".repeat(500), ")".repeat(500));
+    assert!(matches!(
+        default_parser_error(&expression),
+        ParserError::NestingTooDeep { max: 64, .. }
+    ));
+
+    // Even without a length limit, the depth check happens before the grammar recurses.
+    let expression = format!("{}m{}", "(".repeat(1_000_000), ")".repeat(1_000_000));
+    assert!(matches!(
+        parser_error(&expression, unlimited()),
+        ParserError::NestingTooDeep { .. }
+    ));
+
+    // These used to take exponential time, backtracking through every level.
+    let expression = format!("{}m{}", "(".repeat(64), ")".repeat(64));
+    assert!(Unit::from_str(&expression).is_ok());
+    let expression = format!("{}m{}", "(m.".repeat(32), ")".repeat(31));
+    assert!(Unit::from_str(&expression).is_err());
+
+    let unbalanced = "(".repeat(1_000_000);
+    assert!(matches!(
+        parser_error(&unbalanced, unlimited()),
+        ParserError::NestingTooDeep { .. }
+    ));
+}
+
+#[test]
+fn validate_long_operator_chains() {
+    let expression = "m.".repeat(300) + "m";
+    assert!(matches!(
+        default_parser_error(&expression),
+        ParserError::NestingTooDeep { .. }
+    ));
+
+    let expression = "m/".repeat(1_000_000) + "m";
+    assert!(matches!(
+        parser_error(&expression, unlimited()),
+        ParserError::NestingTooDeep { .. }
+    ));
+
+    let expression = "m.".repeat(64) + "m";
+    assert!(Unit::from_str(&expression).is_ok());
+}
+
+#[test]
+fn validate_unbalanced_and_junk_input() {
+    for expression in &[")))", "((m)", "m)", "{", "}", "{{}}", "m{", "µm", "m\u{0}"] {
+        assert!(Unit::from_str(expression).is_err(), "{:?}", expression);
+    }
+}
+
+#[test]
+fn validate_huge_factors() {
+    assert_eq!(
+        default_parser_error("99999999999m"),
+        ParserError::FactorOutOfRange("99999999999".to_string())
+    );
+    assert_eq!(
+        default_parser_error(&format!("{}/m", "9".repeat(1000))),
+        ParserError::FactorOutOfRange("9".repeat(1000))
+    );
+    assert!(Unit::from_str("4294967295m").is_ok());
+    assert!(matches!(
+        default_parser_error("4294967296m"),
+        ParserError::FactorOutOfRange(_)
+    ));
+}
+
+#[test]
+fn validate_huge_exponents() {
+    assert!(Unit::from_str("m1000").is_ok());
+    assert!(Unit::from_str("m-1000").is_ok());
+    assert_eq!(
+        default_parser_error("m1001"),
+        ParserError::ExponentOutOfRange {
+            exponent: "1001".to_string(),
+            max: 1000
+        }
+    );
+    assert_eq!(
+        default_parser_error("/m1001"),
+        ParserError::ExponentOutOfRange {
+            exponent: "-1001".to_string(),
+            max: 1000
+        }
+    );
+    assert_eq!(
+        default_parser_error("m99999999999"),
+        ParserError::ExponentOutOfRange {
+            exponent: "99999999999".to_string(),
+            max: 1000
+        }
+    );
+    assert!(matches!(
+        default_parser_error("m-2147483648"),
+        ParserError::ExponentOutOfRange { .. }
+    ));
+    assert!(matches!(
+        default_parser_error("10*99999999999"),
+        ParserError::ExponentOutOfRange { .. }
+    ));
+}
+
+#[test]
+fn validate_exponent_overflow() {
+    let limits = unlimited();
+
+    assert!(Unit::from_str_with("m2147483647", limits).is_ok());
+    assert!(Unit::from_str_with("/m2147483647", limits).is_ok());
+
+    // Summing exponents for the same atom.
+    assert_eq!(
+        parser_error("m2147483647.m", limits),
+        ParserError::ExponentOverflow("m2147483647.m".to_string())
+    );
+    assert!(matches!(
+        parser_error("m2147483647/s", limits),
+        ParserError::ExponentOverflow(_)
+    ));
+
+    // The exponent times the atom's own composition (`ar` is L2).
+    assert_eq!(
+        parser_error("ar2000000000", limits),
+        ParserError::ExponentOverflow("ar2000000000".to_string())
+    );
+    assert!(matches!(
+        parser_error("[acr_us]-1073741825", limits),
+        ParserError::ExponentOverflow(_)
+    ));
+}
+
+#[test]
+fn validate_exponent_overflow_after_parsing() {
+    // Everything that builds a `Unit` checks its `Composition`, so `composition()` can't panic.
+    assert!(matches!(
+        Unit::from_str("ar").unwrap().pow(2_000_000_000),
+        Err(Error::ExponentOverflow { .. })
+    ));
+    assert!(matches!(
+        Unit::from_str("m").unwrap().pow(i32::MIN),
+        Err(Error::ExponentOverflow { .. })
+    ));
+    assert!(matches!(
+        parser_error("m2147483647.m", ParseLimits::new()),
+        ParserError::ExponentOutOfRange { .. }
+    ));
+
+    let huge = Unit::from_str("ar").unwrap().pow(1_000_000_000).unwrap();
+    assert_eq!(huge.composition(), Composition::new_length(2_000_000_000));
+    assert_eq!(
+        huge.checked_mul(&huge).unwrap_err(),
+        Error::CombinedExponentOverflow {
+            lhs: "ar1000000000".to_string(),
+            rhs: "ar1000000000".to_string()
+        }
+    );
+    assert!(huge.checked_div(&huge.to_inverse()).is_err());
+    assert_eq!(
+        huge.checked_div(&huge).unwrap().expression(),
+        Unit::from_str("1").unwrap().expression()
+    );
+
+    // Units parsed with looser limits are checked the same way.
+    let loose = Unit::from_str_with("m2147483647", unlimited()).unwrap();
+    assert!(matches!(
+        &loose * &loose,
+        Err(Error::CombinedExponentOverflow { .. })
+    ));
+    assert!(matches!(
+        &loose / &loose.to_inverse(),
+        Err(Error::CombinedExponentOverflow { .. })
+    ));
+
+    // As are `Term`s that weren't parsed.
+    let terms = vec![Term {
+        exponent: Some(2_000_000_000),
+        ..Term::new(None, Some(Atom::Are))
+    }];
+    assert!(matches!(Unit::try_new(terms), Err(Error::InvalidUnit(_))));
+
+    let measurement = Measurement::new(2.0, huge).unwrap();
+    assert!(matches!(
+        &measurement * &measurement,
+        Err(Error::CombinedExponentOverflow { .. })
+    ));
+    assert!(matches!(
+        measurement.powi(3),
+        Err(Error::ExponentOverflow { .. })
+    ));
+}
+
+#[test]
+fn validate_composition_checked_arithmetic() {
+    let max = Composition::new_length(i32::MAX);
+
+    assert_eq!(max.checked_mul(Composition::new_length(1)), None);
+    assert_eq!(
+        max.checked_mul(Composition::new_length(-1)),
+        Some(Composition::new_length(i32::MAX - 1))
+    );
+    assert_eq!(
+        max.checked_mul(Composition::new_mass(1)),
+        max * Composition::new_mass(1)
+    );
+    assert_eq!(max * Composition::new_length(1), None);
+    assert_eq!(max * 2, None);
+    assert_eq!(max.checked_mul_exponent(2), None);
+    assert_eq!(
+        Composition::new_length(i32::MIN).checked_mul_exponent(-1),
+        None
+    );
+    assert_eq!(
+        Composition::new_length(2).checked_mul_exponent(3),
+        Some(Composition::new_length(6))
+    );
+
+    let mut composition = max;
+    assert_eq!(composition.insert(wise_units::Dimension::Length, 1), None);
+    assert_eq!(composition, max);
+}
+
+#[test]
+fn validate_custom_limits() {
+    let limits = ParseLimits::new()
+        .with_max_length(8)
+        .with_max_depth(1)
+        .with_max_exponent(2);
+
+    assert!(Unit::from_str_with("kg/m2", limits).is_ok());
+    assert!(matches!(
+        parser_error("kg/m3", limits),
+        ParserError::ExponentOutOfRange { max: 2, .. }
+    ));
+    assert!(matches!(
+        parser_error("kg.m/s2", limits),
+        ParserError::NestingTooDeep { max: 1, .. }
+    ));
+    assert!(matches!(
+        parser_error("[lb_av]/[acr_us]", limits),
+        ParserError::InputTooLong { max: 8, .. }
+    ));
+}
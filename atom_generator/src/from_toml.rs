@@ -22,12 +22,13 @@
 //! support for custom special units does not yet exist.
 //!
 
-use crate::rust_structs::{RustAtom, RustFunctionSet};
+use crate::rust_structs::{RustAtom, RustDefinition, RustFunctionSet};
 use crate::toml_structs::{TomlAtom, TomlBaseUnit, TomlUnit};
 use heck::CamelCase;
 
 pub(crate) mod atoms;
 pub(crate) mod custom_atoms;
+mod definitions;
 
 /// Transforms a `Vec<TomlBaseUnit>` to a `Vec<RustAtom>`.
 ///
@@ -38,7 +39,10 @@ fn transform_base_units(atom_list_base_units: &[TomlBaseUnit]) -> Vec<RustAtom>
             type_name: bu.type_name(),
             classification: "Si".to_string(),
             dim: Some(bu.dim.clone()),
-            definition_signature: "Ok(Definition::default())".to_string(),
+            definition: RustDefinition {
+                value: "1.0".to_string(),
+                ..RustDefinition::default()
+            },
             primary_code: bu.primary_code.clone(),
             print_symbol: Some(bu.print_symbol.clone()),
             property: bu.property.clone(),
@@ -47,6 +51,8 @@ fn transform_base_units(atom_list_base_units: &[TomlBaseUnit]) -> Vec<RustAtom>
             is_arbitrary: false,
             is_metric: true,
             is_special: false,
+            definition_value: 1.0,
+            definition_unit: "1".to_string(),
         })
         .collect()
 }
@@ -57,39 +63,38 @@ fn transform_units(atom_list_units: &[TomlUnit]) -> Vec<RustAtom> {
     atom_list_units
         .iter()
         .map(|u| {
-            let definition_signature = if u.is_special {
+            let (definition_value, definition_unit, function_set) = if u.is_special {
                 let function_set = RustFunctionSet {
                     convert_from: build_magnitude_function(&u.primary_code),
                     convert_to: build_scalar_function(&u.primary_code),
                 };
 
                 let function = u.definition.function.clone().unwrap();
-                let function_set_string = build_function_set_string(&function_set);
 
-                format!(
-                    "Definition::new({:?}, \"{}\", Some({}))",
-                    function.value, function.unit, function_set_string
+                (
+                    function.value,
+                    function.unit,
+                    Some(build_function_set_string(&function_set)),
                 )
-            } else if &u.primary_code == "[pi]" {
-                format!(
-                    "Definition::new(::std::f64::consts::PI, \"{}\", None)",
-                    u.definition.unit.clone()
-                )
-            } else if u.definition.value.eq(&1.0_f64) && &u.definition.unit == "1" {
-                "Ok(Definition::default())".to_string()
             } else {
-                format!(
-                    "Definition::new({:?}, \"{}\", None)",
-                    u.definition.value,
-                    u.definition.unit.clone()
-                )
+                (u.definition.value, u.definition.unit.clone(), None)
+            };
+
+            let (value, definition_value) = if &u.primary_code == "[pi]" {
+                ("::std::f64::consts::PI".to_string(), ::std::f64::consts::PI)
+            } else {
+                (format!("{:?}", definition_value), definition_value)
             };
 
             RustAtom {
                 type_name: u.type_name(),
                 classification: u.classification.clone().to_camel_case(),
                 dim: None,
-                definition_signature,
+                definition: RustDefinition {
+                    value,
+                    function_set,
+                    ..RustDefinition::default()
+                },
                 primary_code: u.primary_code.clone(),
                 print_symbol: u.print_symbol.clone(),
                 property: u.property.clone(),
@@ -98,6 +103,8 @@ fn transform_units(atom_list_units: &[TomlUnit]) -> Vec<RustAtom> {
                 is_arbitrary: u.is_arbitrary,
                 is_metric: u.is_metric,
                 is_special: u.is_special,
+                definition_value,
+                definition_unit,
             }
         })
         .collect()
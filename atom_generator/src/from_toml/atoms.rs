@@ -8,10 +8,18 @@ static ATOMS_FILE: &str = include_str!("../../Atoms.toml");
 /// of the repo and transforms that into a `RustAtomList`.
 ///
 pub(crate) fn build_rust_atom_list() -> RustAtomList {
-    let toml_atoms =
-        TomlAtomList::from_str(ATOMS_FILE).expect("unable to deserialize Toml to TomlAtomList");
+    let toml_atoms = build_toml_atom_list();
+    let mut rust_atom_list = transform(&toml_atoms);
 
-    transform(&toml_atoms)
+    super::definitions::resolve(&mut rust_atom_list, &toml_atoms.prefixes);
+
+    rust_atom_list
+}
+
+/// Deserializes the `Atoms.toml` file.
+///
+pub(super) fn build_toml_atom_list() -> TomlAtomList {
+    TomlAtomList::from_str(ATOMS_FILE).expect("unable to deserialize Toml to TomlAtomList")
 }
 
 /// Transforms a `TomlAtomlList` to a `RustAtomList`.
@@ -21,6 +21,10 @@ pub(crate) fn build_rust_atom_list() -> RustAtomList {
 
     transform(&toml_custom_atoms, &mut rust_atom_list);
 
+    // Custom atoms may be defined in terms of UCUM ones (and use UCUM prefixes).
+    let prefixes = super::atoms::build_toml_atom_list().prefixes;
+    super::definitions::resolve(&mut rust_atom_list, &prefixes);
+
     rust_atom_list
 }
 
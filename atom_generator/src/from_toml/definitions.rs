@@ -0,0 +1,457 @@
+//! Precomputes each atom's `Definition`: the `Term`s its expression parses to, its
+//! `Composition`, and its scalar. `wise_units` then looks these up instead of parsing the
+//! expressions at run time.
+//!
+//! Expressions are parsed the same way `wise_units`' term grammar parses them (including a `/`
+//! inverting everything after it, not just the next component), but only the parts of it that
+//! definitions use: factors, (prefixed) atoms, exponents, `.`, `/`, and parentheses.
+//!
+
+use crate::rust_structs::{RustAtom, RustAtomList};
+use crate::toml_structs::TomlPrefix;
+use heck::{CamelCase, ShoutySnakeCase};
+use std::collections::BTreeMap;
+
+/// Each of `wise_units::Dimension`'s variants, by the symbol base units use for them in the TOML.
+///
+const DIMENSIONS: [(&str, &str); 7] = [
+    ("Q", "ElectricCharge"),
+    ("L", "Length"),
+    ("F", "LuminousIntensity"),
+    ("M", "Mass"),
+    ("A", "PlaneAngle"),
+    ("C", "Temperature"),
+    ("T", "Time"),
+];
+
+/// Fills in the `definition` of each atom in `atom_list`.
+///
+pub(super) fn resolve(atom_list: &mut RustAtomList, prefixes: &[TomlPrefix]) {
+    let resolved: Vec<_> = {
+        let resolver = Resolver {
+            atoms: &atom_list.atoms,
+            prefixes,
+        };
+
+        (0..atom_list.atoms.len())
+            .map(|index| {
+                let terms = resolver.terms(index);
+                let composition = resolver.composition(index);
+                let scalar = resolver.scalar(index);
+
+                (
+                    terms.iter().map(|term| resolver.term_code(term)).collect(),
+                    composition_code(&composition),
+                    scalar.map_or_else(|| "None".to_string(), |s| format!("Some({:?})", s)),
+                )
+            })
+            .collect()
+    };
+
+    for (atom, (terms, composition, scalar)) in atom_list.atoms.iter_mut().zip(resolved) {
+        atom.definition.static_name = atom.type_name.to_shouty_snake_case();
+        atom.definition.terms = terms;
+        atom.definition.composition = composition;
+        atom.definition.scalar = scalar;
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct Term {
+    factor: Option<u32>,
+    prefix: Option<usize>,
+    atom: Option<usize>,
+    exponent: Option<i32>,
+}
+
+impl Term {
+    fn invert(&mut self) {
+        self.exponent = match self.exponent {
+            Some(-1) => None,
+            Some(exponent) => Some(-exponent),
+            None => Some(-1),
+        };
+    }
+
+    fn is_unity(&self) -> bool {
+        self.factor == Some(1)
+            && self.exponent.is_none()
+            && self.atom.is_none()
+            && self.prefix.is_none()
+    }
+}
+
+struct Resolver<'a> {
+    atoms: &'a [RustAtom],
+    prefixes: &'a [TomlPrefix],
+}
+
+impl Resolver<'_> {
+    fn terms(&self, atom_index: usize) -> Vec<Term> {
+        let expression = &self.atoms[atom_index].definition_unit;
+        let mut parser = Parser {
+            resolver: self,
+            expression,
+            position: 0,
+        };
+
+        let terms = parser.main_term();
+
+        assert!(
+            parser.position == expression.len(),
+            "Unable to parse definition {:?} of {}",
+            expression,
+            self.atoms[atom_index].type_name
+        );
+
+        terms
+    }
+
+    /// Mirrors `Composable for Atom`: base units have their own dimension; everything else has
+    /// the `Composition` of its definition's `Term`s.
+    ///
+    fn composition(&self, atom_index: usize) -> BTreeMap<usize, i32> {
+        let atom = &self.atoms[atom_index];
+
+        if let Some(ref dim) = atom.dim {
+            let mut composition = BTreeMap::new();
+            let _ = composition.insert(dimension_index(dim), 1);
+
+            return composition;
+        }
+
+        let mut composition = BTreeMap::new();
+
+        for term in self.terms(atom_index) {
+            if let Some(term_atom) = term.atom {
+                for (dimension, exponent) in self.composition(term_atom) {
+                    *composition.entry(dimension).or_insert(0) +=
+                        exponent * term.exponent.unwrap_or(1);
+                }
+            }
+        }
+
+        composition.retain(|_, exponent| *exponent != 0);
+        composition
+    }
+
+    /// Mirrors `Reducible for Definition`, in the same order of operations, so the result is
+    /// exactly what `wise_units` would calculate. `None` for special atoms (which use their
+    /// functions instead) and anything defined in terms of them.
+    ///
+    fn scalar(&self, atom_index: usize) -> Option<f64> {
+        let atom = &self.atoms[atom_index];
+
+        if atom.is_special {
+            return None;
+        }
+
+        let terms = self.terms(atom_index);
+
+        if terms.len() == 1 && terms[0].is_unity() {
+            return Some(atom.definition_value);
+        }
+
+        let terms_scalar = terms.iter().try_fold(1.0, |acc, term| {
+            let atom_scalar = match term.atom {
+                Some(term_atom) => self.scalar(term_atom)?,
+                None => 1.0,
+            };
+            let prefix_scalar = term.prefix.map_or(1.0, |p| self.prefixes[p].value);
+
+            let mut product = atom_scalar * prefix_scalar;
+
+            if let Some(factor) = term.factor {
+                product *= f64::from(factor);
+            }
+
+            if let Some(exponent) = term.exponent {
+                product = product.powi(exponent);
+            }
+
+            Some(acc * product)
+        })?;
+
+        Some(atom.definition_value * terms_scalar)
+    }
+
+    fn term_code(&self, term: &Term) -> String {
+        let prefix = option_code(
+            term.prefix
+                .map(|p| format!("Prefix::{}", self.prefixes[p].names[0].to_camel_case())),
+        );
+        let atom = option_code(
+            term.atom
+                .map(|a| format!("Atom::{}", self.atoms[a].type_name)),
+        );
+
+        if term.factor.is_none() && term.exponent.is_none() {
+            return format!("Term::new({}, {})", prefix, atom);
+        }
+
+        format!(
+            "Term {{ atom: {}, prefix: {}, factor: {}, exponent: {}, annotation: None }}",
+            atom,
+            prefix,
+            option_code(term.factor.map(|f| f.to_string())),
+            option_code(term.exponent.map(|e| e.to_string()))
+        )
+    }
+
+    /// The atom whose code is `symbol`, or the prefix and atom whose codes make up `symbol`.
+    /// Primary codes are tried before secondary ones, as `wise_units`' symbol grammar does.
+    ///
+    fn symbol(&self, symbol: &str) -> Option<(Option<usize>, usize)> {
+        self.prefixed_atom(symbol, |p| &p.primary_code, |a| Some(&a.primary_code))
+            .or_else(|| {
+                self.prefixed_atom(
+                    symbol,
+                    |p| &p.secondary_code,
+                    |a| a.secondary_code.as_deref(),
+                )
+            })
+    }
+
+    fn prefixed_atom(
+        &self,
+        symbol: &str,
+        prefix_code: fn(&TomlPrefix) -> &str,
+        atom_code: fn(&RustAtom) -> Option<&str>,
+    ) -> Option<(Option<usize>, usize)> {
+        let find_atom = |code: &str| {
+            self.atoms
+                .iter()
+                .position(|atom| atom_code(atom) == Some(code))
+        };
+
+        if let Some(atom) = find_atom(symbol) {
+            return Some((None, atom));
+        }
+
+        let prefix = self
+            .prefixes
+            .iter()
+            .position(|p| symbol.starts_with(prefix_code(p)))?;
+
+        find_atom(&symbol[prefix_code(&self.prefixes[prefix]).len()..])
+            .map(|atom| (Some(prefix), atom))
+    }
+}
+
+/// A recursive descent parser for the parts of the term grammar that definitions use.
+///
+struct Parser<'a> {
+    resolver: &'a Resolver<'a>,
+    expression: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn main_term(&mut self) -> Vec<Term> {
+        if self.eat("/") {
+            let mut terms = self.term();
+            terms.iter_mut().for_each(Term::invert);
+
+            terms
+        } else {
+            self.term()
+        }
+    }
+
+    fn term(&mut self) -> Vec<Term> {
+        let mut terms = self.component();
+
+        if self.eat(".") {
+            terms.append(&mut self.term());
+        } else if self.eat("/") {
+            let mut rest = self.term();
+            rest.iter_mut().for_each(Term::invert);
+            terms.append(&mut rest);
+        }
+
+        terms
+    }
+
+    fn component(&mut self) -> Vec<Term> {
+        let start = self.position;
+
+        if let Some(factor) = self.factor() {
+            if let Some(mut terms) = self.basic_component() {
+                if factor != 1 {
+                    if let Some(first) = terms.first_mut() {
+                        first.factor = Some(factor);
+                    }
+                }
+
+                return terms;
+            }
+
+            self.position = start;
+        }
+
+        self.basic_component()
+            .unwrap_or_else(|| panic!("Unable to parse {:?} at {}", self.expression, self.position))
+    }
+
+    fn basic_component(&mut self) -> Option<Vec<Term>> {
+        if let Some(term) = self.annotatable() {
+            return Some(vec![term]);
+        }
+
+        if let Some(factor) = self.factor() {
+            return Some(vec![Term {
+                factor: Some(factor),
+                ..Term::default()
+            }]);
+        }
+
+        if self.eat("(") {
+            let terms = self.term();
+            assert!(
+                self.eat(")"),
+                "Unclosed parenthesis in {:?}",
+                self.expression
+            );
+
+            return Some(terms);
+        }
+
+        None
+    }
+
+    fn annotatable(&mut self) -> Option<Term> {
+        let rest = self.rest();
+
+        let length = if rest.starts_with("10^") || rest.starts_with("10*") {
+            3
+        } else {
+            symbol_length(rest)
+        };
+
+        if length == 0 {
+            return None;
+        }
+
+        let symbol = &rest[..length];
+        let (prefix, atom) = self
+            .resolver
+            .symbol(symbol)
+            .unwrap_or_else(|| panic!("Unknown symbol {:?} in {:?}", symbol, self.expression));
+        self.position += length;
+
+        Some(Term {
+            factor: None,
+            prefix,
+            atom: Some(atom),
+            exponent: self.exponent(),
+        })
+    }
+
+    fn exponent(&mut self) -> Option<i32> {
+        let start = self.position;
+        let sign = if self.eat("-") {
+            -1
+        } else {
+            let _ = self.eat("+");
+            1
+        };
+
+        match self.digits() {
+            Some(digits) => Some(sign * digits.parse::<i32>().unwrap()),
+            None => {
+                self.position = start;
+                None
+            }
+        }
+    }
+
+    fn factor(&mut self) -> Option<u32> {
+        let start = self.position;
+        let digits = self.digits()?;
+
+        if self.rest().starts_with('*') || self.rest().starts_with('^') {
+            self.position = start;
+            return None;
+        }
+
+        Some(digits.parse().unwrap())
+    }
+
+    fn digits(&mut self) -> Option<&'a str> {
+        let expression = self.expression;
+        let rest = &expression[self.position..];
+        let length = rest.bytes().take_while(u8::is_ascii_digit).count();
+
+        if length == 0 {
+            return None;
+        }
+
+        self.position += length;
+
+        Some(&rest[..length])
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        if self.rest().starts_with(token) {
+            self.position += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.expression[self.position..]
+    }
+}
+
+/// The length of the symbol at the start of `rest`. Unlike the term grammar's `symbol_char`
+/// rule, anything in square brackets is part of the symbol, so atoms like `m[H2O]` work.
+///
+fn symbol_length(rest: &str) -> usize {
+    let mut in_brackets = false;
+
+    rest.bytes()
+        .take_while(|&byte| {
+            match byte {
+                b'[' => in_brackets = true,
+                b']' => in_brackets = false,
+                _ => (),
+            }
+
+            in_brackets || byte == b']' || is_symbol_char(byte)
+        })
+        .count()
+}
+
+/// The characters the term grammar's `symbol_char` rule allows.
+///
+const fn is_symbol_char(byte: u8) -> bool {
+    matches!(
+        byte,
+        b'!' | b'#'..=b'\'' | b'*' | b',' | b':'..=b'<' | b'>'..=b'z' | b'|' | b'~'
+    )
+}
+
+fn dimension_index(dim: &str) -> usize {
+    DIMENSIONS
+        .iter()
+        .position(|(symbol, _)| *symbol == dim)
+        .unwrap_or_else(|| panic!("Unknown dimension {:?}", dim))
+}
+
+fn composition_code(composition: &BTreeMap<usize, i32>) -> String {
+    composition.iter().fold(
+        "Composition::EMPTY".to_string(),
+        |code, (&dimension, exponent)| {
+            format!(
+                "{}.with(Dimension::{}, {})",
+                code, DIMENSIONS[dimension].1, exponent
+            )
+        },
+    )
+}
+
+fn option_code(code: Option<String>) -> String {
+    code.map_or_else(|| "None".to_string(), |code| format!("Some({})", code))
+}
@@ -12,6 +12,7 @@ pub(self) mod handlebars;
 pub(self) mod mapper;
 pub(self) mod property;
 pub(self) mod symbol_grammar;
+pub(self) mod units;
 
 pub(self) use self::handlebars::HANDLEBARS;
 
@@ -29,6 +30,7 @@ pub(crate) fn generate_files(rust_atom_list: &RustAtomList) {
     generate_atom_file(rust_atom_list);
     generate_symbol_grammar_file(rust_atom_list);
     generate_mapper_file(rust_atom_list);
+    generate_units_file(rust_atom_list);
 }
 
 fn generate_classification_file(rust_atom_list: &RustAtomList) {
@@ -61,6 +63,12 @@ fn generate_mapper_file(rust_atom_list: &RustAtomList) {
     write_project_file(&file_path, &file_body);
 }
 
+fn generate_units_file(rust_atom_list: &RustAtomList) {
+    let file_body = self::units::generate_file_body(rust_atom_list);
+    let file_path = build_file_path("units/atoms.rs");
+    write_project_file(&file_path, &file_body);
+}
+
 fn build_file_path(file_name: &str) -> PathBuf {
     let dest_dir = "../api/src/";
 
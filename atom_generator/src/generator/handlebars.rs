@@ -1,4 +1,4 @@
-use super::{atom, classification, mapper, property, symbol_grammar};
+use super::{atom, classification, mapper, property, symbol_grammar, units};
 use handlebars::Handlebars;
 use heck::CamelCase;
 
@@ -25,6 +25,9 @@ lazy_static! {
         handlebars
             .register_template_string("mapper", mapper::HBS_TEMPLATE)
             .unwrap();
+        handlebars
+            .register_template_string("units", units::HBS_TEMPLATE)
+            .unwrap();
 
         handlebars
     };
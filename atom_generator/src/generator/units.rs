@@ -0,0 +1,12 @@
+use crate::rust_structs::{RustAtomList, RustUnitList};
+
+pub(super) static HBS_TEMPLATE: &str = include_str!("../templates/units.rs.hbs");
+
+/// Uses the associated handlebars template to generate the Rust code for the
+/// predefined `Unit` constants, one per `Atom`.
+///
+pub(super) fn generate_file_body(atom_list: &RustAtomList) -> String {
+    let unit_list = RustUnitList::from(atom_list);
+
+    super::HANDLEBARS.render("units", &unit_list).unwrap()
+}
@@ -1,16 +1,20 @@
 pub(crate) mod pest_symbol_list;
 pub(crate) mod rust_atom;
 pub(crate) mod rust_classification_list;
+pub(crate) mod rust_definition;
 pub(crate) mod rust_function_set;
 pub(crate) mod rust_mapper_list;
 pub(crate) mod rust_property_list;
+pub(crate) mod rust_unit_list;
 
 pub(crate) use self::pest_symbol_list::PestSymbolList;
 pub(crate) use self::rust_atom::RustAtom;
 pub(crate) use self::rust_classification_list::RustClassificationList;
+pub(crate) use self::rust_definition::RustDefinition;
 pub(crate) use self::rust_function_set::RustFunctionSet;
 pub(crate) use self::rust_mapper_list::RustMapperList;
 pub(crate) use self::rust_property_list::RustPropertyList;
+pub(crate) use self::rust_unit_list::RustUnitList;
 
 use heck::SnakeCase;
 
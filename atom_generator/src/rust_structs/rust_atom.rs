@@ -1,9 +1,11 @@
+use super::RustDefinition;
+
 #[derive(Debug, Serialize)]
 pub(crate) struct RustAtom {
     pub(crate) type_name: String,
     pub(crate) classification: String,
     pub(crate) dim: Option<String>,
-    pub(crate) definition_signature: String,
+    pub(crate) definition: RustDefinition,
     pub(crate) primary_code: String,
     pub(crate) print_symbol: Option<String>,
     pub(crate) property: String,
@@ -12,4 +14,11 @@ pub(crate) struct RustAtom {
     pub(crate) is_arbitrary: bool,
     pub(crate) is_metric: bool,
     pub(crate) is_special: bool,
+
+    // What the atom is defined as, from the TOML. These are turned into `definition` once all
+    // atoms are known, since definitions refer to other atoms.
+    #[serde(skip)]
+    pub(crate) definition_value: f64,
+    #[serde(skip)]
+    pub(crate) definition_unit: String,
 }
@@ -0,0 +1,12 @@
+/// The parts of an atom's `Definition` that are precomputed when generating code, each as the
+/// Rust code that builds it.
+///
+#[derive(Debug, Default, Serialize)]
+pub(crate) struct RustDefinition {
+    pub(crate) static_name: String,
+    pub(crate) value: String,
+    pub(crate) terms: Vec<String>,
+    pub(crate) function_set: Option<String>,
+    pub(crate) composition: String,
+    pub(crate) scalar: String,
+}
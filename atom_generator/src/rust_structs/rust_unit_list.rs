@@ -0,0 +1,32 @@
+use super::RustAtomList;
+use heck::ShoutySnakeCase;
+
+#[derive(Debug, Serialize)]
+pub(crate) struct RustUnitList {
+    pub(crate) units: Vec<RustUnit>,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct RustUnit {
+    pub(crate) const_name: String,
+    pub(crate) type_name: String,
+    pub(crate) primary_code: String,
+    pub(crate) name: String,
+}
+
+impl<'a> From<&'a RustAtomList> for RustUnitList {
+    fn from(atom_list: &'a RustAtomList) -> Self {
+        let units = atom_list
+            .atoms
+            .iter()
+            .map(|atom| RustUnit {
+                const_name: atom.type_name.to_shouty_snake_case(),
+                type_name: atom.type_name.clone(),
+                primary_code: atom.primary_code.clone(),
+                name: atom.names.first().cloned().unwrap_or_default(),
+            })
+            .collect();
+
+        Self { units }
+    }
+}
@@ -235,14 +235,23 @@ pub unsafe extern "C" fn measurement_sub(
 ///
 /// `data` and `other` are unchecked, so validate them before passing them in.
 ///
+/// # Errors
+///
+/// If the product isn't a finite number, the error is set and a `null` pointer is returned.
+///
 #[no_mangle]
 pub unsafe extern "C" fn measurement_mul(
     data: *const Measurement,
     other: *const Measurement,
 ) -> *const Measurement {
+    error::clear_last_err_msg();
+
     let m1 = &*data;
     let m2 = &*other;
-    let result = m1 * m2;
+    let result = match m1 * m2 {
+        Ok(m) => m,
+        Err(why) => return crate::set_error_and_return(why.to_string()),
+    };
 
     Box::into_raw(Box::new(result))
 }
@@ -253,13 +262,22 @@ pub unsafe extern "C" fn measurement_mul(
 ///
 /// `data` is unchecked, so validate it before passing it in.
 ///
+/// # Errors
+///
+/// If the product isn't a finite number, the error is set and a `null` pointer is returned.
+///
 #[no_mangle]
 pub unsafe extern "C" fn measurement_mul_scalar(
     data: *const Measurement,
     scalar: f64,
 ) -> *const Measurement {
+    error::clear_last_err_msg();
+
     let measurement = &*data;
-    let result = measurement * scalar;
+    let result = match measurement * scalar {
+        Ok(m) => m,
+        Err(why) => return crate::set_error_and_return(why.to_string()),
+    };
 
     Box::into_raw(Box::new(result))
 }
@@ -270,14 +288,24 @@ pub unsafe extern "C" fn measurement_mul_scalar(
 ///
 /// `data` and `other` are unchecked, so validate them before passing them in.
 ///
+/// # Errors
+///
+/// If `other` is zero, or the quotient isn't a finite number, the error is set and a `null`
+/// pointer is returned.
+///
 #[no_mangle]
 pub unsafe extern "C" fn measurement_div(
     data: *const Measurement,
     other: *const Measurement,
 ) -> *const Measurement {
+    error::clear_last_err_msg();
+
     let m1 = &*data;
     let m2 = &*other;
-    let result = m1 / m2;
+    let result = match m1 / m2 {
+        Ok(m) => m,
+        Err(why) => return crate::set_error_and_return(why.to_string()),
+    };
 
     Box::into_raw(Box::new(result))
 }
@@ -288,13 +316,23 @@ pub unsafe extern "C" fn measurement_div(
 ///
 /// `data` is unchecked, so validate it before passing it in.
 ///
+/// # Errors
+///
+/// If `scalar` is zero, or the quotient isn't a finite number, the error is set and a `null`
+/// pointer is returned.
+///
 #[no_mangle]
 pub unsafe extern "C" fn measurement_div_scalar(
     data: *const Measurement,
     scalar: f64,
 ) -> *const Measurement {
+    error::clear_last_err_msg();
+
     let measurement = &*data;
-    let result = measurement / scalar;
+    let result = match measurement / scalar {
+        Ok(m) => m,
+        Err(why) => return crate::set_error_and_return(why.to_string()),
+    };
 
     Box::into_raw(Box::new(result))
 }
@@ -504,6 +542,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn divide_by_zero_produces_error() {
+        let expression = CString::new("kg").expect("CString::new failed");
+        let expected_error = "Operation caused a divide by 0";
+        unsafe {
+            let m = measurement_new(42.0, expression.as_ptr());
+            let result = measurement_div_scalar(m, 0.0);
+            assert_eq!(result, ptr::null());
+            let error = CStr::from_ptr(ffi_common::error::get_last_err_msg());
+            let error_str = error.to_str().expect("Failed to get str from CStr");
+            assert_eq!(error_str, expected_error);
+        }
+    }
+
     #[test]
     fn invalid_conversion_produces_error() {
         let expression1 = CString::new("[lb_av]/[acr_us]").expect("CString::new failed");
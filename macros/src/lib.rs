@@ -0,0 +1,210 @@
+//! Macros for writing `wise_units` `Unit`s and `Measurement`s whose unit strings are parsed, and
+//! validated, at compile time. Invalid unit strings are compile errors, and the macros expand to
+//! building the `Term`s directly, so nothing is parsed at run time.
+//!
+//! ```
+//! use wise_units::{Measurement, Unit};
+//! use wise_units_macros::{measurement, unit};
+//!
+//! let rate: Unit = unit!("kg/[acr_us]");
+//! assert_eq!(rate.expression(), "kg/[acr_us]");
+//!
+//! let m: Measurement = measurement!(12.5, "kg/[acr_us]");
+//! assert_eq!(m.value(), 12.5);
+//! assert_eq!(m.unit(), &rate);
+//! ```
+//!
+#![deny(unused_extern_crates)]
+#![warn(
+    clippy::all,
+    clippy::correctness,
+    clippy::nursery,
+    clippy::pedantic,
+    future_incompatible,
+    missing_copy_implementations,
+    nonstandard_style,
+    rust_2018_idioms,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_qualifications,
+    unused_results
+)]
+
+use proc_macro::TokenStream;
+use proc_macro2::{Literal, TokenStream as TokenStream2};
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input, Expr, ExprLit, ExprUnary, Lit, LitStr, Token, UnOp,
+};
+use wise_units::{Term, Unit};
+
+/// Builds a `wise_units::Unit` from a UCUM unit string, which is parsed when compiling.
+///
+/// ```
+/// use std::str::FromStr;
+/// use wise_units::Unit;
+/// use wise_units_macros::unit;
+///
+/// assert_eq!(unit!("m2/s"), Unit::from_str("m2/s").unwrap());
+/// ```
+///
+/// Strings that don't parse fail to compile, with the parser's error:
+///
+/// ```compile_fail
+/// use wise_units_macros::unit;
+///
+/// let bad = unit!("not_a_unit");
+/// ```
+///
+#[proc_macro]
+pub fn unit(input: TokenStream) -> TokenStream {
+    let expression = parse_macro_input!(input as LitStr);
+
+    match unit_tokens(&expression) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// Builds a `wise_units::Measurement` from a numeric literal and a UCUM unit string.
+///
+/// Both are checked when compiling: the unit string must parse, and the value must be finite. For
+/// values only known at run time, use `Measurement::new(value, unit!("..."))`.
+///
+/// ```
+/// use wise_units::Measurement;
+/// use wise_units_macros::measurement;
+///
+/// assert_eq!(
+///     measurement!(-40, "[degF]"),
+///     Measurement::try_new(-40.0, "[degF]").unwrap()
+/// );
+/// ```
+///
+/// ```compile_fail
+/// use wise_units_macros::measurement;
+///
+/// let too_big = measurement!(1e400, "m");
+/// ```
+///
+#[proc_macro]
+pub fn measurement(input: TokenStream) -> TokenStream {
+    let MeasurementInput { value, expression } = parse_macro_input!(input as MeasurementInput);
+
+    let unit = match unit_tokens(&expression) {
+        Ok(tokens) => tokens,
+        Err(error) => return error.to_compile_error().into(),
+    };
+
+    let value = if value.is_sign_negative() {
+        let magnitude = Literal::f64_suffixed(-value);
+        quote!(-#magnitude)
+    } else {
+        Literal::f64_suffixed(value).into_token_stream()
+    };
+
+    quote!(::wise_units::Measurement::new_unchecked(#value, #unit)).into()
+}
+
+/// The arguments to `measurement!()`: a (possibly negated) integer or float literal, then the unit
+/// string.
+///
+struct MeasurementInput {
+    value: f64,
+    expression: LitStr,
+}
+
+impl Parse for MeasurementInput {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let value_expr: Expr = input.parse()?;
+        let _comma: Token![,] = input.parse()?;
+        let expression: LitStr = input.parse()?;
+
+        if !input.is_empty() {
+            let _trailing: Option<Token![,]> = input.parse()?;
+        }
+
+        let value = parse_value(&value_expr)?;
+
+        Ok(Self { value, expression })
+    }
+}
+
+fn parse_value(expr: &Expr) -> syn::Result<f64> {
+    let value = match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Float(lit),
+            ..
+        }) => lit.base10_parse::<f64>()?,
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => lit.base10_parse::<f64>()?,
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => -parse_value(expr)?,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                expr,
+                "expected a numeric literal; use `Measurement::new()` for other values",
+            ))
+        }
+    };
+
+    if value.is_finite() {
+        Ok(value)
+    } else {
+        Err(syn::Error::new_spanned(
+            expr,
+            format!("Value is not finite: {}", value),
+        ))
+    }
+}
+
+fn unit_tokens(expression: &LitStr) -> syn::Result<TokenStream2> {
+    let unit: Unit = expression
+        .value()
+        .parse()
+        .map_err(|error: wise_units::Error| syn::Error::new(expression.span(), error))?;
+
+    let terms = unit.terms().iter().map(term_tokens);
+
+    Ok(quote!(::wise_units::Unit::new(::std::vec![#(#terms),*])))
+}
+
+fn term_tokens(term: &Term) -> TokenStream2 {
+    let atom = option_tokens(term.atom.map(|atom| {
+        let variant = format_ident!("{}", format!("{:?}", atom));
+        quote!(::wise_units::Atom::#variant)
+    }));
+    let prefix = option_tokens(term.prefix.map(|prefix| {
+        let variant = format_ident!("{}", format!("{:?}", prefix));
+        quote!(::wise_units::Prefix::#variant)
+    }));
+    let factor = option_tokens(term.factor.map(|factor| quote!(#factor)));
+    let exponent = option_tokens(term.exponent.map(|exponent| quote!(#exponent)));
+    let annotation = option_tokens(
+        term.annotation
+            .as_ref()
+            .map(|annotation| quote!(::std::string::String::from(#annotation))),
+    );
+
+    quote! {
+        ::wise_units::Term {
+            atom: #atom,
+            prefix: #prefix,
+            factor: #factor,
+            exponent: #exponent,
+            annotation: #annotation,
+        }
+    }
+}
+
+fn option_tokens(value: Option<TokenStream2>) -> TokenStream2 {
+    value.map_or_else(
+        || quote!(::std::option::Option::None),
+        |tokens| quote!(::std::option::Option::Some(#tokens)),
+    )
+}
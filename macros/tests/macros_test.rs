@@ -0,0 +1,56 @@
+use std::str::FromStr;
+use wise_units::{Measurement, Unit};
+use wise_units_macros::{measurement, unit};
+
+macro_rules! validate_unit {
+    ($($expression:literal),+ $(,)?) => {
+        $(
+            let expected = Unit::from_str($expression).unwrap();
+            let actual = unit!($expression);
+
+            assert_eq!(actual.terms(), expected.terms(), "{}", $expression);
+        )+
+    };
+}
+
+#[test]
+fn validate_unit() {
+    validate_unit!(
+        "1",
+        "m",
+        "km",
+        "m2",
+        "/m",
+        "m/s2",
+        "kg.m2/s2",
+        "kg/[acr_us]",
+        "[foz_us]/1000[ft_i]",
+        "10*3/L",
+        "10{cells}/L",
+        "kg{P2O5}/har",
+        "{tot}",
+        "Cel",
+        "[pH]",
+        "KiBy",
+    );
+}
+
+#[test]
+fn validate_measurement() {
+    assert_eq!(
+        measurement!(12.5, "kg/[acr_us]"),
+        Measurement::try_new(12.5, "kg/[acr_us]").unwrap()
+    );
+    assert_eq!(
+        measurement!(-40, "Cel"),
+        Measurement::try_new(-40.0, "Cel").unwrap()
+    );
+    assert_eq!(
+        measurement!(-0.5, "m").value().to_bits(),
+        (-0.5_f64).to_bits()
+    );
+    assert_eq!(
+        measurement!(1_000, "g",).unit(),
+        &Unit::from_str("g").unwrap()
+    );
+}